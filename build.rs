@@ -3,6 +3,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     tonic_build::configure()
         .file_descriptor_set_path("descriptor.bin")
         .out_dir("src/pb")
+        // JSON for the REST gateway; missing fields take their proto3 defaults.
+        .type_attribute(
+            ".",
+            "#[derive(serde::Serialize, serde::Deserialize)] #[serde(default)]",
+        )
         .compile_protos(&["proto/auth.proto"], &["proto"])?;
     Ok(())
 }
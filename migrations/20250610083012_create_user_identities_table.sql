@@ -0,0 +1,23 @@
+DO
+$$
+    BEGIN
+        IF EXISTS (SELECT 1 FROM pg_class WHERE relname = 'user_identities') THEN
+            EXECUTE 'DROP TABLE user_identities CASCADE';
+        END IF;
+    END
+$$;
+
+CREATE TABLE user_identities
+(
+    id         UUID PRIMARY KEY,
+    user_id    UUID REFERENCES users (ID) NOT NULL,
+    provider   VARCHAR(50)                NOT NULL,
+    subject    VARCHAR(255)               NOT NULL,
+    email      VARCHAR(255),
+    created_at TIMESTAMPTZ                NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ                NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ                         DEFAULT NULL,
+    UNIQUE (provider, subject)
+);
+
+CREATE INDEX idx_user_identities_user_id ON user_identities (user_id);
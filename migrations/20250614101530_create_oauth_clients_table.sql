@@ -0,0 +1,36 @@
+DO
+$$
+    BEGIN
+        IF EXISTS (SELECT 1 FROM pg_class WHERE relname = 'oauth_consents') THEN
+            EXECUTE 'DROP TABLE oauth_consents CASCADE';
+        END IF;
+        IF EXISTS (SELECT 1 FROM pg_class WHERE relname = 'oauth_clients') THEN
+            EXECUTE 'DROP TABLE oauth_clients CASCADE';
+        END IF;
+    END
+$$;
+
+CREATE TABLE oauth_clients
+(
+    id                 UUID PRIMARY KEY,
+    client_id          VARCHAR(100) UNIQUE NOT NULL,
+    client_secret_hash TEXT,
+    name               VARCHAR(100)        NOT NULL,
+    redirect_uris      TEXT[]              NOT NULL DEFAULT '{}',
+    allowed_scopes     TEXT[]              NOT NULL DEFAULT '{openid}',
+    created_at         TIMESTAMPTZ         NOT NULL DEFAULT NOW(),
+    updated_at         TIMESTAMPTZ         NOT NULL DEFAULT NOW(),
+    deleted_at         TIMESTAMPTZ                  DEFAULT NULL
+);
+
+CREATE TABLE oauth_consents
+(
+    id         UUID PRIMARY KEY,
+    user_id    UUID REFERENCES users (ID)                   NOT NULL,
+    client_id  VARCHAR(100) REFERENCES oauth_clients (client_id) NOT NULL,
+    scopes     TEXT[]                                       NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ                                  NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ                                  NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ                                           DEFAULT NULL,
+    UNIQUE (user_id, client_id)
+);
@@ -0,0 +1,20 @@
+DO
+$$
+    BEGIN
+        IF EXISTS (SELECT 1 FROM pg_class WHERE relname = 'service_clients') THEN
+            EXECUTE 'DROP TABLE service_clients CASCADE';
+        END IF;
+    END
+$$;
+
+CREATE TABLE service_clients
+(
+    id                 UUID PRIMARY KEY,
+    client_id          VARCHAR(100) UNIQUE NOT NULL,
+    client_secret_hash TEXT                NOT NULL,
+    name               VARCHAR(100)        NOT NULL,
+    allowed_scopes     TEXT[]              NOT NULL DEFAULT '{}',
+    created_at         TIMESTAMPTZ         NOT NULL DEFAULT NOW(),
+    updated_at         TIMESTAMPTZ         NOT NULL DEFAULT NOW(),
+    deleted_at         TIMESTAMPTZ                  DEFAULT NULL
+);
@@ -0,0 +1,26 @@
+DO
+$$
+    BEGIN
+        IF EXISTS (SELECT 1 FROM pg_class WHERE relname = 'api_keys') THEN
+            EXECUTE 'DROP TABLE api_keys CASCADE';
+        END IF;
+    END
+$$;
+
+CREATE TABLE api_keys
+(
+    id           UUID PRIMARY KEY,
+    user_id      UUID REFERENCES users (ID) NOT NULL,
+    name         VARCHAR(100)               NOT NULL,
+    prefix       VARCHAR(32) UNIQUE         NOT NULL,
+    key_hash     VARCHAR(64)                NOT NULL,
+    scopes       TEXT[]                     NOT NULL DEFAULT '{}',
+    expires_at   TIMESTAMPTZ,
+    last_used_at TIMESTAMPTZ,
+    revoked_at   TIMESTAMPTZ,
+    created_at   TIMESTAMPTZ                NOT NULL DEFAULT NOW(),
+    updated_at   TIMESTAMPTZ                NOT NULL DEFAULT NOW(),
+    deleted_at   TIMESTAMPTZ                         DEFAULT NULL
+);
+
+CREATE INDEX idx_api_keys_user_id ON api_keys (user_id);
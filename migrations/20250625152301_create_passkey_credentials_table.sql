@@ -0,0 +1,24 @@
+DO
+$$
+    BEGIN
+        IF EXISTS (SELECT 1 FROM pg_class WHERE relname = 'passkey_credentials') THEN
+            EXECUTE 'DROP TABLE passkey_credentials CASCADE';
+        END IF;
+    END
+$$;
+
+CREATE TABLE passkey_credentials
+(
+    id            UUID PRIMARY KEY,
+    user_id       UUID REFERENCES users (ID) NOT NULL,
+    name          VARCHAR(100)               NOT NULL,
+    credential_id TEXT UNIQUE                NOT NULL,
+    credential    TEXT                       NOT NULL,
+    sign_count    BIGINT                     NOT NULL DEFAULT 0,
+    last_used_at  TIMESTAMPTZ,
+    created_at    TIMESTAMPTZ                NOT NULL DEFAULT NOW(),
+    updated_at    TIMESTAMPTZ                NOT NULL DEFAULT NOW(),
+    deleted_at    TIMESTAMPTZ                         DEFAULT NULL
+);
+
+CREATE INDEX idx_passkey_credentials_user_id ON passkey_credentials (user_id);
@@ -0,0 +1,54 @@
+DO
+$$
+    BEGIN
+        IF EXISTS (SELECT 1 FROM pg_class WHERE relname = 'memberships') THEN
+            EXECUTE 'DROP TABLE memberships CASCADE';
+        END IF;
+        IF EXISTS (SELECT 1 FROM pg_class WHERE relname = 'organizations') THEN
+            EXECUTE 'DROP TABLE organizations CASCADE';
+        END IF;
+        IF EXISTS (SELECT 1 FROM pg_type WHERE typname = 'membership_role') THEN
+            EXECUTE 'DROP TYPE membership_role';
+        END IF;
+    END
+$$;
+
+CREATE TYPE membership_role AS ENUM
+    (
+        'owner',
+        'admin',
+        'member'
+        );
+
+CREATE TABLE organizations
+(
+    id         UUID PRIMARY KEY,
+    name       VARCHAR(100)        NOT NULL,
+    slug       VARCHAR(100) UNIQUE NOT NULL,
+    created_at TIMESTAMPTZ         NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ         NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ                  DEFAULT NULL
+);
+
+CREATE TABLE memberships
+(
+    id         UUID PRIMARY KEY,
+    org_id     UUID REFERENCES organizations (ID) NOT NULL,
+    user_id    UUID REFERENCES users (ID)         NOT NULL,
+    role       membership_role                    NOT NULL DEFAULT 'member',
+    created_at TIMESTAMPTZ                        NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ                        NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ                                 DEFAULT NULL,
+    UNIQUE (org_id, user_id)
+);
+
+CREATE INDEX idx_memberships_user_id ON memberships (user_id);
+
+-- Users without an organization live in the default tenant. Email is unique per tenant, so the
+-- same address may exist once in the default tenant and once in every tenant-scoped organization.
+ALTER TABLE users
+    ADD COLUMN IF NOT EXISTS org_id UUID REFERENCES organizations (ID) DEFAULT NULL;
+ALTER TABLE users
+    DROP CONSTRAINT IF EXISTS users_email_key;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_users_tenant_email
+    ON users (COALESCE(org_id, '00000000-0000-0000-0000-000000000000'::uuid), email);
@@ -0,0 +1,25 @@
+DO
+$$
+    BEGIN
+        IF EXISTS (SELECT 1 FROM pg_class WHERE relname = 'invitations') THEN
+            EXECUTE 'DROP TABLE invitations CASCADE';
+        END IF;
+    END
+$$;
+
+CREATE TABLE invitations
+(
+    id          UUID PRIMARY KEY,
+    org_id      UUID REFERENCES organizations (ID) NOT NULL,
+    email       VARCHAR(255)                       NOT NULL,
+    role        membership_role                    NOT NULL DEFAULT 'member',
+    invited_by  UUID REFERENCES users (ID)         NOT NULL,
+    expires_at  TIMESTAMPTZ                        NOT NULL,
+    accepted_at TIMESTAMPTZ,
+    revoked_at  TIMESTAMPTZ,
+    created_at  TIMESTAMPTZ                        NOT NULL DEFAULT NOW(),
+    updated_at  TIMESTAMPTZ                        NOT NULL DEFAULT NOW(),
+    deleted_at  TIMESTAMPTZ                                 DEFAULT NULL
+);
+
+CREATE INDEX idx_invitations_org_id ON invitations (org_id);
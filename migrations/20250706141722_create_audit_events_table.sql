@@ -0,0 +1,27 @@
+DO
+$$
+    BEGIN
+        IF EXISTS (SELECT 1 FROM pg_class WHERE relname = 'audit_events') THEN
+            EXECUTE 'DROP TABLE audit_events CASCADE';
+        END IF;
+    END
+$$;
+
+-- Audit events are append-only, so there is no updated_at or deleted_at.
+CREATE TABLE audit_events
+(
+    id         UUID PRIMARY KEY,
+    action     VARCHAR(50)  NOT NULL,
+    outcome    VARCHAR(20)  NOT NULL,
+    actor_id   UUID,
+    target     VARCHAR(255),
+    org_id     UUID,
+    ip         VARCHAR(64),
+    device     TEXT,
+    detail     TEXT,
+    created_at TIMESTAMPTZ  NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_audit_events_created_at ON audit_events (created_at DESC, id DESC);
+CREATE INDEX idx_audit_events_actor_id ON audit_events (actor_id);
+CREATE INDEX idx_audit_events_org_id ON audit_events (org_id);
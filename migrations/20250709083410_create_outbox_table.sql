@@ -0,0 +1,26 @@
+DO
+$$
+    BEGIN
+        IF EXISTS (SELECT 1 FROM pg_class WHERE relname = 'outbox') THEN
+            EXECUTE 'DROP TABLE outbox CASCADE';
+        END IF;
+    END
+$$;
+
+CREATE TABLE outbox
+(
+    id              UUID PRIMARY KEY,
+    kind            VARCHAR(50)  NOT NULL,
+    payload         JSONB        NOT NULL,
+    status          VARCHAR(20)  NOT NULL DEFAULT 'pending',
+    attempts        INTEGER      NOT NULL DEFAULT 0,
+    last_error      TEXT,
+    next_attempt_at TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+    delivered_at    TIMESTAMPTZ,
+    created_at      TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ  NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_outbox_due ON outbox (next_attempt_at) WHERE status = 'pending';
+CREATE INDEX idx_outbox_created_at ON outbox (created_at DESC, id DESC);
+CREATE INDEX idx_outbox_finished ON outbox (updated_at) WHERE status <> 'pending';
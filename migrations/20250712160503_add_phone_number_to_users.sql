@@ -0,0 +1,5 @@
+-- Phone numbers are stored in E.164 form and only used for OTP delivery once verified.
+ALTER TABLE users
+    ADD COLUMN IF NOT EXISTS phone_number VARCHAR(32) DEFAULT NULL;
+ALTER TABLE users
+    ADD COLUMN IF NOT EXISTS phone_verified_at TIMESTAMPTZ DEFAULT NULL;
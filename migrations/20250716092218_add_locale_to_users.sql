@@ -0,0 +1,3 @@
+-- Preferred language for emails, as a primary language tag such as "en" or "id".
+ALTER TABLE users
+    ADD COLUMN IF NOT EXISTS locale VARCHAR(16) DEFAULT NULL;
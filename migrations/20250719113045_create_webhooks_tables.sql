@@ -0,0 +1,38 @@
+DO
+$$
+    BEGIN
+        IF EXISTS (SELECT 1 FROM pg_class WHERE relname = 'webhook_deliveries') THEN
+            EXECUTE 'DROP TABLE webhook_deliveries CASCADE';
+        END IF;
+        IF EXISTS (SELECT 1 FROM pg_class WHERE relname = 'webhook_subscriptions') THEN
+            EXECUTE 'DROP TABLE webhook_subscriptions CASCADE';
+        END IF;
+    END
+$$;
+
+CREATE TABLE webhook_subscriptions
+(
+    id         UUID PRIMARY KEY,
+    url        TEXT         NOT NULL,
+    secret     VARCHAR(64)  NOT NULL,
+    events     TEXT[]       NOT NULL,
+    created_at TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ           DEFAULT NULL
+);
+
+-- One row per delivery attempt; status_code is NULL when no response was received.
+CREATE TABLE webhook_deliveries
+(
+    id              UUID PRIMARY KEY,
+    subscription_id UUID         NOT NULL REFERENCES webhook_subscriptions (id) ON DELETE CASCADE,
+    event_id        UUID         NOT NULL,
+    event           VARCHAR(50)  NOT NULL,
+    attempt         INTEGER      NOT NULL,
+    status_code     INTEGER,
+    error           TEXT,
+    duration_ms     BIGINT       NOT NULL,
+    created_at      TIMESTAMPTZ  NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_webhook_deliveries_subscription ON webhook_deliveries (subscription_id, created_at DESC, id DESC);
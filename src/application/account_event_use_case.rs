@@ -0,0 +1,211 @@
+use crate::domain::entity::account_event::{AccountEvent, AccountEventKind};
+use crate::domain::entity::domain_event::DomainEvent;
+use crate::domain::port::account_event_port::AccountEventPort;
+use crate::domain::port::event_subscriber_port::EventSubscriberPort;
+use crate::pb::auth::AccountEvent as AccountEventResponse;
+use async_trait::async_trait;
+use futures::{Stream, StreamExt};
+use std::pin::Pin;
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
+use tonic::{Response, Status};
+use tracing::{error, info};
+use uuid::Uuid;
+
+pub type AccountEventFeed =
+    Pin<Box<dyn Stream<Item = Result<AccountEventResponse, Status>> + Send>>;
+
+#[derive(Clone)]
+pub struct AccountEventUseCase {
+    events: Arc<dyn AccountEventPort>,
+    shutdown: CancellationToken,
+}
+
+impl AccountEventUseCase {
+    /// Open feeds end once `shutdown` is cancelled so they do not hold the server open.
+    pub fn new(events: Arc<dyn AccountEventPort>, shutdown: CancellationToken) -> Self {
+        AccountEventUseCase { events, shutdown }
+    }
+}
+
+impl From<AccountEvent> for AccountEventResponse {
+    fn from(event: AccountEvent) -> Self {
+        AccountEventResponse {
+            id: event.id.to_string(),
+            kind: event.kind,
+            detail: event.detail,
+            ip: event.ip,
+            device: event.device,
+            occurred_at: event.occurred_at.to_rfc3339(),
+        }
+    }
+}
+
+/// The account feed only carries what the owner should be alerted about.
+fn account_event(event: &DomainEvent) -> Option<AccountEvent> {
+    let account_event = match event {
+        DomainEvent::LoggedIn {
+            user_id,
+            method,
+            ip,
+            device,
+            ..
+        } => AccountEvent::new(*user_id, AccountEventKind::Login)
+            .with_detail(method.as_str())
+            .from_client(ip, device),
+        DomainEvent::LoggedOut { user_id } => {
+            AccountEvent::new(*user_id, AccountEventKind::SessionRevoked)
+        }
+        DomainEvent::PasswordReset { user_id } => {
+            AccountEvent::new(*user_id, AccountEventKind::PasswordChanged)
+        }
+        DomainEvent::PasskeyRegistered { user_id, .. } => {
+            AccountEvent::new(*user_id, AccountEventKind::MfaChanged).with_detail("passkey")
+        }
+        DomainEvent::PhoneNumberVerified { user_id } => {
+            AccountEvent::new(*user_id, AccountEventKind::MfaChanged).with_detail("phone")
+        }
+        _ => return None,
+    };
+    Some(account_event)
+}
+
+#[async_trait]
+impl EventSubscriberPort for AccountEventUseCase {
+    async fn handle(&self, event: &DomainEvent) {
+        let Some(account_event) = account_event(event) else {
+            return;
+        };
+        if let Err(e) = self.events.publish(&account_event).await {
+            error!(
+                "Failed to publish {} account event for user {}: {}",
+                account_event.kind, account_event.user_id, e
+            );
+        }
+    }
+}
+
+impl AccountEventUseCase {
+    pub(crate) async fn watch_account_events(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Response<AccountEventFeed>, Status> {
+        let events = self.events.subscribe(user_id).await.map_err(|e| {
+            error!(
+                "Failed to subscribe to account events of {}: {}",
+                user_id, e
+            );
+            Status::unavailable("Account events are unavailable")
+        })?;
+
+        info!("User {} is watching account events", user_id);
+
+        let feed: AccountEventFeed = Box::pin(
+            events
+                .take_until(self.shutdown.clone().cancelled_owned())
+                .map(|event| Ok(event.into())),
+        );
+        Ok(Response::new(feed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entity::domain_event::{LoginMethod, OtpChannel};
+    use crate::test_support::MemoryAccountEvents;
+
+    fn account_events() -> (AccountEventUseCase, CancellationToken) {
+        let shutdown = CancellationToken::new();
+        let account_events =
+            AccountEventUseCase::new(Arc::new(MemoryAccountEvents::default()), shutdown.clone());
+        (account_events, shutdown)
+    }
+
+    fn logged_in(user_id: Uuid) -> DomainEvent {
+        DomainEvent::LoggedIn {
+            user_id,
+            org_id: None,
+            method: LoginMethod::Password,
+            ip: "203.0.113.7".to_string(),
+            device: "curl/8.0".to_string(),
+        }
+    }
+
+    async fn next_kinds(feed: &mut AccountEventFeed, count: usize) -> Vec<String> {
+        let mut kinds = Vec::new();
+        for _ in 0..count {
+            kinds.push(feed.next().await.unwrap().unwrap().kind);
+        }
+        kinds
+    }
+
+    #[tokio::test]
+    async fn watchers_only_see_their_own_account() {
+        let (account_events, _) = account_events();
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut alice_feed = account_events
+            .watch_account_events(alice)
+            .await
+            .unwrap()
+            .into_inner();
+        let mut bob_feed = account_events
+            .watch_account_events(bob)
+            .await
+            .unwrap()
+            .into_inner();
+
+        account_events.handle(&logged_in(alice)).await;
+        account_events
+            .handle(&DomainEvent::LoggedOut { user_id: bob })
+            .await;
+        account_events
+            .handle(&DomainEvent::PasswordReset { user_id: alice })
+            .await;
+
+        assert_eq!(
+            next_kinds(&mut alice_feed, 2).await,
+            vec!["login", "password_changed"]
+        );
+        assert_eq!(next_kinds(&mut bob_feed, 1).await, vec!["session_revoked"]);
+    }
+
+    #[tokio::test]
+    async fn only_alert_worthy_events_reach_the_feed() {
+        let (account_events, _) = account_events();
+        let user_id = Uuid::new_v4();
+        let mut feed = account_events
+            .watch_account_events(user_id)
+            .await
+            .unwrap()
+            .into_inner();
+
+        account_events
+            .handle(&DomainEvent::OtpSent {
+                user_id,
+                channel: OtpChannel::Email,
+            })
+            .await;
+        account_events.handle(&logged_in(user_id)).await;
+
+        let event = feed.next().await.unwrap().unwrap();
+        assert_eq!(event.kind, "login");
+        assert_eq!(event.detail, "password");
+        assert_eq!(event.ip, "203.0.113.7");
+        assert_eq!(event.device, "curl/8.0");
+    }
+
+    #[tokio::test]
+    async fn feeds_end_on_shutdown() {
+        let (account_events, shutdown) = account_events();
+        let mut feed = account_events
+            .watch_account_events(Uuid::new_v4())
+            .await
+            .unwrap()
+            .into_inner();
+
+        shutdown.cancel();
+
+        assert!(feed.next().await.is_none());
+    }
+}
@@ -0,0 +1,120 @@
+use crate::domain::dto::auth_dto::{CreateApiKeyDto, RevokeApiKeyDto};
+use crate::domain::entity::api_key::ApiKey;
+use crate::domain::port::db::api_key_port::ApiKeyPort;
+use crate::pb::auth::{
+    ApiKey as ApiKeyResponse, CreateApiKeyData, CreateApiKeyResponse, ListApiKeysData,
+    ListApiKeysResponse, RevokeApiKeyResponse,
+};
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+use tonic::{Response, Status};
+use tracing::{error, info};
+use uuid::Uuid;
+
+pub struct ApiKeyUseCase {
+    api_keys: Arc<dyn ApiKeyPort + Send + Sync>,
+}
+
+impl ApiKeyUseCase {
+    pub fn new(api_keys: Arc<dyn ApiKeyPort + Send + Sync>) -> Self {
+        ApiKeyUseCase { api_keys }
+    }
+}
+
+fn format_time(time: Option<DateTime<Utc>>) -> String {
+    time.map(|t| t.to_rfc3339()).unwrap_or_default()
+}
+
+impl From<ApiKey> for ApiKeyResponse {
+    fn from(api_key: ApiKey) -> Self {
+        ApiKeyResponse {
+            id: api_key.id.to_string(),
+            name: api_key.name,
+            prefix: api_key.prefix,
+            scopes: api_key.scopes,
+            expires_at: format_time(api_key.expires_at),
+            last_used_at: format_time(api_key.last_used_at),
+            revoked_at: format_time(api_key.revoked_at),
+            created_at: api_key.created_at.to_rfc3339(),
+        }
+    }
+}
+
+impl ApiKeyUseCase {
+    pub(crate) async fn create_api_key(
+        &self,
+        user_id: Uuid,
+        request: CreateApiKeyDto,
+    ) -> Result<Response<CreateApiKeyResponse>, Status> {
+        let expires_at = (request.expires_in_days > 0)
+            .then(|| Utc::now() + Duration::days(request.expires_in_days));
+
+        let (key, prefix, key_hash) = ApiKey::generate_key();
+        let api_key = ApiKey::new(
+            user_id,
+            request.name,
+            prefix,
+            key_hash,
+            request.scopes,
+            expires_at,
+        );
+
+        self.api_keys.save(&api_key).await.map_err(|e| {
+            error!("Failed to save API key for user {}: {}", user_id, e);
+            Status::internal("Failed to save API key")
+        })?;
+
+        info!("API key {} created for user: {}", api_key.prefix, user_id);
+
+        Ok(Response::new(CreateApiKeyResponse {
+            message: "API key created successfully, store it now as it will not be shown again"
+                .to_string(),
+            data: Some(CreateApiKeyData {
+                key,
+                api_key: Some(api_key.into()),
+            }),
+        }))
+    }
+
+    pub(crate) async fn list_api_keys(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Response<ListApiKeysResponse>, Status> {
+        let api_keys = self.api_keys.list_by_user(user_id).await.map_err(|e| {
+            error!("Failed to list API keys for user {}: {}", user_id, e);
+            Status::internal("Failed to list API keys")
+        })?;
+
+        Ok(Response::new(ListApiKeysResponse {
+            message: "API keys retrieved successfully".to_string(),
+            data: Some(ListApiKeysData {
+                api_keys: api_keys.into_iter().map(Into::into).collect(),
+            }),
+        }))
+    }
+
+    pub(crate) async fn revoke_api_key(
+        &self,
+        user_id: Uuid,
+        request: RevokeApiKeyDto,
+    ) -> Result<Response<RevokeApiKeyResponse>, Status> {
+        let id = Uuid::parse_str(&request.id)
+            .map_err(|_| Status::invalid_argument("Invalid API key id"))?;
+
+        let revoked = self.api_keys.revoke(id, user_id).await.map_err(|e| {
+            error!("Failed to revoke API key {}: {}", id, e);
+            Status::internal("Failed to revoke API key")
+        })?;
+
+        if !revoked {
+            error!("API key {} not found for user {}", id, user_id);
+            return Err(Status::not_found("API key not found"));
+        }
+
+        info!("API key {} revoked by user: {}", id, user_id);
+
+        Ok(Response::new(RevokeApiKeyResponse {
+            message: "API key revoked successfully".to_string(),
+        }))
+    }
+}
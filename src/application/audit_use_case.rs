@@ -0,0 +1,401 @@
+use crate::domain::dto::auth_dto::ListAuditEventsDto;
+use crate::domain::entity::audit_event::{AuditAction, AuditEvent, AuditFilter, AuditOutcome};
+use crate::domain::entity::user::User;
+use crate::domain::port::db::audit_port::AuditPort;
+use crate::domain::port::db::membership_port::MembershipPort;
+use crate::domain::port::db::user_port::UserPort;
+use crate::pb::auth::{
+    AuditEvent as AuditEventResponse, ListAuditEventsData, ListAuditEventsResponse,
+};
+use crate::util::util::{decode_cursor, encode_cursor};
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tonic::{Response, Status};
+use tracing::error;
+use uuid::Uuid;
+
+const DEFAULT_PAGE_SIZE: i64 = 50;
+
+/// Request details attached to an audit event.
+#[derive(Debug, Clone, Default)]
+pub struct AuditContext {
+    pub actor_id: Option<Uuid>,
+    pub target: Option<String>,
+    pub org_id: Option<Uuid>,
+    pub ip: Option<String>,
+    pub device: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct AuditUseCase {
+    audit: Arc<dyn AuditPort + Send + Sync>,
+    users: Arc<dyn UserPort + Send + Sync>,
+    memberships: Arc<dyn MembershipPort + Send + Sync>,
+}
+
+impl AuditUseCase {
+    pub fn new(
+        audit: Arc<dyn AuditPort + Send + Sync>,
+        users: Arc<dyn UserPort + Send + Sync>,
+        memberships: Arc<dyn MembershipPort + Send + Sync>,
+    ) -> Self {
+        AuditUseCase {
+            audit,
+            users,
+            memberships,
+        }
+    }
+}
+
+impl From<AuditEvent> for AuditEventResponse {
+    fn from(event: AuditEvent) -> Self {
+        AuditEventResponse {
+            id: event.id.to_string(),
+            action: event.action,
+            outcome: event.outcome,
+            actor_id: event.actor_id.map(|id| id.to_string()).unwrap_or_default(),
+            target: event.target.unwrap_or_default(),
+            org_id: event.org_id.map(|id| id.to_string()).unwrap_or_default(),
+            ip: event.ip.unwrap_or_default(),
+            device: event.device.unwrap_or_default(),
+            detail: event.detail.unwrap_or_default(),
+            created_at: event.created_at.to_rfc3339(),
+        }
+    }
+}
+
+fn parse_timestamp(timestamp: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(timestamp)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+fn non_empty(value: String) -> Option<String> {
+    (!value.is_empty()).then_some(value)
+}
+
+impl AuditUseCase {
+    /// Records the outcome of an operation. Failures to write the audit trail are logged but
+    /// never fail the operation itself.
+    pub(crate) async fn record<T>(
+        &self,
+        action: AuditAction,
+        context: AuditContext,
+        result: &Result<T, Status>,
+    ) {
+        let (outcome, detail) = match result {
+            Ok(_) => (AuditOutcome::Success, None),
+            Err(status) => (AuditOutcome::Failure, Some(status.message().to_string())),
+        };
+
+        let event = AuditEvent {
+            actor_id: context.actor_id,
+            target: context.target,
+            org_id: context.org_id,
+            ip: context.ip,
+            device: context.device,
+            detail,
+            ..AuditEvent::new(action, outcome)
+        };
+
+        if let Err(e) = self.audit.record(&event).await {
+            error!("Failed to record audit event {}: {}", event.action, e);
+        }
+    }
+
+    /// Records an operation requested without credentials for the account `email` names. The
+    /// organization the caller claims is only kept if that account belongs to it, and the
+    /// account becomes the actor once a successful `action` proved the caller owns it.
+    pub(crate) async fn record_anonymous<T>(
+        &self,
+        action: AuditAction,
+        context: AuditContext,
+        email: &str,
+        claimed_org_id: &str,
+        result: &Result<T, Status>,
+    ) {
+        let claimed_org_id = Uuid::parse_str(claimed_org_id).ok();
+        let user = self.find_user(email, claimed_org_id).await;
+
+        let org_id = match (&user, claimed_org_id) {
+            (Some(user), Some(org_id)) => {
+                match self.memberships.find_membership(org_id, user.id).await {
+                    Ok(membership) => membership.map(|m| m.org_id),
+                    Err(e) => {
+                        error!("Failed to query membership of user {}: {}", user.id, e);
+                        None
+                    }
+                }
+            }
+            _ => None,
+        };
+
+        let proves_owner = matches!(
+            action,
+            AuditAction::Register
+                | AuditAction::Login
+                | AuditAction::OtpVerify
+                | AuditAction::PasswordReset
+        );
+        let actor_id = user
+            .filter(|_| proves_owner && result.is_ok())
+            .map(|user| user.id);
+
+        let context = AuditContext {
+            actor_id,
+            org_id,
+            ..context
+        };
+        self.record(action, context, result).await;
+    }
+
+    /// The account in the claimed organization's tenant, falling back to the default tenant.
+    async fn find_user(&self, email: &str, org_id: Option<Uuid>) -> Option<User> {
+        for tenant in org_id.map(Some).into_iter().chain([None]) {
+            match self.users.find_by_email(email, tenant).await {
+                Ok(Some(user)) => return Some(user),
+                Ok(None) => {}
+                Err(e) => {
+                    error!("Failed to query user with email {}: {}", email, e);
+                    return None;
+                }
+            }
+        }
+        None
+    }
+
+    /// Members see the events they performed; organization owners and admins can list every
+    /// event recorded for their organization.
+    pub(crate) async fn list_audit_events(
+        &self,
+        user_id: Uuid,
+        request: ListAuditEventsDto,
+    ) -> Result<Response<ListAuditEventsResponse>, Status> {
+        let org_id = Uuid::parse_str(&request.org_id).ok();
+
+        let actor_id = match org_id {
+            Some(org_id) => {
+                let membership = self
+                    .memberships
+                    .find_membership(org_id, user_id)
+                    .await
+                    .map_err(|e| {
+                        error!("Failed to query membership of user {}: {}", user_id, e);
+                        Status::internal("Failed to query membership")
+                    })?;
+
+                if !membership.is_some_and(|m| m.role.can_manage_members()) {
+                    error!("User {} cannot read audit events of {}", user_id, org_id);
+                    return Err(Status::permission_denied(
+                        "Only organization owners and admins can read its audit events",
+                    ));
+                }
+                None
+            }
+            None => Some(user_id),
+        };
+
+        let before = match non_empty(request.cursor) {
+            Some(cursor) => Some(
+                decode_cursor(&cursor).ok_or_else(|| Status::invalid_argument("Invalid cursor"))?,
+            ),
+            None => None,
+        };
+
+        let filter = AuditFilter {
+            actor_id,
+            org_id,
+            action: non_empty(request.action),
+            outcome: non_empty(request.outcome),
+            since: parse_timestamp(&request.since),
+            until: parse_timestamp(&request.until),
+            before,
+        };
+
+        let page_size = match request.page_size {
+            0 => DEFAULT_PAGE_SIZE,
+            size => i64::from(size),
+        };
+
+        let events = self.audit.list(&filter, page_size).await.map_err(|e| {
+            error!("Failed to list audit events: {}", e);
+            Status::internal("Failed to list audit events")
+        })?;
+
+        let next_cursor = if events.len() as i64 == page_size {
+            events
+                .last()
+                .map(|event| encode_cursor(event.created_at, event.id))
+                .unwrap_or_default()
+        } else {
+            String::new()
+        };
+
+        Ok(Response::new(ListAuditEventsResponse {
+            message: "Audit events retrieved successfully".to_string(),
+            data: Some(ListAuditEventsData {
+                events: events.into_iter().map(Into::into).collect(),
+                next_cursor,
+            }),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entity::membership::{Membership, MembershipRole};
+    use crate::domain::entity::user::UserStatus;
+    use crate::test_support::Fakes;
+
+    const EMAIL: &str = "member@example.com";
+
+    fn member(fakes: &Fakes, org_id: Uuid, role: MembershipRole) -> User {
+        let user = User::new(
+            "Member".to_string(),
+            EMAIL.to_string(),
+            "unused".to_string(),
+            UserStatus::Active,
+        );
+        fakes.users.with_row(user.clone());
+        fakes
+            .memberships
+            .with_row(Membership::new(org_id, user.id, role));
+        user
+    }
+
+    fn target(email: &str) -> AuditContext {
+        AuditContext {
+            target: Some(email.to_string()),
+            ..AuditContext::default()
+        }
+    }
+
+    fn list_own() -> ListAuditEventsDto {
+        ListAuditEventsDto {
+            org_id: String::new(),
+            action: String::new(),
+            outcome: String::new(),
+            since: String::new(),
+            until: String::new(),
+            cursor: String::new(),
+            page_size: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn claimed_organization_is_kept_only_for_members() {
+        let fakes = Fakes::default();
+        let org_id = Uuid::new_v4();
+        member(&fakes, org_id, MembershipRole::Member);
+        let audit = fakes.audit_use_case();
+        let ok: Result<(), Status> = Ok(());
+
+        audit
+            .record_anonymous(
+                AuditAction::OtpSend,
+                target(EMAIL),
+                EMAIL,
+                &org_id.to_string(),
+                &ok,
+            )
+            .await;
+        let other_org = Uuid::new_v4().to_string();
+        audit
+            .record_anonymous(AuditAction::OtpSend, target(EMAIL), EMAIL, &other_org, &ok)
+            .await;
+        audit
+            .record_anonymous(
+                AuditAction::OtpSend,
+                target("stranger@example.com"),
+                "stranger@example.com",
+                &org_id.to_string(),
+                &ok,
+            )
+            .await;
+
+        let orgs: Vec<_> = fakes.audit.all().iter().map(|event| event.org_id).collect();
+        assert_eq!(orgs, vec![Some(org_id), None, None]);
+    }
+
+    #[tokio::test]
+    async fn account_is_the_actor_only_once_it_proved_ownership() {
+        let fakes = Fakes::default();
+        let user = member(&fakes, Uuid::new_v4(), MembershipRole::Member);
+        let audit = fakes.audit_use_case();
+        let ok: Result<(), Status> = Ok(());
+        let failed: Result<(), Status> = Err(Status::unauthenticated("Invalid email or password"));
+
+        audit
+            .record_anonymous(AuditAction::Login, target(EMAIL), EMAIL, "", &ok)
+            .await;
+        audit
+            .record_anonymous(AuditAction::Login, target(EMAIL), EMAIL, "", &failed)
+            .await;
+        audit
+            .record_anonymous(AuditAction::OtpSend, target(EMAIL), EMAIL, "", &ok)
+            .await;
+
+        let actors: Vec<_> = fakes
+            .audit
+            .all()
+            .iter()
+            .map(|event| event.actor_id)
+            .collect();
+        assert_eq!(actors, vec![Some(user.id), None, None]);
+    }
+
+    #[tokio::test]
+    async fn members_only_list_events_they_performed() {
+        let fakes = Fakes::default();
+        let user = member(&fakes, Uuid::new_v4(), MembershipRole::Member);
+        let audit = fakes.audit_use_case();
+        let ok: Result<(), Status> = Ok(());
+
+        let own = AuditContext {
+            actor_id: Some(user.id),
+            ..AuditContext::default()
+        };
+        audit.record(AuditAction::Logout, own, &ok).await;
+        // Anyone can name this email, e.g. another tenant's account or an attacker's attempt.
+        audit.record(AuditAction::OtpSend, target(EMAIL), &ok).await;
+        let someone_else = AuditContext {
+            actor_id: Some(Uuid::new_v4()),
+            ..target(EMAIL)
+        };
+        audit.record(AuditAction::Login, someone_else, &ok).await;
+
+        let events = audit
+            .list_audit_events(user.id, list_own())
+            .await
+            .unwrap()
+            .into_inner()
+            .data
+            .unwrap()
+            .events;
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].action, "logout");
+    }
+
+    #[tokio::test]
+    async fn only_managers_list_organization_events() {
+        let fakes = Fakes::default();
+        let org_id = Uuid::new_v4();
+        let user = member(&fakes, org_id, MembershipRole::Member);
+        let audit = fakes.audit_use_case();
+
+        let status = audit
+            .list_audit_events(
+                user.id,
+                ListAuditEventsDto {
+                    org_id: org_id.to_string(),
+                    ..list_own()
+                },
+            )
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+    }
+}
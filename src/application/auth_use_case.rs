@@ -1,51 +1,195 @@
+use crate::config::env::EnvConfig;
 use crate::domain::dto::auth_dto::{
-    ForgotPasswordDto, LoginDto, LogoutDto, RegisterDto, SendOtpDto, VerifyEmailDto,
+    BeginExternalLoginDto, BeginPasskeyLoginDto, CompleteExternalLoginDto, FinishPasskeyLoginDto,
+    FinishPasskeyRegistrationDto, ForgotPasswordDto, LoginDto, LogoutDto, RegisterDto, SendOtpDto,
+    SwitchOrganizationDto, UpdatePhoneNumberDto, VerifyEmailDto, VerifyPhoneNumberDto,
 };
+use crate::domain::entity::domain_event::{DomainEvent, LoginFailure, LoginMethod, OtpChannel};
+use crate::domain::entity::membership::{Membership, MembershipRole};
+use crate::domain::entity::organization::Organization;
+use crate::domain::entity::outbox_message::{OutboxMessage, OutboxPayload};
+use crate::domain::entity::passkey_credential::PasskeyCredential;
 use crate::domain::entity::user::{User, UserStatus};
+use crate::domain::entity::user_identity::UserIdentity;
 use crate::domain::entity::user_sessions::UserSessions;
+use crate::domain::port::db::membership_port::MembershipPort;
+use crate::domain::port::db::organization_port::OrganizationPort;
+use crate::domain::port::db::outbox_port::OutboxPort;
+use crate::domain::port::db::passkey_credential_port::PasskeyCredentialPort;
+use crate::domain::port::db::user_identity_port::UserIdentityPort;
 use crate::domain::port::db::user_port::UserPort;
 use crate::domain::port::db_port::DbPort;
+use crate::domain::port::external_identity_port::{ExternalIdentity, ExternalIdentityPort};
 use crate::domain::port::redis_port::RedisPort;
+use crate::domain::service::event_bus_service::EventBus;
 use crate::domain::service::jwt_service::Token;
+use crate::domain::service::otp_service::OtpEmail;
+use crate::domain::service::webauthn_service::WebauthnService;
 use crate::interface::common::client_info::GeoLocation;
 use crate::pb::auth::{
-    ForgotPasswordResponse, LoginData, LoginResponse, LogoutResponse, RegisterData,
-    RegisterResponse, SendOtpResponse, User as UserResponse, VerifyEmailResponse,
+    BeginExternalLoginData, BeginExternalLoginResponse, BeginPasskeyLoginData,
+    BeginPasskeyLoginResponse, BeginPasskeyRegistrationData, BeginPasskeyRegistrationResponse,
+    CompleteExternalLoginResponse, FinishPasskeyLoginResponse, FinishPasskeyRegistrationData,
+    FinishPasskeyRegistrationResponse, ForgotPasswordResponse, LoginData, LoginResponse,
+    LogoutResponse, RegisterData, RegisterResponse, SendOtpResponse, SwitchOrganizationResponse,
+    UpdatePhoneNumberResponse, User as UserResponse, VerifyEmailResponse,
+    VerifyPhoneNumberResponse,
 };
-use crate::util::util::{hash_password_async, verify_password_async};
-use crate::{cfg, email, email_otp};
+use crate::util::util::{
+    generate_random_string, hash_password_async, pkce_challenge, primary_language_tag,
+    verify_password_async,
+};
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tonic::{Response, Status};
-use tracing::{error, info};
+use tracing::{error, info, instrument};
+use uuid::Uuid;
+
+const EXTERNAL_LOGIN_STATE_TTL_SECS: u64 = 600;
+const PASSKEY_CHALLENGE_TTL_SECS: u64 = 300;
+const PHONE_OTP_TTL_SECS: u64 = 600;
+
+#[derive(Serialize, Deserialize)]
+struct ExternalLoginState {
+    provider: String,
+    code_verifier: String,
+    nonce: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PasskeyLoginState {
+    user_id: Uuid,
+    state: String,
+}
+
+/// Organization ids are validated by the DTOs; an empty id selects no organization.
+fn parse_org_id(org_id: &str) -> Option<Uuid> {
+    Uuid::parse_str(org_id).ok()
+}
+
+fn otp_key(email: &str, tenant: Option<Uuid>) -> String {
+    match tenant {
+        Some(org_id) => format!("otp:{}:{}", org_id, email),
+        None => format!("otp:{}", email),
+    }
+}
+
+/// A hash of a random password nobody knows, for accounts that only sign in through a provider.
+async fn unusable_password() -> Result<String, Status> {
+    hash_password_async(generate_random_string(64))
+        .await
+        .map_err(|e| {
+            error!("Failed to hash password: {}", e);
+            Status::internal("Failed to hash password")
+        })
+}
+
+pub(crate) fn registered(user: &User) -> DomainEvent {
+    DomainEvent::UserRegistered {
+        user_id: user.id,
+        email: user.email.clone(),
+        name: user.name.clone(),
+        org_id: user.org_id,
+    }
+}
+
+pub(crate) fn email_verified(user: &User) -> DomainEvent {
+    DomainEvent::EmailVerified {
+        user_id: user.id,
+        email: user.email.clone(),
+        name: user.name.clone(),
+        org_id: user.org_id,
+    }
+}
 
+#[derive(Clone)]
 pub struct AuthUseCase {
     adapter: Arc<dyn UserPort + Send + Sync>,
     session: Arc<dyn DbPort<UserSessions> + Send + Sync>,
     redis_adapter: Arc<dyn RedisPort + Send + Sync>,
+    identities: Arc<dyn UserIdentityPort + Send + Sync>,
+    external_identity: Arc<dyn ExternalIdentityPort>,
+    passkeys: Arc<dyn PasskeyCredentialPort + Send + Sync>,
+    organizations: Arc<dyn OrganizationPort + Send + Sync>,
+    memberships: Arc<dyn MembershipPort + Send + Sync>,
+    outbox: Arc<dyn OutboxPort + Send + Sync>,
+    events: Arc<EventBus>,
+    otp: Arc<OtpEmail>,
+    webauthn: Arc<WebauthnService>,
+    config: Arc<EnvConfig>,
 }
 
 impl AuthUseCase {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         adapter: Arc<dyn UserPort + Send + Sync>,
         session: Arc<dyn DbPort<UserSessions> + Send + Sync>,
         redis_adapter: Arc<dyn RedisPort + Send + Sync>,
+        identities: Arc<dyn UserIdentityPort + Send + Sync>,
+        external_identity: Arc<dyn ExternalIdentityPort>,
+        passkeys: Arc<dyn PasskeyCredentialPort + Send + Sync>,
+        organizations: Arc<dyn OrganizationPort + Send + Sync>,
+        memberships: Arc<dyn MembershipPort + Send + Sync>,
+        outbox: Arc<dyn OutboxPort + Send + Sync>,
+        events: Arc<EventBus>,
+        otp: Arc<OtpEmail>,
+        webauthn: Arc<WebauthnService>,
+        config: Arc<EnvConfig>,
     ) -> Self {
         AuthUseCase {
             adapter,
             session,
             redis_adapter,
+            identities,
+            external_identity,
+            passkeys,
+            organizations,
+            memberships,
+            outbox,
+            events,
+            otp,
+            webauthn,
+            config,
         }
     }
 }
 
 impl AuthUseCase {
+    /// The tenant user lookups run in. Unless tenant-scoped users are enabled, every account
+    /// lives in the default tenant whatever organization the request names.
+    fn tenant(&self, org_id: &str) -> Option<Uuid> {
+        if !self.config.tenant_scoped_users {
+            return None;
+        }
+        parse_org_id(org_id)
+    }
+
+    async fn publish_login_failed(&self, user_id: Option<Uuid>, reason: LoginFailure) {
+        self.events
+            .publish(DomainEvent::LoginFailed { user_id, reason })
+            .await;
+    }
+
+    /// Reports a sign-in refused for an account that is not active. Suspended and banned
+    /// accounts count as locked.
+    async fn record_inactive_login(&self, user: &User) {
+        let reason = if matches!(user.status, UserStatus::Suspended | UserStatus::Banned) {
+            LoginFailure::Locked
+        } else {
+            LoginFailure::Inactive
+        };
+        self.publish_login_failed(Some(user.id), reason).await;
+    }
+
+    #[instrument(name = "AuthUseCase::register", level = "debug", skip_all)]
     pub(crate) async fn register(
         &self,
         request: RegisterDto,
+        request_locale: Option<String>,
     ) -> Result<Response<RegisterResponse>, Status> {
         let user_exists = self
             .adapter
-            .find_by_coll("email", &request.email)
+            .find_by_email(&request.email, self.tenant(&request.org_id))
             .await
             .map_err(|_| {
                 error!("Failed to query user with email: {}", request.email);
@@ -62,17 +206,34 @@ impl AuthUseCase {
             Status::internal("Failed to hash password")
         })?;
 
+        let tenant = self.tenant(&request.org_id);
+        if let Some(org_id) = tenant {
+            self.find_organization(org_id).await?;
+        }
+
         let user = User::new(
             request.name,
             request.email,
             hashed_password,
             UserStatus::Inactive,
-        );
+        )
+        .in_tenant(tenant)
+        .with_locale(primary_language_tag(&request.locale).or(request_locale));
 
-        self.adapter.save(&user).await.map_err(|e| {
-            error!("Failed to save user: {}", e);
-            Status::internal(format!("Failed to save user: {}", e))
-        })?;
+        let membership =
+            tenant.map(|org_id| Membership::new(org_id, user.id, MembershipRole::Member));
+        let event = registered(&user);
+        let messages = self.events.outbox_messages(&event).await;
+
+        self.adapter
+            .register(&user, membership.as_ref(), &messages)
+            .await
+            .map_err(|e| {
+                error!("Failed to save user: {}", e);
+                Status::internal(format!("Failed to save user: {}", e))
+            })?;
+
+        self.events.publish_committed(event).await;
 
         let proto_user = UserResponse {
             id: user.id.to_string(),
@@ -91,6 +252,7 @@ impl AuthUseCase {
         Ok(Response::new(response))
     }
 
+    #[instrument(name = "AuthUseCase::login", level = "debug", skip_all)]
     pub(crate) async fn login(
         &self,
         request: LoginDto,
@@ -98,80 +260,102 @@ impl AuthUseCase {
         device: String,
         location: GeoLocation,
     ) -> Result<Response<LoginResponse>, Status> {
-        let login_req = request;
+        let user = self
+            .check_password(
+                &request.email,
+                &request.password,
+                self.tenant(&request.org_id),
+            )
+            .await?;
 
-        if let Some(user) = self
+        let org_id = parse_org_id(&request.org_id);
+        if let Some(org_id) = org_id {
+            self.ensure_member(&user, org_id).await?;
+        }
+
+        let data = self
+            .start_session(&user, org_id, ip.clone(), device.clone(), location)
+            .await?;
+        self.publish_logged_in(
+            &user,
+            org_id.or(user.org_id),
+            LoginMethod::Password,
+            ip,
+            device,
+        )
+        .await;
+
+        info!("User logged in successfully: {}", user.email);
+        Ok(Response::new(LoginResponse {
+            message: "Login successful".to_string(),
+            data: Some(data),
+        }))
+    }
+
+    /// Checks an email and password, publishing `LoginFailed` for every refused attempt. Every
+    /// sign-in that takes a password goes through here so they are monitored alike.
+    pub(crate) async fn check_password(
+        &self,
+        email: &str,
+        password: &str,
+        tenant: Option<Uuid>,
+    ) -> Result<User, Status> {
+        let user = self
             .adapter
-            .find_by_coll("email", &login_req.email)
+            .find_by_email(email, tenant)
             .await
             .map_err(|_| {
-                error!("Failed to query user with email: {}", login_req.email);
+                error!("Failed to query user with email: {}", email);
                 Status::not_found("Failed to query user")
-            })?
-        {
-            if user.status != UserStatus::Active {
-                error!("User with email {} is not active", login_req.email);
-                return Err(Status::permission_denied("Verify your email first"));
-            }
-
-            let password_valid = verify_password_async(&login_req.password, &user.password).await;
-
-            return if let Ok(true) = password_valid {
-                let (access_token, refresh_token) = Token::create_tokens(user.id.to_string())
-                    .await
-                    .map_err(|_| {
-                        error!("Failed to generate tokens");
-                        Status::internal("Failed to generate tokens")
-                    })?;
-
-                let user_json = serde_json::to_string(&user).map_err(|_| {
-                    error!("Failed to serialize user");
-                    Status::internal("Failed to serialize user")
-                })?;
+            })?;
 
-                self.redis_adapter
-                    .set_value(&access_token, &user_json)
-                    .await
-                    .expect("Failed to set value in Redis at Login");
-                info!("Redis set value for user: {}", user_json);
-
-                let response = LoginResponse {
-                    message: "Login successful".to_string(),
-                    data: Some(LoginData {
-                        access_token,
-                        refresh_token,
-                    }),
-                };
-
-                let user_session = UserSessions::new(
-                    user.id,
-                    ip.clone().parse().expect("Invalid IP address"),
-                    device.clone(),
-                    serde_json::to_string(&location).unwrap_or_default(),
-                );
+        let Some(user) = user else {
+            self.publish_login_failed(None, LoginFailure::InvalidCredentials)
+                .await;
+            error!("Invalid email or password for user: {}", email);
+            return Err(Status::unauthenticated("Invalid email or password"));
+        };
 
-                self.session.save(&user_session).await.map_err(|e| {
-                    error!("Failed to save user session: {}", e);
-                    Status::internal("Failed to save user session")
-                })?;
+        if user.status != UserStatus::Active {
+            self.record_inactive_login(&user).await;
+            error!("User with email {} is not active", email);
+            return Err(Status::permission_denied("Verify your email first"));
+        }
 
-                info!("User logged in successfully: {}", user.email);
-                Ok(Response::new(response))
-            } else {
-                if let Err(e) = password_valid {
-                    error!("Failed to verify password: {}", e);
-                } else {
-                    error!("Invalid password or email for user: {}", login_req.email);
+        match verify_password_async(password, &user.password).await {
+            Ok(true) => Ok(user),
+            result => {
+                self.publish_login_failed(Some(user.id), LoginFailure::InvalidCredentials)
+                    .await;
+                match result {
+                    Err(e) => error!("Failed to verify password: {}", e),
+                    _ => error!("Invalid password or email for user: {}", email),
                 }
-
                 Err(Status::unauthenticated("Invalid password or email"))
-            };
+            }
         }
+    }
 
-        error!("Invalid email or password for user: {}", login_req.email);
-        Err(Status::unauthenticated("Invalid email or password"))
+    pub(crate) async fn publish_logged_in(
+        &self,
+        user: &User,
+        org_id: Option<Uuid>,
+        method: LoginMethod,
+        ip: String,
+        device: String,
+    ) {
+        self.events
+            .publish(DomainEvent::LoggedIn {
+                user_id: user.id,
+                org_id,
+                method,
+                ip,
+                device,
+            })
+            .await;
     }
 
+    #[instrument(name = "AuthUseCase::logout", level = "debug", skip_all)]
     pub(crate) async fn logout(
         &self,
         request: LogoutDto,
@@ -184,8 +368,8 @@ impl AuthUseCase {
             .ensure_not_blacklisted(refresh_token)
             .await?;
 
-        let config = cfg();
-        Token::validate_token(refresh_token, &config.refresh_secret).map_err(|e| {
+        let config = &self.config;
+        let claims = Token::validate_token(refresh_token, &config.refresh_secret).map_err(|e| {
             error!("Invalid refresh token: {}", e);
             Status::unauthenticated("Invalid refresh token")
         })?;
@@ -200,6 +384,12 @@ impl AuthUseCase {
 
         self.redis_adapter.blacklist_token(&access_token).await.ok();
 
+        if let Ok(user_id) = Uuid::parse_str(claims.subject()) {
+            self.events
+                .publish(DomainEvent::LoggedOut { user_id })
+                .await;
+        }
+
         info!("Logout success: {}", refresh_token);
 
         Ok(Response::new(LogoutResponse {
@@ -207,13 +397,15 @@ impl AuthUseCase {
         }))
     }
 
+    #[instrument(name = "AuthUseCase::send_otp", level = "debug", skip_all)]
     pub(crate) async fn send_otp(
         &self,
         request: SendOtpDto,
+        request_locale: Option<String>,
     ) -> Result<Response<SendOtpResponse>, Status> {
         let existing_user = self
             .adapter
-            .find_by_coll("email", &request.email)
+            .find_by_email(&request.email, self.tenant(&request.org_id))
             .await
             .map_err(|e| {
                 error!(
@@ -223,15 +415,27 @@ impl AuthUseCase {
                 Status::internal("Failed to lookup user")
             })?;
 
-        if existing_user.is_none() {
+        let user = existing_user.ok_or_else(|| {
             error!("User with email {} does not exist", request.email);
-            return Err(Status::not_found("User not found"));
-        }
+            Status::not_found("User not found")
+        })?;
+
+        let phone_number = match request.channel.as_str() {
+            "sms" => Some(
+                user.verified_phone_number()
+                    .ok_or_else(|| {
+                        error!("User {} has no verified phone number", request.email);
+                        Status::failed_precondition("No verified phone number on this account")
+                    })?
+                    .to_string(),
+            ),
+            _ => None,
+        };
 
-        let otp_helper = email_otp();
+        let otp_helper = &self.otp;
 
         let otp_code = otp_helper.generate_code(6);
-        let otp_key = format!("otp:{}", request.email);
+        let otp_key = otp_key(&request.email, self.tenant(&request.org_id));
         self.redis_adapter
             .set_value(&otp_key, &otp_code)
             .await
@@ -242,32 +446,50 @@ impl AuthUseCase {
 
         info!("OTP generated for user: {}", request.email);
 
-        let email_bg = request.email.clone();
-
-        tokio::spawn(async move {
-            let email_sender = email();
-            match email_sender.send_otp_email(&email_bg, &otp_code).await {
-                Ok(_) => info!("Background OTP email sent successfully to: {}", email_bg),
-                Err(e) => error!("Background OTP email failed for {}: {}", email_bg, e),
-            }
-        });
+        let channel = if phone_number.is_some() {
+            OtpChannel::Sms
+        } else {
+            OtpChannel::Email
+        };
+        let payload = match phone_number {
+            Some(phone_number) => OutboxPayload::OtpSms {
+                phone_number,
+                otp_key,
+            },
+            None => OutboxPayload::OtpEmail {
+                recipient: request.email.clone(),
+                otp_key,
+                locale: user.locale.or(request_locale),
+            },
+        };
+        self.outbox
+            .enqueue(&OutboxMessage::new(payload))
+            .await
+            .map_err(|e| {
+                error!("Failed to enqueue OTP for {}: {}", request.email, e);
+                Status::internal("Failed to send OTP")
+            })?;
 
-        info!(
-            "OTP request response sent immediately for: {}",
-            request.email
-        );
+        self.events
+            .publish(DomainEvent::OtpSent {
+                user_id: user.id,
+                channel,
+            })
+            .await;
+        info!("OTP queued for: {}", request.email);
         Ok(Response::new(SendOtpResponse {
             message: "OTP request sent successfully".to_string(),
         }))
     }
 
+    #[instrument(name = "AuthUseCase::verify_email", level = "debug", skip_all)]
     pub(crate) async fn verify_email(
         &self,
         request: VerifyEmailDto,
     ) -> Result<Response<VerifyEmailResponse>, Status> {
         let existing_user = self
             .adapter
-            .find_by_coll("email", &request.email)
+            .find_by_email(&request.email, self.tenant(&request.org_id))
             .await
             .map_err(|e| {
                 error!(
@@ -282,7 +504,7 @@ impl AuthUseCase {
             return Err(Status::not_found("User not found"));
         }
 
-        let otp_key = format!("otp:{}", request.email);
+        let otp_key = otp_key(&request.email, self.tenant(&request.org_id));
         let existing_otp = self.redis_adapter.get_value(&otp_key).await.map_err(|e| {
             error!("Failed to get OTP from Redis: {}", e);
             Status::internal("Failed to get OTP")
@@ -297,11 +519,18 @@ impl AuthUseCase {
         }
 
         let user = existing_user.unwrap();
+        let event = email_verified(&user);
+        let messages = self.events.outbox_messages(&event).await;
 
-        self.adapter.verify_email(user.id).await.map_err(|e| {
-            error!("Failed to verify email for user {}: {}", user.email, e);
-            Status::internal("Failed to verify email")
-        })?;
+        self.adapter
+            .verify_email(user.id, &messages)
+            .await
+            .map_err(|e| {
+                error!("Failed to verify email for user {}: {}", user.email, e);
+                Status::internal("Failed to verify email")
+            })?;
+
+        self.events.publish_committed(event).await;
 
         self.redis_adapter
             .delete_value(&otp_key)
@@ -318,13 +547,14 @@ impl AuthUseCase {
         }))
     }
 
+    #[instrument(name = "AuthUseCase::forgot_password", level = "debug", skip_all)]
     pub(crate) async fn forgot_password(
         &self,
         request: ForgotPasswordDto,
     ) -> Result<Response<ForgotPasswordResponse>, Status> {
         let existing_user = self
             .adapter
-            .find_by_coll("email", &request.email)
+            .find_by_email(&request.email, self.tenant(&request.org_id))
             .await
             .map_err(|e| {
                 error!(
@@ -346,7 +576,7 @@ impl AuthUseCase {
             ));
         }
 
-        let otp_key = format!("otp:{}", request.email);
+        let otp_key = otp_key(&request.email, self.tenant(&request.org_id));
         let existing_otp = self.redis_adapter.get_value(&otp_key).await.map_err(|e| {
             error!("Failed to get OTP from Redis: {}", e);
             Status::internal("Failed to get OTP")
@@ -384,9 +614,1345 @@ impl AuthUseCase {
                 Status::internal("Failed to remove OTP")
             })?;
 
+        self.events
+            .publish(DomainEvent::PasswordReset { user_id: user.id })
+            .await;
+
         info!("Password reset successfully for email: {}", request.email);
         Ok(Response::new(ForgotPasswordResponse {
             message: "Password reset successfully".to_string(),
         }))
     }
+
+    /// Stores an unverified phone number and texts it a code to confirm ownership.
+    #[instrument(name = "AuthUseCase::update_phone_number", level = "debug", skip_all)]
+    pub(crate) async fn update_phone_number(
+        &self,
+        user_id: Uuid,
+        request: UpdatePhoneNumberDto,
+    ) -> Result<Response<UpdatePhoneNumberResponse>, Status> {
+        self.adapter
+            .set_phone_number(user_id, &request.phone_number)
+            .await
+            .map_err(|e| {
+                error!("Failed to update phone number of user {}: {}", user_id, e);
+                Status::internal("Failed to update phone number")
+            })?;
+
+        let otp_code = self.otp.generate_code(6);
+        let otp_key = format!("phone_otp:{}", user_id);
+        self.redis_adapter
+            .set_value_with_ttl(&otp_key, &otp_code, PHONE_OTP_TTL_SECS)
+            .await
+            .map_err(|e| {
+                error!("Failed to set phone OTP in Redis: {}", e);
+                Status::internal("Failed to set OTP")
+            })?;
+
+        let message = OutboxMessage::new(OutboxPayload::OtpSms {
+            phone_number: request.phone_number,
+            otp_key,
+        });
+        self.outbox.enqueue(&message).await.map_err(|e| {
+            error!("Failed to enqueue phone OTP for user {}: {}", user_id, e);
+            Status::internal("Failed to send OTP")
+        })?;
+
+        self.events
+            .publish(DomainEvent::OtpSent {
+                user_id,
+                channel: OtpChannel::Sms,
+            })
+            .await;
+        info!("Phone verification code queued for user: {}", user_id);
+
+        Ok(Response::new(UpdatePhoneNumberResponse {
+            message: "Verification code sent to the new phone number".to_string(),
+        }))
+    }
+
+    #[instrument(name = "AuthUseCase::verify_phone_number", level = "debug", skip_all)]
+    pub(crate) async fn verify_phone_number(
+        &self,
+        user_id: Uuid,
+        request: VerifyPhoneNumberDto,
+    ) -> Result<Response<VerifyPhoneNumberResponse>, Status> {
+        let otp_key = format!("phone_otp:{}", user_id);
+        let existing_otp = self.redis_adapter.get_value(&otp_key).await.map_err(|e| {
+            error!("Failed to get phone OTP from Redis: {}", e);
+            Status::internal("Failed to get OTP")
+        })?;
+
+        if existing_otp.as_deref() != Some(request.otp.as_str()) {
+            error!("Phone OTP verification failed for user {}", user_id);
+            return Err(Status::invalid_argument("Invalid OTP code"));
+        }
+
+        self.adapter
+            .verify_phone_number(user_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to verify phone number of user {}: {}", user_id, e);
+                Status::internal("Failed to verify phone number")
+            })?;
+
+        self.redis_adapter
+            .delete_value(&otp_key)
+            .await
+            .map_err(|e| {
+                error!("Failed to remove phone OTP from Redis: {}", e);
+                Status::internal("Failed to remove OTP")
+            })?;
+
+        self.events
+            .publish(DomainEvent::PhoneNumberVerified { user_id })
+            .await;
+
+        Ok(Response::new(VerifyPhoneNumberResponse {
+            message: "Phone number verified successfully".to_string(),
+        }))
+    }
+
+    #[instrument(name = "AuthUseCase::begin_external_login", level = "debug", skip_all)]
+    pub(crate) async fn begin_external_login(
+        &self,
+        request: BeginExternalLoginDto,
+    ) -> Result<Response<BeginExternalLoginResponse>, Status> {
+        let provider = request.provider.to_lowercase();
+
+        if !self.external_identity.has_provider(&provider) {
+            error!("Unsupported identity provider: {}", provider);
+            return Err(Status::invalid_argument("Unsupported identity provider"));
+        }
+
+        let state = generate_random_string(32);
+        let code_verifier = generate_random_string(64);
+        let nonce = generate_random_string(32);
+
+        let authorization_url = self
+            .external_identity
+            .authorization_url(&provider, &state, &nonce, &pkce_challenge(&code_verifier))
+            .await
+            .map_err(|e| {
+                error!("Failed to build authorization url for {}: {}", provider, e);
+                Status::unavailable("Identity provider is unavailable")
+            })?;
+
+        let state_json = serde_json::to_string(&ExternalLoginState {
+            provider: provider.clone(),
+            code_verifier,
+            nonce,
+        })
+        .map_err(|_| {
+            error!("Failed to serialize external login state");
+            Status::internal("Failed to serialize external login state")
+        })?;
+
+        self.redis_adapter
+            .set_value_with_ttl(
+                &format!("oidc_state:{}", state),
+                &state_json,
+                EXTERNAL_LOGIN_STATE_TTL_SECS,
+            )
+            .await
+            .map_err(|e| {
+                error!("Failed to set external login state in Redis: {}", e);
+                Status::internal("Failed to start external login")
+            })?;
+
+        info!("External login started with provider: {}", provider);
+
+        Ok(Response::new(BeginExternalLoginResponse {
+            message: "External login started".to_string(),
+            data: Some(BeginExternalLoginData {
+                authorization_url,
+                state,
+            }),
+        }))
+    }
+
+    #[instrument(
+        name = "AuthUseCase::complete_external_login",
+        level = "debug",
+        skip_all
+    )]
+    pub(crate) async fn complete_external_login(
+        &self,
+        request: CompleteExternalLoginDto,
+        ip: String,
+        device: String,
+        location: GeoLocation,
+    ) -> Result<Response<CompleteExternalLoginResponse>, Status> {
+        let state_json = self
+            .redis_adapter
+            .pull_value(&format!("oidc_state:{}", request.state))
+            .await
+            .map_err(|e| {
+                error!("Failed to get external login state from Redis: {}", e);
+                Status::internal("Failed to get external login state")
+            })?
+            .ok_or_else(|| {
+                error!("Unknown or expired external login state");
+                Status::invalid_argument("Invalid or expired login state")
+            })?;
+
+        let state: ExternalLoginState = serde_json::from_str(&state_json).map_err(|_| {
+            error!("Failed to deserialize external login state");
+            Status::internal("Failed to deserialize external login state")
+        })?;
+
+        let identity = self
+            .external_identity
+            .exchange_code(
+                &state.provider,
+                &request.code,
+                &state.code_verifier,
+                &state.nonce,
+            )
+            .await
+            .map_err(|e| {
+                error!("Code exchange with {} failed: {}", state.provider, e);
+                Status::unauthenticated("External login failed")
+            })?;
+
+        let user = self.resolve_external_user(&identity).await?;
+
+        if user.status != UserStatus::Active {
+            self.record_inactive_login(&user).await;
+            error!("User with email {} is not active", user.email);
+            return Err(Status::permission_denied("Account is not active"));
+        }
+
+        let data = self
+            .start_session(&user, None, ip.clone(), device.clone(), location)
+            .await?;
+        self.events
+            .publish(DomainEvent::LoggedIn {
+                user_id: user.id,
+                org_id: user.org_id,
+                method: LoginMethod::External(identity.provider.clone()),
+                ip,
+                device,
+            })
+            .await;
+
+        info!(
+            "User logged in with {} successfully: {}",
+            identity.provider, user.email
+        );
+        Ok(Response::new(CompleteExternalLoginResponse {
+            message: "Login successful".to_string(),
+            data: Some(data),
+        }))
+    }
+
+    #[instrument(
+        name = "AuthUseCase::begin_passkey_registration",
+        level = "debug",
+        skip_all
+    )]
+    pub(crate) async fn begin_passkey_registration(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Response<BeginPasskeyRegistrationResponse>, Status> {
+        let user = self
+            .adapter
+            .find_by_id(user_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to query user {}: {}", user_id, e);
+                Status::internal("Failed to query user")
+            })?
+            .ok_or_else(|| Status::not_found("User not found"))?;
+
+        let existing = self.user_passkeys(user.id).await?;
+        let (options_json, state_json) = self.webauthn.start_registration(&user, &existing)?;
+
+        self.redis_adapter
+            .set_value_with_ttl(
+                &format!("passkey_reg:{}", user.id),
+                &state_json,
+                PASSKEY_CHALLENGE_TTL_SECS,
+            )
+            .await
+            .map_err(|e| {
+                error!("Failed to set passkey registration state in Redis: {}", e);
+                Status::internal("Failed to start passkey registration")
+            })?;
+
+        info!("Passkey registration started for user: {}", user.email);
+
+        Ok(Response::new(BeginPasskeyRegistrationResponse {
+            message: "Passkey registration started".to_string(),
+            data: Some(BeginPasskeyRegistrationData { options_json }),
+        }))
+    }
+
+    #[instrument(
+        name = "AuthUseCase::finish_passkey_registration",
+        level = "debug",
+        skip_all
+    )]
+    pub(crate) async fn finish_passkey_registration(
+        &self,
+        user_id: Uuid,
+        request: FinishPasskeyRegistrationDto,
+    ) -> Result<Response<FinishPasskeyRegistrationResponse>, Status> {
+        let state_json = self
+            .redis_adapter
+            .pull_value(&format!("passkey_reg:{}", user_id))
+            .await
+            .map_err(|e| {
+                error!("Failed to get passkey registration state from Redis: {}", e);
+                Status::internal("Failed to get passkey registration state")
+            })?
+            .ok_or_else(|| {
+                error!("No pending passkey registration for user: {}", user_id);
+                Status::failed_precondition("No pending passkey registration")
+            })?;
+
+        let registered = self
+            .webauthn
+            .finish_registration(&request.credential_json, &state_json)?;
+
+        let passkey = PasskeyCredential::new(
+            user_id,
+            request.name,
+            registered.credential_id,
+            registered.credential,
+            registered.sign_count,
+        );
+
+        self.passkeys.save(&passkey).await.map_err(|e| {
+            error!("Failed to save passkey credential: {}", e);
+            Status::internal("Failed to save passkey")
+        })?;
+
+        self.events
+            .publish(DomainEvent::PasskeyRegistered {
+                user_id,
+                passkey_id: passkey.id,
+            })
+            .await;
+
+        info!("Passkey registered for user: {}", user_id);
+
+        Ok(Response::new(FinishPasskeyRegistrationResponse {
+            message: "Passkey registered successfully".to_string(),
+            data: Some(FinishPasskeyRegistrationData {
+                id: passkey.id.to_string(),
+                name: passkey.name,
+                created_at: passkey.created_at.to_rfc3339(),
+            }),
+        }))
+    }
+
+    #[instrument(name = "AuthUseCase::begin_passkey_login", level = "debug", skip_all)]
+    pub(crate) async fn begin_passkey_login(
+        &self,
+        request: BeginPasskeyLoginDto,
+    ) -> Result<Response<BeginPasskeyLoginResponse>, Status> {
+        let user = self
+            .adapter
+            .find_by_email(&request.email, self.tenant(&request.org_id))
+            .await
+            .map_err(|e| {
+                error!(
+                    "Database error during user lookup for email {}: {}",
+                    request.email, e
+                );
+                Status::internal("Failed to lookup user")
+            })?;
+
+        // Unknown users and users without passkeys get the same answer to avoid account probing.
+        let Some(user) = user else {
+            error!("User with email {} does not exist", request.email);
+            return Err(Status::failed_precondition("No passkey available"));
+        };
+
+        let credentials = self.user_passkeys(user.id).await?;
+        if credentials.is_empty() {
+            error!("User {} has no registered passkeys", user.email);
+            return Err(Status::failed_precondition("No passkey available"));
+        }
+
+        let (options_json, state) = self.webauthn.start_authentication(&credentials)?;
+
+        let session_id = generate_random_string(32);
+        let state_json = serde_json::to_string(&PasskeyLoginState {
+            user_id: user.id,
+            state,
+        })
+        .map_err(|_| {
+            error!("Failed to serialize passkey login state");
+            Status::internal("Failed to serialize passkey login state")
+        })?;
+
+        self.redis_adapter
+            .set_value_with_ttl(
+                &format!("passkey_auth:{}", session_id),
+                &state_json,
+                PASSKEY_CHALLENGE_TTL_SECS,
+            )
+            .await
+            .map_err(|e| {
+                error!("Failed to set passkey login state in Redis: {}", e);
+                Status::internal("Failed to start passkey login")
+            })?;
+
+        info!("Passkey login started for user: {}", user.email);
+
+        Ok(Response::new(BeginPasskeyLoginResponse {
+            message: "Passkey login started".to_string(),
+            data: Some(BeginPasskeyLoginData {
+                options_json,
+                session_id,
+            }),
+        }))
+    }
+
+    #[instrument(name = "AuthUseCase::finish_passkey_login", level = "debug", skip_all)]
+    pub(crate) async fn finish_passkey_login(
+        &self,
+        request: FinishPasskeyLoginDto,
+        ip: String,
+        device: String,
+        location: GeoLocation,
+    ) -> Result<Response<FinishPasskeyLoginResponse>, Status> {
+        let state_json = self
+            .redis_adapter
+            .pull_value(&format!("passkey_auth:{}", request.session_id))
+            .await
+            .map_err(|e| {
+                error!("Failed to get passkey login state from Redis: {}", e);
+                Status::internal("Failed to get passkey login state")
+            })?
+            .ok_or_else(|| {
+                error!("Unknown or expired passkey login session");
+                Status::invalid_argument("Invalid or expired login session")
+            })?;
+
+        let login_state: PasskeyLoginState = serde_json::from_str(&state_json).map_err(|_| {
+            error!("Failed to deserialize passkey login state");
+            Status::internal("Failed to deserialize passkey login state")
+        })?;
+
+        let assertion = self
+            .webauthn
+            .finish_authentication(&request.credential_json, &login_state.state)?;
+
+        let stored = self
+            .passkeys
+            .find_by_credential_id(&assertion.credential_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to query passkey credential: {}", e);
+                Status::internal("Failed to query passkey")
+            })?
+            .filter(|c| c.user_id == login_state.user_id);
+        let Some(stored) = stored else {
+            self.publish_login_failed(Some(login_state.user_id), LoginFailure::InvalidCredentials)
+                .await;
+            error!("Passkey {} is not registered", assertion.credential_id);
+            return Err(Status::unauthenticated("Passkey login failed"));
+        };
+
+        let credential = self
+            .webauthn
+            .refresh_credential(&stored.credential, &assertion)?;
+        self.passkeys
+            .record_use(stored.id, &credential, assertion.sign_count)
+            .await
+            .map_err(|e| {
+                error!("Failed to update passkey usage: {}", e);
+                Status::internal("Failed to update passkey")
+            })?;
+
+        let user = self
+            .adapter
+            .find_by_id(stored.user_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to query user {}: {}", stored.user_id, e);
+                Status::internal("Failed to query user")
+            })?
+            .ok_or_else(|| Status::not_found("User not found"))?;
+
+        if user.status != UserStatus::Active {
+            self.record_inactive_login(&user).await;
+            error!("User with email {} is not active", user.email);
+            return Err(Status::permission_denied("Verify your email first"));
+        }
+
+        let data = self
+            .start_session(&user, None, ip.clone(), device.clone(), location)
+            .await?;
+        self.events
+            .publish(DomainEvent::LoggedIn {
+                user_id: user.id,
+                org_id: user.org_id,
+                method: LoginMethod::Passkey,
+                ip,
+                device,
+            })
+            .await;
+
+        info!("User logged in with passkey successfully: {}", user.email);
+        Ok(Response::new(FinishPasskeyLoginResponse {
+            message: "Login successful".to_string(),
+            data: Some(data),
+        }))
+    }
+
+    #[instrument(name = "AuthUseCase::switch_organization", level = "debug", skip_all)]
+    pub(crate) async fn switch_organization(
+        &self,
+        user_id: Uuid,
+        request: SwitchOrganizationDto,
+        ip: String,
+        device: String,
+        location: GeoLocation,
+    ) -> Result<Response<SwitchOrganizationResponse>, Status> {
+        let user = self
+            .adapter
+            .find_by_id(user_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to query user {}: {}", user_id, e);
+                Status::internal("Failed to query user")
+            })?
+            .ok_or_else(|| Status::not_found("User not found"))?;
+
+        let org_id = parse_org_id(&request.org_id);
+        if let Some(org_id) = org_id {
+            self.find_organization(org_id).await?;
+            self.ensure_member(&user, org_id).await?;
+        }
+
+        let data = self
+            .start_session(&user, org_id, ip, device, location)
+            .await?;
+        self.events
+            .publish(DomainEvent::OrganizationSwitched {
+                user_id: user.id,
+                org_id: org_id.or(user.org_id),
+            })
+            .await;
+
+        info!(
+            "User {} switched to organization: {}",
+            user.email,
+            org_id.map(|id| id.to_string()).unwrap_or_default()
+        );
+        Ok(Response::new(SwitchOrganizationResponse {
+            message: "Organization switched successfully".to_string(),
+            data: Some(data),
+        }))
+    }
+
+    async fn find_organization(&self, org_id: Uuid) -> Result<Organization, Status> {
+        self.organizations
+            .find_by_id(org_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to query organization {}: {}", org_id, e);
+                Status::internal("Failed to query organization")
+            })?
+            .ok_or_else(|| {
+                error!("Organization {} does not exist", org_id);
+                Status::not_found("Organization not found")
+            })
+    }
+
+    /// Users belong to their own tenant implicitly and to other organizations by membership.
+    async fn ensure_member(&self, user: &User, org_id: Uuid) -> Result<(), Status> {
+        if user.org_id == Some(org_id) {
+            return Ok(());
+        }
+
+        let membership = self
+            .memberships
+            .find_membership(org_id, user.id)
+            .await
+            .map_err(|e| {
+                error!("Failed to query membership of user {}: {}", user.id, e);
+                Status::internal("Failed to query membership")
+            })?;
+
+        if membership.is_none() {
+            error!(
+                "User {} is not a member of organization {}",
+                user.email, org_id
+            );
+            return Err(Status::permission_denied(
+                "You are not a member of this organization",
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn user_passkeys(&self, user_id: Uuid) -> Result<Vec<PasskeyCredential>, Status> {
+        self.passkeys.list_by_user(user_id).await.map_err(|e| {
+            error!("Failed to list passkeys for user {}: {}", user_id, e);
+            Status::internal("Failed to list passkeys")
+        })
+    }
+
+    /// Finds the user linked to an external identity, linking by verified email or creating
+    /// a new active account when no link exists yet.
+    async fn resolve_external_user(&self, identity: &ExternalIdentity) -> Result<User, Status> {
+        let linked = self
+            .identities
+            .find_by_provider_subject(&identity.provider, &identity.subject)
+            .await
+            .map_err(|e| {
+                error!("Failed to query user identity: {}", e);
+                Status::internal("Failed to query user identity")
+            })?;
+
+        if let Some(linked) = linked {
+            return self
+                .adapter
+                .find_by_id(linked.user_id)
+                .await
+                .map_err(|e| {
+                    error!("Failed to query user {}: {}", linked.user_id, e);
+                    Status::internal("Failed to query user")
+                })?
+                .ok_or_else(|| Status::not_found("User not found"));
+        }
+
+        let email = match (&identity.email, identity.email_verified) {
+            (Some(email), true) => email.clone(),
+            _ => {
+                error!(
+                    "{} identity {} has no verified email",
+                    identity.provider, identity.subject
+                );
+                return Err(Status::failed_precondition(
+                    "Identity provider did not return a verified email",
+                ));
+            }
+        };
+
+        // External logins carry no organization, so they sign in to the default tenant; accounts
+        // in other tenants that share the email are separate people.
+        let existing_user = self
+            .adapter
+            .find_by_email(&email, None)
+            .await
+            .map_err(|e| {
+                error!(
+                    "Database error during user lookup for email {}: {}",
+                    email, e
+                );
+                Status::internal("Failed to lookup user")
+            })?;
+
+        let mut user = match existing_user {
+            Some(user) => user,
+            None => {
+                let hashed_password = unusable_password().await?;
+
+                let name = identity
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| email.split('@').next().unwrap_or_default().to_string());
+                let user = User::new(name, email.clone(), hashed_password, UserStatus::Inactive);
+                let event = registered(&user);
+                let messages = self.events.outbox_messages(&event).await;
+
+                self.adapter
+                    .register(&user, None, &messages)
+                    .await
+                    .map_err(|e| {
+                        error!("Failed to save user: {}", e);
+                        Status::internal("Failed to save user")
+                    })?;
+                self.events.publish_committed(event).await;
+
+                info!("User registered with {}: {}", identity.provider, email);
+                user
+            }
+        };
+
+        // The provider has already proven mailbox ownership, so no OTP round-trip is needed.
+        // Whoever registered the unverified account never proved it, so their password must not
+        // survive activation. Inactive accounts cannot log in, so they hold no sessions to revoke.
+        if user.status == UserStatus::Inactive {
+            user.password = unusable_password().await?;
+            self.adapter
+                .update_password(user.id, &user)
+                .await
+                .map_err(|e| {
+                    error!("Failed to reset password for user {}: {}", user.email, e);
+                    Status::internal("Failed to reset password")
+                })?;
+            let event = email_verified(&user);
+            let messages = self.events.outbox_messages(&event).await;
+            self.adapter
+                .verify_email(user.id, &messages)
+                .await
+                .map_err(|e| {
+                    error!("Failed to verify email for user {}: {}", user.email, e);
+                    Status::internal("Failed to verify email")
+                })?;
+            user.status = UserStatus::Active;
+            self.events.publish_committed(event).await;
+        }
+
+        let link = UserIdentity::new(
+            user.id,
+            identity.provider.clone(),
+            identity.subject.clone(),
+            Some(email),
+        );
+        self.identities.save(&link).await.map_err(|e| {
+            error!("Failed to link {} identity: {}", identity.provider, e);
+            Status::internal("Failed to link identity")
+        })?;
+
+        info!(
+            "Linked {} identity to user: {}",
+            identity.provider, user.email
+        );
+        Ok(user)
+    }
+
+    /// Issues tokens for `user`. Tenant-scoped users always act inside their own organization
+    /// unless another one they belong to is selected.
+    async fn start_session(
+        &self,
+        user: &User,
+        org_id: Option<Uuid>,
+        ip: String,
+        device: String,
+        location: GeoLocation,
+    ) -> Result<LoginData, Status> {
+        let org_id = org_id.or(user.org_id).map(|id| id.to_string());
+        let (access_token, refresh_token) =
+            Token::create_tokens(&self.config, user.id.to_string(), org_id)
+                .await
+                .map_err(|_| {
+                    error!("Failed to generate tokens");
+                    Status::internal("Failed to generate tokens")
+                })?;
+
+        let user_json = serde_json::to_string(user).map_err(|_| {
+            error!("Failed to serialize user");
+            Status::internal("Failed to serialize user")
+        })?;
+
+        self.redis_adapter
+            .set_value(&access_token, &user_json)
+            .await
+            .expect("Failed to set value in Redis at Login");
+        info!("Redis set value for user: {}", user_json);
+
+        let user_session = UserSessions::new(
+            user.id,
+            ip.parse().expect("Invalid IP address"),
+            device,
+            serde_json::to_string(&location).unwrap_or_default(),
+        );
+
+        self.session.save(&user_session).await.map_err(|e| {
+            error!("Failed to save user session: {}", e);
+            Status::internal("Failed to save user session")
+        })?;
+
+        Ok(LoginData {
+            access_token,
+            refresh_token,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::service::id_token_service::IdTokenService;
+    use crate::infrastructure::notification::memory_adapter::MemoryAdapter;
+    use crate::infrastructure::notification::webhook_sms_adapter::WebhookSmsAdapter;
+    use crate::test_support::{Fakes, SmsGateway, Table, test_config};
+    use crate::util::util::verify_password_async;
+    use axum::extract::State;
+    use axum::routing::{get, post};
+    use axum::{Form, Json, Router};
+    use serde_json::{Value, json};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use webauthn_authenticator_rs::WebauthnAuthenticator;
+    use webauthn_authenticator_rs::prelude::Url;
+    use webauthn_authenticator_rs::softpasskey::SoftPasskey;
+
+    const CLIENT_ID: &str = "mock-client";
+
+    /// The account the mock issuer signs in.
+    #[derive(Clone)]
+    struct MockAccount {
+        subject: &'static str,
+        email: &'static str,
+    }
+
+    #[derive(Clone)]
+    struct MockIssuer {
+        url: String,
+        account: MockAccount,
+        signer: Arc<std::sync::Mutex<Arc<IdTokenService>>>,
+        jwks_fetches: Arc<AtomicUsize>,
+    }
+
+    impl MockIssuer {
+        fn signer(&self) -> Arc<IdTokenService> {
+            self.signer.lock().unwrap().clone()
+        }
+
+        /// Starts signing with a key under another id, as an issuer rotating keys would.
+        fn rotate_key(&self) {
+            let mut config = test_config("");
+            config.oidc_signing_key_id = "rotated".to_string();
+            *self.signer.lock().unwrap() = Arc::new(IdTokenService::new(&config).unwrap());
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct TokenForm {
+        code: String,
+    }
+
+    /// Serves discovery, JWKS, token and userinfo endpoints on a local port, like a real
+    /// OpenID Connect provider. Its authorization codes are the nonce they were issued for.
+    async fn start_issuer(account: MockAccount) -> MockIssuer {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let issuer = MockIssuer {
+            url: url.clone(),
+            account,
+            signer: Arc::new(std::sync::Mutex::new(Arc::new(
+                IdTokenService::new(&test_config("")).unwrap(),
+            ))),
+            jwks_fetches: Arc::new(AtomicUsize::new(0)),
+        };
+
+        let app = Router::new()
+            .route(
+                "/.well-known/openid-configuration",
+                get(|State(issuer): State<MockIssuer>| async move {
+                    Json(json!({
+                        "issuer": issuer.url,
+                        "authorization_endpoint": format!("{}/authorize", issuer.url),
+                        "token_endpoint": format!("{}/token", issuer.url),
+                        "userinfo_endpoint": format!("{}/userinfo", issuer.url),
+                        "jwks_uri": format!("{}/jwks", issuer.url),
+                    }))
+                }),
+            )
+            .route(
+                "/jwks",
+                get(|State(issuer): State<MockIssuer>| async move {
+                    issuer.jwks_fetches.fetch_add(1, Ordering::SeqCst);
+                    Json(issuer.signer().jwks())
+                }),
+            )
+            .route(
+                "/token",
+                post(
+                    |State(issuer): State<MockIssuer>, Form(form): Form<TokenForm>| async move {
+                        let now = chrono::Utc::now().timestamp();
+                        let id_token = issuer
+                            .signer()
+                            .sign(&json!({
+                                "iss": issuer.url,
+                                "aud": CLIENT_ID,
+                                "sub": issuer.account.subject,
+                                "nonce": form.code,
+                                "iat": now,
+                                "exp": now + 300,
+                            }))
+                            .unwrap();
+                        Json(json!({
+                            "access_token": "mock-access-token",
+                            "token_type": "Bearer",
+                            "id_token": id_token,
+                        }))
+                    },
+                ),
+            )
+            .route(
+                "/userinfo",
+                get(|State(issuer): State<MockIssuer>| async move {
+                    Json::<Value>(json!({
+                        "sub": issuer.account.subject,
+                        "email": issuer.account.email,
+                        "email_verified": true,
+                        "name": "Mock User",
+                    }))
+                }),
+            )
+            .with_state(issuer.clone());
+
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        issuer
+    }
+
+    fn provider_config(provider: &str) -> Arc<EnvConfig> {
+        Arc::new(test_config(&format!(
+            r#"
+oidc_providers = "mock"
+oidc_mock_client_id = "{}"
+oidc_mock_client_secret = "mock-secret"
+{}
+"#,
+            CLIENT_ID, provider
+        )))
+    }
+
+    async fn harness(account: MockAccount, users: Vec<User>) -> (AuthUseCase, Fakes, MockIssuer) {
+        let issuer = start_issuer(account).await;
+        let config = provider_config(&format!("oidc_mock_issuer = \"{}\"", issuer.url));
+
+        let fakes = Fakes {
+            users: Arc::new(Table::with(users)),
+            ..Fakes::default()
+        };
+        (fakes.auth_use_case(&config), fakes, issuer)
+    }
+
+    /// Runs an external login, answering the authorization request with `code` or, by default,
+    /// with the code the mock issuer grants for the request's nonce.
+    async fn sign_in_with(
+        auth: &AuthUseCase,
+        code: Option<&str>,
+    ) -> Result<CompleteExternalLoginResponse, Status> {
+        let begin = auth
+            .begin_external_login(BeginExternalLoginDto {
+                provider: "mock".to_string(),
+            })
+            .await?
+            .into_inner()
+            .data
+            .unwrap();
+        let nonce = Url::parse(&begin.authorization_url)
+            .unwrap()
+            .query_pairs()
+            .find(|(key, _)| key == "nonce")
+            .map(|(_, nonce)| nonce.into_owned())
+            .unwrap();
+
+        auth.complete_external_login(
+            CompleteExternalLoginDto {
+                state: begin.state,
+                code: code.map(String::from).unwrap_or(nonce),
+            },
+            "127.0.0.1".to_string(),
+            "test".to_string(),
+            location(),
+        )
+        .await
+        .map(Response::into_inner)
+    }
+
+    async fn sign_in(auth: &AuthUseCase) -> Result<CompleteExternalLoginResponse, Status> {
+        sign_in_with(auth, None).await
+    }
+
+    async fn user_with_password(email: &str, password: &str, status: UserStatus) -> User {
+        let hashed = hash_password_async(password.to_string()).await.unwrap();
+        User::new("Existing".to_string(), email.to_string(), hashed, status)
+    }
+
+    #[tokio::test]
+    async fn first_external_login_creates_an_active_account() {
+        let account = MockAccount {
+            subject: "subject-1",
+            email: "new@example.com",
+        };
+        let (auth, fakes, _) = harness(account, Vec::new()).await;
+
+        let response = sign_in(&auth).await.unwrap();
+
+        assert!(response.data.is_some());
+        let users = fakes.users.all();
+        assert_eq!(users.len(), 1);
+        assert_eq!(users[0].email, "new@example.com");
+        assert_eq!(users[0].status, UserStatus::Active);
+        let identities = fakes.identities.all();
+        assert_eq!(identities.len(), 1);
+        assert_eq!(identities[0].user_id, users[0].id);
+        assert_eq!(identities[0].subject, "subject-1");
+
+        // The second sign-in follows the link instead of creating another account.
+        sign_in(&auth).await.unwrap();
+        assert_eq!(fakes.users.all().len(), 1);
+        assert_eq!(fakes.identities.all().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn external_login_links_an_existing_active_account() {
+        let existing =
+            user_with_password("linked@example.com", "hunter22", UserStatus::Active).await;
+        let account = MockAccount {
+            subject: "subject-2",
+            email: "linked@example.com",
+        };
+        let (auth, fakes, _) = harness(account, vec![existing.clone()]).await;
+
+        sign_in(&auth).await.unwrap();
+
+        assert_eq!(fakes.users.all().len(), 1);
+        let identities = fakes.identities.all();
+        assert_eq!(identities.len(), 1);
+        assert_eq!(identities[0].user_id, existing.id);
+        // The owner proved the password themselves, so it keeps working.
+        let user = fakes.users.get(existing.id).unwrap();
+        assert!(
+            verify_password_async("hunter22", &user.password)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn external_login_ignores_accounts_in_other_tenants() {
+        let mut tenant_user =
+            user_with_password("shared@example.com", "hunter22", UserStatus::Active).await;
+        tenant_user.org_id = Some(Uuid::new_v4());
+        let account = MockAccount {
+            subject: "subject-4",
+            email: "shared@example.com",
+        };
+        let (auth, fakes, _) = harness(account, vec![tenant_user.clone()]).await;
+
+        sign_in(&auth).await.unwrap();
+
+        let users = fakes.users.all();
+        assert_eq!(users.len(), 2);
+        let identities = fakes.identities.all();
+        assert_eq!(identities.len(), 1);
+        assert_ne!(identities[0].user_id, tenant_user.id);
+        assert_eq!(fakes.users.get(identities[0].user_id).unwrap().org_id, None);
+    }
+
+    #[tokio::test]
+    async fn external_login_discards_the_password_of_an_unverified_account() {
+        let squatted =
+            user_with_password("victim@example.com", "attacker", UserStatus::Inactive).await;
+        let account = MockAccount {
+            subject: "subject-3",
+            email: "victim@example.com",
+        };
+        let (auth, fakes, _) = harness(account, vec![squatted.clone()]).await;
+
+        sign_in(&auth).await.unwrap();
+
+        let user = fakes.users.get(squatted.id).unwrap();
+        assert_eq!(user.status, UserStatus::Active);
+        assert!(
+            !verify_password_async("attacker", &user.password)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn external_login_rejects_an_id_token_issued_for_another_login() {
+        let account = MockAccount {
+            subject: "subject-5",
+            email: "replay@example.com",
+        };
+        let (auth, fakes, _) = harness(account, Vec::new()).await;
+
+        let status = sign_in_with(&auth, Some("nonce-of-another-login"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+        assert!(fakes.users.all().is_empty());
+    }
+
+    #[tokio::test]
+    async fn issuer_keys_are_cached_until_an_unknown_key_appears() {
+        let account = MockAccount {
+            subject: "subject-6",
+            email: "rotate@example.com",
+        };
+        let (auth, _, issuer) = harness(account, Vec::new()).await;
+
+        sign_in(&auth).await.unwrap();
+        sign_in(&auth).await.unwrap();
+        assert_eq!(issuer.jwks_fetches.load(Ordering::SeqCst), 1);
+
+        issuer.rotate_key();
+        sign_in(&auth).await.unwrap();
+        assert_eq!(issuer.jwks_fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn id_tokens_from_a_provider_without_jwks_are_rejected() {
+        let issuer = start_issuer(MockAccount {
+            subject: "subject-7",
+            email: "nokeys@example.com",
+        })
+        .await;
+        let config = provider_config(&format!(
+            "oidc_mock_auth_url = \"{0}/authorize\"\noidc_mock_token_url = \"{0}/token\"\noidc_mock_userinfo_url = \"{0}/userinfo\"",
+            issuer.url
+        ));
+        let fakes = Fakes::default();
+        let auth = fakes.auth_use_case(&config);
+
+        let status = sign_in(&auth).await.unwrap_err();
+
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+        assert!(fakes.users.all().is_empty());
+    }
+
+    #[tokio::test]
+    async fn phone_number_is_verified_with_the_code_texted_through_the_gateway() {
+        let fakes = Fakes::default();
+        let user = user_with_password("phone@example.com", "hunter22", UserStatus::Active).await;
+        fakes.users.with_row(user.clone());
+        let config = Arc::new(test_config(""));
+        let auth = fakes.auth_use_case(&config);
+        let gateway = SmsGateway::start(axum::http::StatusCode::OK).await;
+        let outbox = fakes.outbox_use_case_with_sms(
+            &config,
+            Arc::new(MemoryAdapter::new()),
+            Arc::new(WebhookSmsAdapter::new(gateway.url.clone(), None)),
+        );
+
+        auth.update_phone_number(
+            user.id,
+            UpdatePhoneNumberDto {
+                phone_number: "+6281234567890".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(outbox.dispatch_due().await.unwrap(), 1);
+
+        let received = gateway.received();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].body["to"], json!("+6281234567890"));
+        let text = received[0].body["body"].as_str().unwrap();
+        let code = text.rsplit(' ').next().unwrap().to_string();
+        assert!(
+            fakes
+                .users
+                .get(user.id)
+                .unwrap()
+                .phone_verified_at
+                .is_none()
+        );
+
+        let wrong = auth
+            .verify_phone_number(
+                user.id,
+                VerifyPhoneNumberDto {
+                    otp: "000000".to_string(),
+                },
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(wrong.code(), tonic::Code::InvalidArgument);
+
+        auth.verify_phone_number(user.id, VerifyPhoneNumberDto { otp: code })
+            .await
+            .unwrap();
+        let user = fakes.users.get(user.id).unwrap();
+        assert_eq!(user.phone_number.as_deref(), Some("+6281234567890"));
+        assert!(user.phone_verified_at.is_some());
+    }
+
+    const RP_ORIGIN: &str = "http://localhost:3000";
+
+    fn location() -> GeoLocation {
+        GeoLocation {
+            city: String::new(),
+            country: String::new(),
+            region: String::new(),
+            latitude: 0.0,
+            longitude: 0.0,
+        }
+    }
+
+    /// An active user with a software passkey registered through the use case.
+    async fn registered_passkey() -> (AuthUseCase, Fakes, User, WebauthnAuthenticator<SoftPasskey>)
+    {
+        let fakes = Fakes::default();
+        let user = user_with_password("passkey@example.com", "hunter22", UserStatus::Active).await;
+        fakes.users.with_row(user.clone());
+        let auth = fakes.auth_use_case(&Arc::new(test_config("")));
+        let mut authenticator = WebauthnAuthenticator::new(SoftPasskey::new(true));
+
+        let options = auth
+            .begin_passkey_registration(user.id)
+            .await
+            .unwrap()
+            .into_inner()
+            .data
+            .unwrap()
+            .options_json;
+        let credential = authenticator
+            .do_registration(
+                Url::parse(RP_ORIGIN).unwrap(),
+                serde_json::from_str(&options).unwrap(),
+            )
+            .unwrap();
+
+        auth.finish_passkey_registration(
+            user.id,
+            FinishPasskeyRegistrationDto {
+                name: "laptop".to_string(),
+                credential_json: serde_json::to_string(&credential).unwrap(),
+            },
+        )
+        .await
+        .unwrap();
+
+        (auth, fakes, user, authenticator)
+    }
+
+    /// Ceremony options as a phishing site relaying them would present them: the authenticator
+    /// only signs for the relying party id of the page it runs on.
+    fn relayed<T: serde::de::DeserializeOwned>(options: &str, origin: &str) -> T {
+        let mut options: Value = serde_json::from_str(options).unwrap();
+        let rp_id = json!(Url::parse(origin).unwrap().host_str().unwrap());
+        let public_key = &mut options["publicKey"];
+        if public_key.get("rp").is_some() {
+            public_key["rp"]["id"] = rp_id;
+        } else {
+            public_key["rpId"] = rp_id;
+        }
+        serde_json::from_value(options).unwrap()
+    }
+
+    async fn passkey_login(
+        auth: &AuthUseCase,
+        authenticator: &mut WebauthnAuthenticator<SoftPasskey>,
+        origin: &str,
+    ) -> Result<FinishPasskeyLoginResponse, Status> {
+        let begin = auth
+            .begin_passkey_login(BeginPasskeyLoginDto {
+                email: "passkey@example.com".to_string(),
+                org_id: String::new(),
+            })
+            .await?
+            .into_inner()
+            .data
+            .unwrap();
+        let credential = authenticator
+            .do_authentication(
+                Url::parse(origin).unwrap(),
+                relayed(&begin.options_json, origin),
+            )
+            .unwrap();
+
+        auth.finish_passkey_login(
+            FinishPasskeyLoginDto {
+                session_id: begin.session_id,
+                credential_json: serde_json::to_string(&credential).unwrap(),
+            },
+            "127.0.0.1".to_string(),
+            "test".to_string(),
+            location(),
+        )
+        .await
+        .map(Response::into_inner)
+    }
+
+    #[tokio::test]
+    async fn passkey_registers_and_signs_in() {
+        let (auth, fakes, user, mut authenticator) = registered_passkey().await;
+
+        let passkeys = fakes.passkeys.all();
+        assert_eq!(passkeys.len(), 1);
+        assert_eq!(passkeys[0].user_id, user.id);
+
+        let response = passkey_login(&auth, &mut authenticator, RP_ORIGIN)
+            .await
+            .unwrap();
+        assert!(response.data.is_some());
+        let first = fakes.passkeys.all().remove(0);
+        assert!(first.last_used_at.is_some());
+
+        passkey_login(&auth, &mut authenticator, RP_ORIGIN)
+            .await
+            .unwrap();
+        let second = fakes.passkeys.all().remove(0);
+        assert!(second.sign_count > first.sign_count);
+    }
+
+    #[tokio::test]
+    async fn passkey_registration_from_another_origin_is_rejected() {
+        let fakes = Fakes::default();
+        let user = user_with_password("passkey@example.com", "hunter22", UserStatus::Active).await;
+        fakes.users.with_row(user.clone());
+        let auth = fakes.auth_use_case(&Arc::new(test_config("")));
+        let mut authenticator = WebauthnAuthenticator::new(SoftPasskey::new(true));
+
+        let options = auth
+            .begin_passkey_registration(user.id)
+            .await
+            .unwrap()
+            .into_inner()
+            .data
+            .unwrap()
+            .options_json;
+        let credential = authenticator
+            .do_registration(
+                Url::parse("https://evil.example").unwrap(),
+                relayed(&options, "https://evil.example"),
+            )
+            .unwrap();
+
+        let status = auth
+            .finish_passkey_registration(
+                user.id,
+                FinishPasskeyRegistrationDto {
+                    name: "laptop".to_string(),
+                    credential_json: serde_json::to_string(&credential).unwrap(),
+                },
+            )
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert!(fakes.passkeys.all().is_empty());
+    }
+
+    #[tokio::test]
+    async fn passkey_assertion_from_another_origin_is_rejected() {
+        let (auth, fakes, _, mut authenticator) = registered_passkey().await;
+
+        let status = passkey_login(&auth, &mut authenticator, "https://evil.example")
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+        assert!(fakes.sessions.all().is_empty());
+    }
+
+    #[tokio::test]
+    async fn passkey_assertion_with_a_stale_counter_is_rejected() {
+        let (auth, fakes, _, mut authenticator) = registered_passkey().await;
+        passkey_login(&auth, &mut authenticator, RP_ORIGIN)
+            .await
+            .unwrap();
+
+        // Pretend a clone of this authenticator already signed in many times.
+        let mut stored = fakes.passkeys.all().remove(0);
+        let mut credential: Value = serde_json::from_str(&stored.credential).unwrap();
+        credential["cred"]["counter"] = json!(100);
+        stored.credential = credential.to_string();
+        fakes.passkeys.update(stored.id, &stored).await.unwrap();
+
+        let status = passkey_login(&auth, &mut authenticator, RP_ORIGIN)
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+        assert_eq!(fakes.sessions.all().len(), 1);
+    }
 }
@@ -0,0 +1,138 @@
+use crate::config::env::EnvConfig;
+use crate::domain::dto::auth_dto::ClientCredentialsTokenDto;
+use crate::domain::port::db::service_client_port::ServiceClientPort;
+use crate::domain::service::jwt_service::Token;
+use crate::pb::auth::{ClientCredentialsTokenData, ClientCredentialsTokenResponse};
+use crate::util::util::verify_password_async;
+use std::sync::Arc;
+use tonic::{Response, Status};
+use tracing::{error, info};
+
+pub struct ClientCredentialsUseCase {
+    clients: Arc<dyn ServiceClientPort + Send + Sync>,
+    config: Arc<EnvConfig>,
+}
+
+impl ClientCredentialsUseCase {
+    pub fn new(clients: Arc<dyn ServiceClientPort + Send + Sync>, config: Arc<EnvConfig>) -> Self {
+        ClientCredentialsUseCase { clients, config }
+    }
+}
+
+impl ClientCredentialsUseCase {
+    pub(crate) async fn issue_token(
+        &self,
+        request: ClientCredentialsTokenDto,
+    ) -> Result<Response<ClientCredentialsTokenResponse>, Status> {
+        let client = self
+            .clients
+            .find_by_client_id(&request.client_id)
+            .await
+            .map_err(|e| {
+                error!(
+                    "Failed to query service client {}: {}",
+                    request.client_id, e
+                );
+                Status::internal("Failed to query client")
+            })?
+            .ok_or_else(|| {
+                error!("Unknown service client: {}", request.client_id);
+                Status::unauthenticated("Invalid client credentials")
+            })?;
+
+        match verify_password_async(&request.client_secret, &client.client_secret_hash).await {
+            Ok(true) => {}
+            Ok(false) => {
+                error!("Invalid secret for service client: {}", request.client_id);
+                return Err(Status::unauthenticated("Invalid client credentials"));
+            }
+            Err(e) => {
+                error!("Failed to verify client secret: {}", e);
+                return Err(Status::internal("Failed to verify client credentials"));
+            }
+        }
+
+        let requested: Vec<String> = request.scope.split_whitespace().map(String::from).collect();
+
+        let scopes = if requested.is_empty() {
+            client.allowed_scopes.clone()
+        } else if let Some(scope) = requested
+            .iter()
+            .find(|s| !client.allowed_scopes.contains(s))
+        {
+            error!(
+                "Service client {} requested disallowed scope {}",
+                client.client_id, scope
+            );
+            return Err(Status::permission_denied(format!(
+                "Scope {} is not allowed for this client",
+                scope
+            )));
+        } else {
+            requested
+        };
+
+        let scope = scopes.join(" ");
+        let access_token =
+            Token::create_client_token(&self.config, client.client_id.clone(), scope.clone())
+                .await
+                .map_err(|_| {
+                    error!("Failed to generate client token");
+                    Status::internal("Failed to generate token")
+                })?;
+
+        info!("Client token issued to: {}", client.client_id);
+
+        Ok(Response::new(ClientCredentialsTokenResponse {
+            message: "Token issued successfully".to_string(),
+            data: Some(ClientCredentialsTokenData {
+                access_token,
+                token_type: "Bearer".to_string(),
+                expires_in: self.config.client_token_duration,
+                scope,
+            }),
+        }))
+    }
+
+    /// Authorizes an internal caller by its mutual TLS certificate: the certificate's common
+    /// name must be a registered service client id allowed `scope`. Returns the client id.
+    pub(crate) async fn authorize_certificate(
+        &self,
+        common_name: Option<&str>,
+        scope: &str,
+    ) -> Result<String, Status> {
+        let denied = || {
+            Status::permission_denied(format!(
+                "A client certificate for a service client with the {} scope is required",
+                scope
+            ))
+        };
+
+        let client_id = common_name.ok_or_else(denied)?;
+        let client = self
+            .clients
+            .find_by_client_id(client_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to query service client {}: {}", client_id, e);
+                Status::internal("Failed to query client")
+            })?
+            .ok_or_else(|| {
+                error!(
+                    "Client certificate for unknown service client: {}",
+                    client_id
+                );
+                denied()
+            })?;
+
+        if !client.allowed_scopes.iter().any(|s| s == scope) {
+            error!(
+                "Service client {} presented a certificate without scope {}",
+                client_id, scope
+            );
+            return Err(denied());
+        }
+
+        Ok(client.client_id)
+    }
+}
@@ -0,0 +1,114 @@
+use crate::config::env::EnvConfig;
+use crate::domain::port::health_check_port::HealthCheckPort;
+use futures::future::join_all;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::time::timeout;
+use tonic_health::ServingStatus;
+use tonic_health::server::HealthReporter;
+use tracing::{info, warn};
+
+/// Drives `grpc.health.v1.Health`. Each dependency is reported under its own name. The overall
+/// status (`""`) and every served gRPC service only stay SERVING while all dependencies pass.
+#[derive(Clone)]
+pub struct HealthUseCase {
+    reporter: HealthReporter,
+    services: Vec<&'static str>,
+    checks: Vec<Arc<dyn HealthCheckPort>>,
+    shutting_down: Arc<AtomicBool>,
+    config: Arc<EnvConfig>,
+}
+
+impl HealthUseCase {
+    pub fn new(
+        reporter: HealthReporter,
+        services: Vec<&'static str>,
+        checks: Vec<Arc<dyn HealthCheckPort>>,
+        config: Arc<EnvConfig>,
+    ) -> Self {
+        HealthUseCase {
+            reporter,
+            services,
+            checks,
+            shutting_down: Arc::new(AtomicBool::new(false)),
+            config,
+        }
+    }
+}
+
+fn status(healthy: bool) -> ServingStatus {
+    if healthy {
+        ServingStatus::Serving
+    } else {
+        ServingStatus::NotServing
+    }
+}
+
+impl HealthUseCase {
+    /// Probes every dependency forever, once per `HEALTH_CHECK_INTERVAL`.
+    pub async fn run_probes(self) {
+        let interval = Duration::from_secs(self.config.health_check_interval);
+        let mut last_healthy = None;
+
+        while !self.shutting_down.load(Ordering::SeqCst) {
+            let healthy = self.probe().await;
+            if last_healthy != Some(healthy) {
+                info!(
+                    "Server is {}",
+                    if healthy { "serving" } else { "not serving" }
+                );
+                last_healthy = Some(healthy);
+            }
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    async fn probe(&self) -> bool {
+        let probe_timeout = Duration::from_secs(self.config.health_check_timeout);
+        let results = join_all(self.checks.iter().map(|check| async move {
+            let healthy = match timeout(probe_timeout, check.check()).await {
+                Ok(Ok(())) => true,
+                Ok(Err(e)) => {
+                    warn!("Health check {} failed: {}", check.name(), e);
+                    false
+                }
+                Err(_) => {
+                    warn!("Health check {} timed out", check.name());
+                    false
+                }
+            };
+            (check.name(), healthy)
+        }))
+        .await;
+
+        // A shutdown may have started while the checks were running.
+        if self.shutting_down.load(Ordering::SeqCst) {
+            return false;
+        }
+
+        for (name, healthy) in &results {
+            self.reporter
+                .set_service_status(*name, status(*healthy))
+                .await;
+        }
+
+        let healthy = results.iter().all(|(_, healthy)| *healthy);
+        self.set_services(status(healthy)).await;
+        healthy
+    }
+
+    /// Reports NOT_SERVING for good so load balancers stop routing here before the server stops.
+    pub async fn mark_shutting_down(&self) {
+        self.shutting_down.store(true, Ordering::SeqCst);
+        self.set_services(ServingStatus::NotServing).await;
+        info!("Health status set to not serving for shutdown");
+    }
+
+    async fn set_services(&self, status: ServingStatus) {
+        self.reporter.set_service_status("", status).await;
+        for service in &self.services {
+            self.reporter.set_service_status(*service, status).await;
+        }
+    }
+}
@@ -0,0 +1,663 @@
+use crate::application::auth_use_case::{email_verified, registered};
+use crate::config::env::EnvConfig;
+use crate::domain::dto::auth_dto::{
+    AcceptInvitationDto, InviteMemberDto, ListInvitationsDto, RevokeInvitationDto,
+};
+use crate::domain::entity::invitation::Invitation;
+use crate::domain::entity::membership::{Membership, MembershipRole};
+use crate::domain::entity::organization::Organization;
+use crate::domain::entity::outbox_message::{OutboxMessage, OutboxPayload};
+use crate::domain::entity::user::{User, UserStatus};
+use crate::domain::port::db::invitation_port::InvitationPort;
+use crate::domain::port::db::membership_port::MembershipPort;
+use crate::domain::port::db::organization_port::OrganizationPort;
+use crate::domain::port::db::user_port::UserPort;
+use crate::domain::service::event_bus_service::EventBus;
+use crate::domain::service::jwt_service::{SubjectType, Token};
+use crate::pb::auth::{
+    AcceptInvitationData, AcceptInvitationResponse, Invitation as InvitationResponse,
+    InviteMemberData, InviteMemberResponse, ListInvitationsData, ListInvitationsResponse,
+    RevokeInvitationResponse, User as UserResponse,
+};
+use crate::util::util::{generate_random_string, hash_password_async};
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use std::time::SystemTime;
+use tonic::{Response, Status};
+use tracing::{error, info};
+use uuid::Uuid;
+
+pub struct InvitationUseCase {
+    invitations: Arc<dyn InvitationPort + Send + Sync>,
+    organizations: Arc<dyn OrganizationPort + Send + Sync>,
+    memberships: Arc<dyn MembershipPort + Send + Sync>,
+    users: Arc<dyn UserPort + Send + Sync>,
+    events: Arc<EventBus>,
+    config: Arc<EnvConfig>,
+}
+
+impl InvitationUseCase {
+    pub fn new(
+        invitations: Arc<dyn InvitationPort + Send + Sync>,
+        organizations: Arc<dyn OrganizationPort + Send + Sync>,
+        memberships: Arc<dyn MembershipPort + Send + Sync>,
+        users: Arc<dyn UserPort + Send + Sync>,
+        events: Arc<EventBus>,
+        config: Arc<EnvConfig>,
+    ) -> Self {
+        InvitationUseCase {
+            invitations,
+            organizations,
+            memberships,
+            users,
+            events,
+            config,
+        }
+    }
+}
+
+impl From<Invitation> for InvitationResponse {
+    fn from(invitation: Invitation) -> Self {
+        InvitationResponse {
+            id: invitation.id.to_string(),
+            org_id: invitation.org_id.to_string(),
+            status: invitation.status().to_string(),
+            email: invitation.email,
+            role: invitation.role.as_str().to_string(),
+            expires_at: invitation.expires_at.to_rfc3339(),
+            created_at: invitation.created_at.to_rfc3339(),
+        }
+    }
+}
+
+async fn hash_password(password: String) -> Result<String, Status> {
+    hash_password_async(password).await.map_err(|e| {
+        error!("Failed to hash password: {}", e);
+        Status::internal("Failed to hash password")
+    })
+}
+
+fn parse_id(id: &str, what: &str) -> Result<Uuid, Status> {
+    Uuid::parse_str(id).map_err(|_| Status::invalid_argument(format!("Invalid {} id", what)))
+}
+
+impl InvitationUseCase {
+    /// Invited accounts live in the organization's tenant when tenant-scoped users are enabled.
+    fn invitation_tenant(&self, org_id: Uuid) -> Option<Uuid> {
+        self.config.tenant_scoped_users.then_some(org_id)
+    }
+
+    pub(crate) async fn invite_member(
+        &self,
+        user_id: Uuid,
+        request: InviteMemberDto,
+        request_locale: Option<String>,
+    ) -> Result<Response<InviteMemberResponse>, Status> {
+        let org_id = parse_id(&request.org_id, "organization")?;
+        let organization = self.find_organization(org_id).await?;
+        let inviter_role = self.ensure_manager(org_id, user_id).await?;
+
+        let role = MembershipRole::from_str(&request.role)
+            .ok_or_else(|| Status::invalid_argument("Invalid role"))?;
+        if role == MembershipRole::Owner && inviter_role != MembershipRole::Owner {
+            error!("User {} cannot invite owners to {}", user_id, org_id);
+            return Err(Status::permission_denied("Only owners can invite owners"));
+        }
+
+        let existing_user = self
+            .users
+            .find_by_email(&request.email, self.invitation_tenant(org_id))
+            .await
+            .map_err(|e| {
+                error!(
+                    "Database error during user lookup for email {}: {}",
+                    request.email, e
+                );
+                Status::internal("Failed to lookup user")
+            })?;
+
+        let locale = existing_user
+            .as_ref()
+            .and_then(|user| user.locale.clone())
+            .or(request_locale);
+
+        if let Some(existing_user) = existing_user {
+            let membership = self
+                .memberships
+                .find_membership(org_id, existing_user.id)
+                .await
+                .map_err(|e| {
+                    error!("Failed to query membership: {}", e);
+                    Status::internal("Failed to query membership")
+                })?;
+            if membership.is_some() {
+                error!("{} is already a member of {}", request.email, org_id);
+                return Err(Status::already_exists("User is already a member"));
+            }
+        }
+
+        let pending = self
+            .invitations
+            .find_pending(org_id, &request.email)
+            .await
+            .map_err(|e| {
+                error!("Failed to query pending invitations: {}", e);
+                Status::internal("Failed to query invitations")
+            })?;
+        if pending.is_some() {
+            error!(
+                "Invitation for {} to {} already pending",
+                request.email, org_id
+            );
+            return Err(Status::already_exists(
+                "An invitation is already pending for this email",
+            ));
+        }
+
+        let expires_at = Utc::now() + Duration::seconds(self.config.invitation_duration);
+        let invitation = Invitation::new(org_id, request.email, role, user_id, expires_at);
+
+        let token = Token::create_invitation_token(
+            &self.config,
+            invitation.id.to_string(),
+            SystemTime::from(expires_at),
+        )
+        .await?;
+        let invitation_link = format!("{}?token={}", self.config.invitation_url, token);
+
+        let inviter = self
+            .users
+            .find_by_id(user_id)
+            .await
+            .ok()
+            .flatten()
+            .map(|user| user.name)
+            .unwrap_or_else(|| organization.name.clone());
+
+        let message = OutboxMessage::new(OutboxPayload::InvitationEmail {
+            recipient: invitation.email.clone(),
+            organization: organization.name,
+            inviter,
+            invitation_link,
+            locale,
+        });
+
+        self.invitations
+            .create_with_message(&invitation, &message)
+            .await
+            .map_err(|e| {
+                error!("Failed to save invitation: {}", e);
+                Status::internal("Failed to save invitation")
+            })?;
+
+        info!(
+            "User {} invited {} to organization {}",
+            user_id, invitation.email, org_id
+        );
+
+        Ok(Response::new(InviteMemberResponse {
+            message: "Invitation sent successfully".to_string(),
+            data: Some(InviteMemberData {
+                invitation: Some(invitation.into()),
+            }),
+        }))
+    }
+
+    pub(crate) async fn list_invitations(
+        &self,
+        user_id: Uuid,
+        request: ListInvitationsDto,
+    ) -> Result<Response<ListInvitationsResponse>, Status> {
+        let org_id = parse_id(&request.org_id, "organization")?;
+        self.ensure_manager(org_id, user_id).await?;
+
+        let invitations = self.invitations.list_by_org(org_id).await.map_err(|e| {
+            error!("Failed to list invitations for {}: {}", org_id, e);
+            Status::internal("Failed to list invitations")
+        })?;
+
+        Ok(Response::new(ListInvitationsResponse {
+            message: "Invitations retrieved successfully".to_string(),
+            data: Some(ListInvitationsData {
+                invitations: invitations.into_iter().map(Into::into).collect(),
+            }),
+        }))
+    }
+
+    pub(crate) async fn revoke_invitation(
+        &self,
+        user_id: Uuid,
+        request: RevokeInvitationDto,
+    ) -> Result<Response<RevokeInvitationResponse>, Status> {
+        let id = parse_id(&request.id, "invitation")?;
+
+        let invitation = self
+            .invitations
+            .find_by_id(id)
+            .await
+            .map_err(|e| {
+                error!("Failed to query invitation {}: {}", id, e);
+                Status::internal("Failed to query invitation")
+            })?
+            .ok_or_else(|| Status::not_found("Invitation not found"))?;
+
+        self.ensure_manager(invitation.org_id, user_id).await?;
+
+        let revoked = self.invitations.revoke(id).await.map_err(|e| {
+            error!("Failed to revoke invitation {}: {}", id, e);
+            Status::internal("Failed to revoke invitation")
+        })?;
+
+        if !revoked {
+            return Err(Status::failed_precondition(
+                "Invitation is no longer pending",
+            ));
+        }
+
+        info!("Invitation {} revoked by user: {}", id, user_id);
+
+        Ok(Response::new(RevokeInvitationResponse {
+            message: "Invitation revoked successfully".to_string(),
+        }))
+    }
+
+    pub(crate) async fn accept_invitation(
+        &self,
+        request: AcceptInvitationDto,
+    ) -> Result<Response<AcceptInvitationResponse>, Status> {
+        let claims = Token::validate_token(&request.token, &self.config.secret_key)
+            .ok()
+            .filter(|claims| claims.subject_type() == SubjectType::Invitation)
+            .ok_or_else(|| {
+                error!("Invalid or expired invitation token");
+                Status::invalid_argument("Invalid or expired invitation")
+            })?;
+
+        let id = parse_id(claims.subject(), "invitation")?;
+        let invitation = self
+            .invitations
+            .find_by_id(id)
+            .await
+            .map_err(|e| {
+                error!("Failed to query invitation {}: {}", id, e);
+                Status::internal("Failed to query invitation")
+            })?
+            .filter(Invitation::is_pending)
+            .ok_or_else(|| {
+                error!("Invitation {} is no longer pending", id);
+                Status::failed_precondition("Invitation is no longer valid")
+            })?;
+
+        let user = self.resolve_invited_user(&invitation, &request).await?;
+
+        let membership = self
+            .memberships
+            .find_membership(invitation.org_id, user.id)
+            .await
+            .map_err(|e| {
+                error!("Failed to query membership: {}", e);
+                Status::internal("Failed to query membership")
+            })?;
+
+        if membership.is_none() {
+            let membership = Membership::new(invitation.org_id, user.id, invitation.role);
+            self.memberships.save(&membership).await.map_err(|e| {
+                error!(
+                    "Failed to add user {} to organization {}: {}",
+                    user.id, invitation.org_id, e
+                );
+                Status::internal("Failed to join organization")
+            })?;
+        }
+
+        self.invitations
+            .mark_accepted(invitation.id)
+            .await
+            .map_err(|e| {
+                error!(
+                    "Failed to mark invitation {} accepted: {}",
+                    invitation.id, e
+                );
+                Status::internal("Failed to accept invitation")
+            })?;
+
+        info!(
+            "User {} accepted invitation to organization {}",
+            user.email, invitation.org_id
+        );
+
+        Ok(Response::new(AcceptInvitationResponse {
+            message: "Invitation accepted successfully".to_string(),
+            data: Some(AcceptInvitationData {
+                org_id: invitation.org_id.to_string(),
+                user: Some(UserResponse {
+                    id: user.id.to_string(),
+                    name: user.name,
+                    email: user.email,
+                }),
+            }),
+        }))
+    }
+
+    /// Finds or creates the invited account. The invite link proves mailbox ownership, so the
+    /// account is activated without the OTP round-trip.
+    async fn resolve_invited_user(
+        &self,
+        invitation: &Invitation,
+        request: &AcceptInvitationDto,
+    ) -> Result<User, Status> {
+        let tenant = self.invitation_tenant(invitation.org_id);
+
+        let existing_user = self
+            .users
+            .find_by_email(&invitation.email, tenant)
+            .await
+            .map_err(|e| {
+                error!(
+                    "Database error during user lookup for email {}: {}",
+                    invitation.email, e
+                );
+                Status::internal("Failed to lookup user")
+            })?;
+
+        let mut user = match existing_user {
+            // An active account keeps the password its owner proved; it cannot be changed here.
+            Some(user) if user.status != UserStatus::Inactive => {
+                if !request.password.is_empty() {
+                    error!(
+                        "Invitation for active account {} set a password",
+                        user.email
+                    );
+                    return Err(Status::failed_precondition(
+                        "An account already exists for this email; accept without a password",
+                    ));
+                }
+                return Ok(user);
+            }
+            // Whoever registered the unverified account never proved the mailbox, so the
+            // invitee's password replaces theirs, or a random one forces a reset.
+            Some(mut user) => {
+                let password = if request.password.is_empty() {
+                    generate_random_string(64)
+                } else {
+                    request.password.clone()
+                };
+                user.password = hash_password(password).await?;
+                self.users
+                    .update_password(user.id, &user)
+                    .await
+                    .map_err(|e| {
+                        error!("Failed to reset password for user {}: {}", user.email, e);
+                        Status::internal("Failed to reset password")
+                    })?;
+                user
+            }
+            None => {
+                if request.name.is_empty() || request.password.is_empty() {
+                    return Err(Status::invalid_argument(
+                        "Name and password are required to create an account",
+                    ));
+                }
+
+                let user = User::new(
+                    request.name.clone(),
+                    invitation.email.clone(),
+                    hash_password(request.password.clone()).await?,
+                    UserStatus::Inactive,
+                )
+                .in_tenant(tenant);
+
+                let event = registered(&user);
+                let messages = self.events.outbox_messages(&event).await;
+                self.users
+                    .register(&user, None, &messages)
+                    .await
+                    .map_err(|e| {
+                        error!("Failed to save user: {}", e);
+                        Status::internal("Failed to save user")
+                    })?;
+                self.events.publish_committed(event).await;
+
+                info!("User registered through invitation: {}", user.email);
+                user
+            }
+        };
+
+        let event = email_verified(&user);
+        let messages = self.events.outbox_messages(&event).await;
+        self.users
+            .verify_email(user.id, &messages)
+            .await
+            .map_err(|e| {
+                error!("Failed to verify email for user {}: {}", user.email, e);
+                Status::internal("Failed to verify email")
+            })?;
+        self.events.publish_committed(event).await;
+        user.status = UserStatus::Active;
+
+        Ok(user)
+    }
+
+    async fn find_organization(&self, org_id: Uuid) -> Result<Organization, Status> {
+        self.organizations
+            .find_by_id(org_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to query organization {}: {}", org_id, e);
+                Status::internal("Failed to query organization")
+            })?
+            .ok_or_else(|| Status::not_found("Organization not found"))
+    }
+
+    async fn ensure_manager(&self, org_id: Uuid, user_id: Uuid) -> Result<MembershipRole, Status> {
+        let membership = self
+            .memberships
+            .find_membership(org_id, user_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to query membership of user {}: {}", user_id, e);
+                Status::internal("Failed to query membership")
+            })?;
+
+        match membership {
+            Some(membership) if membership.role.can_manage_members() => Ok(membership.role),
+            _ => {
+                error!("User {} cannot manage members of {}", user_id, org_id);
+                Err(Status::permission_denied(
+                    "Only organization owners and admins can manage invitations",
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{Fakes, RecordingSubscriber, test_config};
+    use crate::util::util::verify_password_async;
+
+    const INVITEE: &str = "invitee@example.com";
+    const PASSWORD: &str = "Invitee#2024";
+
+    /// A pending invitation to a fresh organization and the token from its email.
+    async fn invitation(fakes: &Fakes, config: &EnvConfig) -> (Invitation, String) {
+        let invitation = Invitation::new(
+            Uuid::new_v4(),
+            INVITEE.to_string(),
+            MembershipRole::Member,
+            Uuid::new_v4(),
+            Utc::now() + Duration::hours(1),
+        );
+        fakes.invitations.with_row(invitation.clone());
+        let token = Token::create_invitation_token(
+            config,
+            invitation.id.to_string(),
+            SystemTime::from(invitation.expires_at),
+        )
+        .await
+        .unwrap();
+        (invitation, token)
+    }
+
+    async fn existing_user(fakes: &Fakes, password: &str, status: UserStatus) -> User {
+        let hashed = hash_password_async(password.to_string()).await.unwrap();
+        let user = User::new("Existing".to_string(), INVITEE.to_string(), hashed, status);
+        fakes.users.with_row(user.clone());
+        user
+    }
+
+    fn accept(token: &str, password: &str) -> AcceptInvitationDto {
+        AcceptInvitationDto {
+            token: token.to_string(),
+            name: "Invitee".to_string(),
+            password: password.to_string(),
+        }
+    }
+
+    async fn password_matches(fakes: &Fakes, user_id: Uuid, password: &str) -> bool {
+        let user = fakes.users.get(user_id).unwrap();
+        verify_password_async(password, &user.password)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn accepting_creates_an_active_member() {
+        let config = Arc::new(test_config(""));
+        let fakes = Fakes::default();
+        let (invitation, token) = invitation(&fakes, &config).await;
+
+        fakes
+            .invitation_use_case(&config)
+            .accept_invitation(accept(&token, PASSWORD))
+            .await
+            .unwrap();
+
+        let user = fakes.users.all().remove(0);
+        assert_eq!(user.status, UserStatus::Active);
+        assert!(password_matches(&fakes, user.id, PASSWORD).await);
+        let membership = fakes.memberships.all().remove(0);
+        assert_eq!(
+            (membership.org_id, membership.user_id),
+            (invitation.org_id, user.id)
+        );
+    }
+
+    #[tokio::test]
+    async fn accepting_replaces_the_password_of_an_unverified_account() {
+        let config = Arc::new(test_config(""));
+        let fakes = Fakes::default();
+        let squatter = existing_user(&fakes, "Squatter#2024", UserStatus::Inactive).await;
+        let (_, token) = invitation(&fakes, &config).await;
+
+        fakes
+            .invitation_use_case(&config)
+            .accept_invitation(accept(&token, PASSWORD))
+            .await
+            .unwrap();
+
+        let user = fakes.users.get(squatter.id).unwrap();
+        assert_eq!(user.status, UserStatus::Active);
+        assert!(password_matches(&fakes, user.id, PASSWORD).await);
+        assert!(!password_matches(&fakes, user.id, "Squatter#2024").await);
+    }
+
+    #[tokio::test]
+    async fn accepting_without_a_password_locks_out_the_unverified_registrant() {
+        let config = Arc::new(test_config(""));
+        let fakes = Fakes::default();
+        let squatter = existing_user(&fakes, "Squatter#2024", UserStatus::Inactive).await;
+        let (_, token) = invitation(&fakes, &config).await;
+
+        fakes
+            .invitation_use_case(&config)
+            .accept_invitation(accept(&token, ""))
+            .await
+            .unwrap();
+
+        assert!(!password_matches(&fakes, squatter.id, "Squatter#2024").await);
+    }
+
+    #[tokio::test]
+    async fn accepting_with_a_password_for_an_active_account_is_refused() {
+        let config = Arc::new(test_config(""));
+        let fakes = Fakes::default();
+        let owner = existing_user(&fakes, "Owner#2024", UserStatus::Active).await;
+        let (_, token) = invitation(&fakes, &config).await;
+        let invitations = fakes.invitation_use_case(&config);
+
+        let status = invitations
+            .accept_invitation(accept(&token, PASSWORD))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+        assert!(fakes.memberships.all().is_empty());
+
+        invitations
+            .accept_invitation(accept(&token, ""))
+            .await
+            .unwrap();
+        assert!(password_matches(&fakes, owner.id, "Owner#2024").await);
+        assert_eq!(fakes.memberships.all().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn creating_an_account_raises_registered_and_verified_events() {
+        let config = Arc::new(test_config(""));
+        let fakes = Fakes::default();
+        let (_, token) = invitation(&fakes, &config).await;
+        let subscriber = Arc::new(RecordingSubscriber::default());
+        let events = EventBus::new(fakes.outbox.clone()).subscribe(subscriber.clone());
+
+        fakes
+            .invitation_use_case_with_events(&config, events)
+            .accept_invitation(accept(&token, PASSWORD))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            subscriber.handled(),
+            vec!["user_registered", "email_verified"]
+        );
+        // The messages went in with the account changes, not through a separate enqueue.
+        assert!(fakes.outbox.all().is_empty());
+    }
+
+    #[tokio::test]
+    async fn activating_an_unverified_account_raises_a_verified_event() {
+        let config = Arc::new(test_config(""));
+        let fakes = Fakes::default();
+        existing_user(&fakes, "Squatter#2024", UserStatus::Inactive).await;
+        let (_, token) = invitation(&fakes, &config).await;
+        let subscriber = Arc::new(RecordingSubscriber::default());
+        let events = EventBus::new(fakes.outbox.clone()).subscribe(subscriber.clone());
+
+        fakes
+            .invitation_use_case_with_events(&config, events)
+            .accept_invitation(accept(&token, PASSWORD))
+            .await
+            .unwrap();
+
+        assert_eq!(subscriber.handled(), vec!["email_verified"]);
+        assert!(fakes.outbox.all().is_empty());
+    }
+
+    #[tokio::test]
+    async fn joining_with_an_active_account_raises_no_account_events() {
+        let config = Arc::new(test_config(""));
+        let fakes = Fakes::default();
+        existing_user(&fakes, "Owner#2024", UserStatus::Active).await;
+        let (_, token) = invitation(&fakes, &config).await;
+        let subscriber = Arc::new(RecordingSubscriber::default());
+        let events = EventBus::new(fakes.outbox.clone()).subscribe(subscriber.clone());
+
+        fakes
+            .invitation_use_case_with_events(&config, events)
+            .accept_invitation(accept(&token, ""))
+            .await
+            .unwrap();
+
+        assert!(subscriber.handled().is_empty());
+    }
+}
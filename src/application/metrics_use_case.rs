@@ -0,0 +1,134 @@
+use crate::domain::entity::domain_event::{DomainEvent, LoginFailure, LoginMethod};
+use crate::domain::port::event_subscriber_port::EventSubscriberPort;
+use crate::domain::port::metrics_port::MetricsPort;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Counts business events for monitoring, so use cases only publish what happened.
+#[derive(Clone)]
+pub struct MetricsUseCase {
+    metrics: Arc<dyn MetricsPort>,
+}
+
+impl MetricsUseCase {
+    pub fn new(metrics: Arc<dyn MetricsPort>) -> Self {
+        MetricsUseCase { metrics }
+    }
+}
+
+#[async_trait]
+impl EventSubscriberPort for MetricsUseCase {
+    async fn handle(&self, event: &DomainEvent) {
+        match event {
+            DomainEvent::UserRegistered { .. } => self.metrics.user_registered(),
+            DomainEvent::LoggedIn { method, .. } => {
+                let method = match method {
+                    LoginMethod::Password => "password",
+                    LoginMethod::Passkey => "passkey",
+                    LoginMethod::External(_) => "external",
+                };
+                self.metrics.login_succeeded(method);
+            }
+            DomainEvent::LoginFailed { reason, .. } => {
+                if *reason == LoginFailure::Locked {
+                    self.metrics.account_locked();
+                }
+                self.metrics.login_failed(reason.as_str());
+            }
+            DomainEvent::OtpSent { channel, .. } => self.metrics.otp_sent(channel.as_str()),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entity::domain_event::OtpChannel;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    #[derive(Default)]
+    struct RecordedMetrics(Mutex<Vec<String>>);
+
+    impl RecordedMetrics {
+        fn record(&self, metric: String) {
+            self.0.lock().unwrap().push(metric);
+        }
+    }
+
+    impl MetricsPort for RecordedMetrics {
+        fn user_registered(&self) {
+            self.record("user_registered".to_string());
+        }
+
+        fn login_succeeded(&self, method: &str) {
+            self.record(format!("login_succeeded:{}", method));
+        }
+
+        fn login_failed(&self, reason: &str) {
+            self.record(format!("login_failed:{}", reason));
+        }
+
+        fn otp_sent(&self, channel: &str) {
+            self.record(format!("otp_sent:{}", channel));
+        }
+
+        fn account_locked(&self) {
+            self.record("account_locked".to_string());
+        }
+    }
+
+    #[tokio::test]
+    async fn events_are_counted_by_kind() {
+        let metrics = Arc::new(RecordedMetrics::default());
+        let subscriber = MetricsUseCase::new(metrics.clone());
+        let user_id = Uuid::new_v4();
+        let logged_in = |method| DomainEvent::LoggedIn {
+            user_id,
+            org_id: None,
+            method,
+            ip: String::new(),
+            device: String::new(),
+        };
+
+        for event in [
+            DomainEvent::UserRegistered {
+                user_id,
+                email: "user@example.com".to_string(),
+                name: "User".to_string(),
+                org_id: None,
+            },
+            logged_in(LoginMethod::Password),
+            logged_in(LoginMethod::External("google".to_string())),
+            DomainEvent::LoginFailed {
+                user_id: None,
+                reason: LoginFailure::InvalidCredentials,
+            },
+            DomainEvent::LoginFailed {
+                user_id: Some(user_id),
+                reason: LoginFailure::Locked,
+            },
+            DomainEvent::OtpSent {
+                user_id,
+                channel: OtpChannel::Sms,
+            },
+            DomainEvent::LoggedOut { user_id },
+        ] {
+            subscriber.handle(&event).await;
+        }
+
+        assert_eq!(
+            *metrics.0.lock().unwrap(),
+            vec![
+                "user_registered",
+                "login_succeeded:password",
+                "login_succeeded:external",
+                "login_failed:invalid_credentials",
+                "account_locked",
+                "login_failed:locked",
+                "otp_sent:sms",
+            ]
+        );
+    }
+}
@@ -0,0 +1,764 @@
+use crate::application::audit_use_case::{AuditContext, AuditUseCase};
+use crate::application::auth_use_case::AuthUseCase;
+use crate::config::env::EnvConfig;
+use crate::domain::entity::audit_event::AuditAction;
+use crate::domain::entity::domain_event::LoginMethod;
+use crate::domain::entity::oauth_client::OAuthClient;
+use crate::domain::entity::oauth_consent::OAuthConsent;
+use crate::domain::entity::user::{User, UserStatus};
+use crate::domain::entity::user_info::UserInfo;
+use crate::domain::port::db::oauth_client_port::OAuthClientPort;
+use crate::domain::port::db::oauth_consent_port::OAuthConsentPort;
+use crate::domain::port::db::user_info_port::UserInfoPort;
+use crate::domain::port::db::user_port::UserPort;
+use crate::domain::port::redis_port::RedisPort;
+use crate::domain::service::id_token_service::IdTokenService;
+use crate::domain::service::jwt_service::Token;
+use crate::util::util::{generate_random_string, pkce_challenge, verify_password_async};
+use chrono::Utc;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use std::sync::Arc;
+use tonic::{Code, Status};
+use tracing::{error, info};
+use uuid::Uuid;
+
+const AUTHORIZATION_CODE_TTL_SECS: u64 = 60;
+
+/// OAuth2 error as defined in RFC 6749 section 5.2, carrying the HTTP status to respond with.
+#[derive(Debug, Serialize)]
+pub struct OAuthError {
+    pub error: &'static str,
+    pub error_description: String,
+    #[serde(skip)]
+    pub status: u16,
+}
+
+impl OAuthError {
+    fn new(error: &'static str, description: impl Into<String>, status: u16) -> Self {
+        OAuthError {
+            error,
+            error_description: description.into(),
+            status,
+        }
+    }
+
+    fn invalid_request(description: impl Into<String>) -> Self {
+        Self::new("invalid_request", description, 400)
+    }
+
+    fn server_error(description: impl Into<String>) -> Self {
+        Self::new("server_error", description, 500)
+    }
+
+    /// Maps a refused sign-in to the error the login form redisplays.
+    fn from_login(status: Status) -> Self {
+        match status.code() {
+            Code::Unauthenticated => Self::new("login_required", status.message(), 401),
+            Code::PermissionDenied => Self::new("access_denied", status.message(), 403),
+            _ => Self::server_error(status.message()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorizeParams {
+    pub response_type: String,
+    pub client_id: String,
+    pub redirect_uri: String,
+    #[serde(default)]
+    pub scope: String,
+    pub state: Option<String>,
+    pub nonce: Option<String>,
+    pub code_challenge: Option<String>,
+    pub code_challenge_method: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TokenParams {
+    pub grant_type: String,
+    pub code: Option<String>,
+    pub redirect_uri: Option<String>,
+    pub client_id: Option<String>,
+    pub client_secret: Option<String>,
+    pub code_verifier: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TokenResponse {
+    pub access_token: String,
+    pub token_type: &'static str,
+    pub expires_in: i64,
+    pub id_token: String,
+    pub scope: String,
+}
+
+pub struct AuthorizePrompt {
+    pub client_name: String,
+    pub scopes: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct AuthorizationCode {
+    client_id: String,
+    user_id: Uuid,
+    redirect_uri: String,
+    scopes: Vec<String>,
+    nonce: Option<String>,
+    code_challenge: Option<String>,
+    auth_time: i64,
+}
+
+pub struct OidcProviderUseCase {
+    auth: AuthUseCase,
+    audit: AuditUseCase,
+    users: Arc<dyn UserPort + Send + Sync>,
+    user_info: Arc<dyn UserInfoPort + Send + Sync>,
+    clients: Arc<dyn OAuthClientPort + Send + Sync>,
+    consents: Arc<dyn OAuthConsentPort + Send + Sync>,
+    redis_adapter: Arc<dyn RedisPort + Send + Sync>,
+    id_token: IdTokenService,
+    config: Arc<EnvConfig>,
+}
+
+impl OidcProviderUseCase {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        auth: AuthUseCase,
+        audit: AuditUseCase,
+        users: Arc<dyn UserPort + Send + Sync>,
+        user_info: Arc<dyn UserInfoPort + Send + Sync>,
+        clients: Arc<dyn OAuthClientPort + Send + Sync>,
+        consents: Arc<dyn OAuthConsentPort + Send + Sync>,
+        redis_adapter: Arc<dyn RedisPort + Send + Sync>,
+        config: Arc<EnvConfig>,
+    ) -> Result<Self, String> {
+        Ok(OidcProviderUseCase {
+            auth,
+            audit,
+            users,
+            user_info,
+            clients,
+            consents,
+            redis_adapter,
+            id_token: IdTokenService::new(&config)?,
+            config,
+        })
+    }
+}
+
+impl OidcProviderUseCase {
+    fn issuer(&self) -> String {
+        self.config
+            .oidc_issuer
+            .clone()
+            .unwrap_or_default()
+            .trim_end_matches('/')
+            .to_string()
+    }
+
+    pub fn discovery(&self) -> Value {
+        let issuer = self.issuer();
+        json!({
+            "issuer": issuer,
+            "authorization_endpoint": format!("{}/oauth2/authorize", issuer),
+            "token_endpoint": format!("{}/oauth2/token", issuer),
+            "userinfo_endpoint": format!("{}/oauth2/userinfo", issuer),
+            "jwks_uri": format!("{}/oauth2/jwks", issuer),
+            "response_types_supported": ["code"],
+            "grant_types_supported": ["authorization_code"],
+            "subject_types_supported": ["public"],
+            "id_token_signing_alg_values_supported": ["RS256"],
+            "scopes_supported": ["openid", "profile", "email"],
+            "token_endpoint_auth_methods_supported": ["client_secret_basic", "client_secret_post", "none"],
+            "code_challenge_methods_supported": ["S256"],
+            "claims_supported": [
+                "sub", "iss", "aud", "exp", "iat", "auth_time", "nonce", "name", "given_name",
+                "family_name", "gender", "birthdate", "email", "email_verified"
+            ],
+        })
+    }
+
+    pub fn jwks(&self) -> Value {
+        self.id_token.jwks()
+    }
+
+    /// Validates an authorization request before anything is shown to the user. Errors here
+    /// must not be redirected because the redirect URI itself may be untrusted.
+    pub async fn prompt(&self, params: &AuthorizeParams) -> Result<AuthorizePrompt, OAuthError> {
+        let (client, scopes) = self.validate_authorize(params).await?;
+
+        Ok(AuthorizePrompt {
+            client_name: client.name,
+            scopes,
+        })
+    }
+
+    /// Authenticates the resource owner like a password login, audited and reported the same
+    /// way, and once consent is on record returns the redirect URL carrying a single-use
+    /// authorization code.
+    pub async fn authorize(
+        &self,
+        params: &AuthorizeParams,
+        email: &str,
+        password: &str,
+        consent_given: bool,
+        context: AuditContext,
+    ) -> Result<String, OAuthError> {
+        let (client, scopes) = self.validate_authorize(params).await?;
+
+        // The consent page has no organization picker, so only default-tenant accounts sign in.
+        let checked = self.auth.check_password(email, password, None).await;
+        let audit = AuditContext {
+            target: Some(email.to_string()),
+            ..context.clone()
+        };
+        self.audit
+            .record_anonymous(AuditAction::Login, audit, email, "", &checked)
+            .await;
+        let user = checked.map_err(OAuthError::from_login)?;
+
+        let consent = self
+            .consents
+            .find_by_user_client(user.id, &client.client_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to query consent: {}", e);
+                OAuthError::server_error("Failed to query consent")
+            })?;
+
+        if !consent.is_some_and(|c| c.covers(&scopes)) {
+            if !consent_given {
+                return Err(OAuthError::new(
+                    "consent_required",
+                    format!("{} is requesting access to your account", client.name),
+                    403,
+                ));
+            }
+
+            let consent = OAuthConsent::new(user.id, client.client_id.clone(), scopes.clone());
+            self.consents.grant(&consent).await.map_err(|e| {
+                error!("Failed to save consent: {}", e);
+                OAuthError::server_error("Failed to save consent")
+            })?;
+            info!(
+                "User {} granted consent to {}",
+                user.email, client.client_id
+            );
+        }
+
+        let code = generate_random_string(48);
+        let authorization_code = AuthorizationCode {
+            client_id: client.client_id.clone(),
+            user_id: user.id,
+            redirect_uri: params.redirect_uri.clone(),
+            scopes,
+            nonce: params.nonce.clone(),
+            code_challenge: params.code_challenge.clone(),
+            auth_time: Utc::now().timestamp(),
+        };
+
+        let code_json = serde_json::to_string(&authorization_code)
+            .map_err(|_| OAuthError::server_error("Failed to serialize authorization code"))?;
+
+        self.redis_adapter
+            .set_value_with_ttl(
+                &format!("oidc_code:{}", code),
+                &code_json,
+                AUTHORIZATION_CODE_TTL_SECS,
+            )
+            .await
+            .map_err(|e| {
+                error!("Failed to store authorization code in Redis: {}", e);
+                OAuthError::server_error("Failed to store authorization code")
+            })?;
+
+        let mut redirect = Url::parse(&params.redirect_uri)
+            .map_err(|_| OAuthError::invalid_request("Invalid redirect_uri"))?;
+        redirect.query_pairs_mut().append_pair("code", &code);
+        if let Some(state) = &params.state {
+            redirect.query_pairs_mut().append_pair("state", state);
+        }
+
+        self.auth
+            .publish_logged_in(
+                &user,
+                user.org_id,
+                LoginMethod::Password,
+                context.ip.unwrap_or_default(),
+                context.device.unwrap_or_default(),
+            )
+            .await;
+
+        info!(
+            "Authorization code issued to {} for {}",
+            client.client_id, user.email
+        );
+        Ok(redirect.to_string())
+    }
+
+    pub async fn token(
+        &self,
+        params: TokenParams,
+        basic_credentials: Option<(String, String)>,
+    ) -> Result<TokenResponse, OAuthError> {
+        if params.grant_type != "authorization_code" {
+            return Err(OAuthError::new(
+                "unsupported_grant_type",
+                "Only authorization_code is supported",
+                400,
+            ));
+        }
+
+        let (client_id, client_secret) = match basic_credentials {
+            Some((id, secret)) => (id, Some(secret)),
+            None => (
+                params
+                    .client_id
+                    .clone()
+                    .ok_or_else(|| OAuthError::invalid_request("client_id is required"))?,
+                params.client_secret.clone(),
+            ),
+        };
+
+        let client = self.authenticate_client(&client_id, client_secret).await?;
+
+        let code = params
+            .code
+            .as_deref()
+            .ok_or_else(|| OAuthError::invalid_request("code is required"))?;
+
+        let code_json = self
+            .redis_adapter
+            .pull_value(&format!("oidc_code:{}", code))
+            .await
+            .map_err(|e| {
+                error!("Failed to get authorization code from Redis: {}", e);
+                OAuthError::server_error("Failed to get authorization code")
+            })?
+            .ok_or_else(|| {
+                OAuthError::new(
+                    "invalid_grant",
+                    "Invalid or expired authorization code",
+                    400,
+                )
+            })?;
+
+        let authorization_code: AuthorizationCode = serde_json::from_str(&code_json)
+            .map_err(|_| OAuthError::server_error("Failed to deserialize authorization code"))?;
+
+        if authorization_code.client_id != client.client_id
+            || params.redirect_uri.as_deref() != Some(authorization_code.redirect_uri.as_str())
+        {
+            error!("Authorization code presented by the wrong client or redirect uri");
+            return Err(OAuthError::new(
+                "invalid_grant",
+                "Authorization code was not issued to this client",
+                400,
+            ));
+        }
+
+        if let Some(challenge) = &authorization_code.code_challenge {
+            let verified = params
+                .code_verifier
+                .as_deref()
+                .is_some_and(|verifier| pkce_challenge(verifier) == *challenge);
+            if !verified {
+                error!("PKCE verification failed for client {}", client.client_id);
+                return Err(OAuthError::new(
+                    "invalid_grant",
+                    "PKCE verification failed",
+                    400,
+                ));
+            }
+        }
+
+        let (user, info) = self.load_user(authorization_code.user_id).await?;
+
+        let access_token = Token::create_oidc_access_token(
+            &self.config,
+            user.id.to_string(),
+            client.client_id.clone(),
+            authorization_code.scopes.join(" "),
+        )
+        .await
+        .map_err(|_| OAuthError::server_error("Failed to generate access token"))?;
+
+        let config = &self.config;
+        let now = Utc::now().timestamp();
+        let mut claims = identity_claims(&user, info.as_ref(), &authorization_code.scopes);
+        claims.insert("iss".to_string(), json!(self.issuer()));
+        claims.insert("aud".to_string(), json!(client.client_id));
+        claims.insert("iat".to_string(), json!(now));
+        claims.insert("exp".to_string(), json!(now + config.access_token_duration));
+        claims.insert("auth_time".to_string(), json!(authorization_code.auth_time));
+        if let Some(nonce) = &authorization_code.nonce {
+            claims.insert("nonce".to_string(), json!(nonce));
+        }
+
+        let id_token = self
+            .id_token
+            .sign(&Value::Object(claims))
+            .map_err(|_| OAuthError::server_error("Failed to sign ID token"))?;
+
+        info!("Tokens issued to {} for {}", client.client_id, user.email);
+
+        Ok(TokenResponse {
+            access_token,
+            token_type: "Bearer",
+            expires_in: config.access_token_duration,
+            id_token,
+            scope: authorization_code.scopes.join(" "),
+        })
+    }
+
+    pub async fn userinfo(&self, access_token: &str) -> Result<Value, OAuthError> {
+        let invalid_token = || OAuthError::new("invalid_token", "Invalid access token", 401);
+
+        self.redis_adapter
+            .ensure_not_blacklisted(access_token)
+            .await
+            .map_err(|_| invalid_token())?;
+
+        let token = Token::validate_oidc_access_token(access_token, &self.config.access_secret)
+            .map_err(|_| invalid_token())?;
+
+        let scopes: Vec<String> = token
+            .scope()
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(String::from)
+            .collect();
+
+        let user_id = Uuid::parse_str(token.subject()).map_err(|_| invalid_token())?;
+        let (user, info) = self.load_user(user_id).await?;
+
+        Ok(Value::Object(identity_claims(
+            &user,
+            info.as_ref(),
+            &scopes,
+        )))
+    }
+
+    async fn validate_authorize(
+        &self,
+        params: &AuthorizeParams,
+    ) -> Result<(OAuthClient, Vec<String>), OAuthError> {
+        let client = self
+            .clients
+            .find_by_client_id(&params.client_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to query client {}: {}", params.client_id, e);
+                OAuthError::server_error("Failed to query client")
+            })?
+            .ok_or_else(|| OAuthError::new("invalid_client", "Unknown client", 400))?;
+
+        if !client.redirect_uris.contains(&params.redirect_uri) {
+            error!(
+                "Unregistered redirect uri {} for client {}",
+                params.redirect_uri, client.client_id
+            );
+            return Err(OAuthError::invalid_request("Unregistered redirect_uri"));
+        }
+
+        if params.response_type != "code" {
+            return Err(OAuthError::new(
+                "unsupported_response_type",
+                "Only the code response type is supported",
+                400,
+            ));
+        }
+
+        let scopes: Vec<String> = params.scope.split_whitespace().map(String::from).collect();
+        if !scopes.iter().any(|s| s == "openid") {
+            return Err(OAuthError::new(
+                "invalid_scope",
+                "The openid scope is required",
+                400,
+            ));
+        }
+        if let Some(scope) = scopes.iter().find(|s| !client.allowed_scopes.contains(s)) {
+            return Err(OAuthError::new(
+                "invalid_scope",
+                format!("Scope {} is not allowed for this client", scope),
+                400,
+            ));
+        }
+
+        match (
+            &params.code_challenge,
+            params.code_challenge_method.as_deref(),
+        ) {
+            (Some(_), Some("S256")) => {}
+            (Some(_), _) => {
+                return Err(OAuthError::invalid_request(
+                    "Only the S256 code_challenge_method is supported",
+                ));
+            }
+            (None, _) if client.is_public() => {
+                return Err(OAuthError::invalid_request("Public clients must use PKCE"));
+            }
+            (None, _) => {}
+        }
+
+        Ok((client, scopes))
+    }
+
+    async fn authenticate_client(
+        &self,
+        client_id: &str,
+        client_secret: Option<String>,
+    ) -> Result<OAuthClient, OAuthError> {
+        let invalid_client =
+            || OAuthError::new("invalid_client", "Client authentication failed", 401);
+
+        let client = self
+            .clients
+            .find_by_client_id(client_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to query client {}: {}", client_id, e);
+                OAuthError::server_error("Failed to query client")
+            })?
+            .ok_or_else(invalid_client)?;
+
+        if let Some(secret_hash) = &client.client_secret_hash {
+            let secret = client_secret.ok_or_else(invalid_client)?;
+            if !matches!(verify_password_async(&secret, secret_hash).await, Ok(true)) {
+                error!("Invalid secret for client {}", client_id);
+                return Err(invalid_client());
+            }
+        }
+
+        Ok(client)
+    }
+
+    async fn load_user(&self, user_id: Uuid) -> Result<(User, Option<UserInfo>), OAuthError> {
+        let user = self
+            .users
+            .find_by_id(user_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to query user {}: {}", user_id, e);
+                OAuthError::server_error("Failed to query user")
+            })?
+            .ok_or_else(|| OAuthError::new("invalid_grant", "User no longer exists", 400))?;
+
+        let info = self
+            .user_info
+            .find_by_coll("user_id", &user_id.to_string())
+            .await
+            .map_err(|e| {
+                error!("Failed to query user info for {}: {}", user_id, e);
+                OAuthError::server_error("Failed to query user info")
+            })?;
+
+        Ok((user, info))
+    }
+}
+
+/// Standard claims released for the granted scopes, shared by ID tokens and userinfo.
+fn identity_claims(user: &User, info: Option<&UserInfo>, scopes: &[String]) -> Map<String, Value> {
+    let mut claims = Map::new();
+    claims.insert("sub".to_string(), json!(user.id.to_string()));
+
+    if scopes.iter().any(|s| s == "profile") {
+        claims.insert("name".to_string(), json!(user.name));
+        if let Some(info) = info {
+            claims.insert("given_name".to_string(), json!(info.first_name));
+            claims.insert("family_name".to_string(), json!(info.last_name));
+            claims.insert("gender".to_string(), json!(info.gender.as_str()));
+            claims.insert(
+                "birthdate".to_string(),
+                json!(info.birth_date.format("%Y-%m-%d").to_string()),
+            );
+        }
+    }
+
+    if scopes.iter().any(|s| s == "email") {
+        claims.insert("email".to_string(), json!(user.email));
+        claims.insert(
+            "email_verified".to_string(),
+            json!(user.status == UserStatus::Active),
+        );
+    }
+
+    claims
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{Fakes, test_config};
+    use crate::util::util::hash_password_async;
+    use std::time::Duration;
+
+    const CLIENT_ID: &str = "web-app";
+    const REDIRECT_URI: &str = "https://app.example/callback";
+    const EMAIL: &str = "ana@example.com";
+    const PASSWORD: &str = "Correct-horse-9";
+    const VERIFIER: &str = "verifier-verifier-verifier-verifier-verifier";
+
+    /// A provider with one active user and one public client.
+    async fn provider() -> (OidcProviderUseCase, Fakes, User) {
+        let fakes = Fakes::default();
+        let password = hash_password_async(PASSWORD.to_string()).await.unwrap();
+        let user = User::new(
+            "Ana".to_string(),
+            EMAIL.to_string(),
+            password,
+            UserStatus::Active,
+        );
+        fakes.users.with_row(user.clone());
+        fakes.oauth_clients.with_row(OAuthClient::new(
+            CLIENT_ID.to_string(),
+            None,
+            "Web App".to_string(),
+            vec![REDIRECT_URI.to_string()],
+            vec!["openid".to_string(), "email".to_string()],
+        ));
+
+        let config = Arc::new(test_config("oidc_issuer = \"https://id.example\""));
+        (fakes.oidc_provider_use_case(&config), fakes, user)
+    }
+
+    fn params() -> AuthorizeParams {
+        AuthorizeParams {
+            response_type: "code".to_string(),
+            client_id: CLIENT_ID.to_string(),
+            redirect_uri: REDIRECT_URI.to_string(),
+            scope: "openid email".to_string(),
+            state: Some("state-1".to_string()),
+            nonce: Some("nonce-1".to_string()),
+            code_challenge: Some(pkce_challenge(VERIFIER)),
+            code_challenge_method: Some("S256".to_string()),
+        }
+    }
+
+    fn context() -> AuditContext {
+        AuditContext {
+            ip: Some("127.0.0.1".to_string()),
+            device: Some("test".to_string()),
+            ..AuditContext::default()
+        }
+    }
+
+    async fn authorization_code(oidc: &OidcProviderUseCase) -> String {
+        let redirect = oidc
+            .authorize(&params(), EMAIL, PASSWORD, true, context())
+            .await
+            .unwrap();
+
+        Url::parse(&redirect)
+            .unwrap()
+            .query_pairs()
+            .find(|(key, _)| key == "code")
+            .map(|(_, code)| code.into_owned())
+            .unwrap()
+    }
+
+    fn exchange(code: &str, redirect_uri: &str, verifier: &str) -> TokenParams {
+        TokenParams {
+            grant_type: "authorization_code".to_string(),
+            code: Some(code.to_string()),
+            redirect_uri: Some(redirect_uri.to_string()),
+            client_id: Some(CLIENT_ID.to_string()),
+            client_secret: None,
+            code_verifier: Some(verifier.to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn authorization_code_grants_tokens_once() {
+        let (oidc, fakes, user) = provider().await;
+        let code = authorization_code(&oidc).await;
+
+        let token = oidc
+            .token(exchange(&code, REDIRECT_URI, VERIFIER), None)
+            .await
+            .unwrap();
+        let claims = oidc.userinfo(&token.access_token).await.unwrap();
+        assert_eq!(claims["sub"], json!(user.id.to_string()));
+        assert_eq!(claims["email"], json!(EMAIL));
+
+        let replayed = oidc
+            .token(exchange(&code, REDIRECT_URI, VERIFIER), None)
+            .await
+            .unwrap_err();
+        assert_eq!(replayed.error, "invalid_grant");
+
+        let audit = fakes.audit.all();
+        assert_eq!(audit.len(), 1);
+        assert_eq!(audit[0].action, "login");
+        assert_eq!(audit[0].outcome, "success");
+        assert_eq!(audit[0].actor_id, Some(user.id));
+    }
+
+    #[tokio::test]
+    async fn token_requires_the_pkce_verifier() {
+        let (oidc, _, _) = provider().await;
+        let code = authorization_code(&oidc).await;
+
+        let error = oidc
+            .token(exchange(&code, REDIRECT_URI, "another-verifier"), None)
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.error, "invalid_grant");
+        assert_eq!(error.error_description, "PKCE verification failed");
+    }
+
+    #[tokio::test]
+    async fn token_requires_the_authorized_redirect_uri() {
+        let (oidc, _, _) = provider().await;
+        let code = authorization_code(&oidc).await;
+
+        let error = oidc
+            .token(
+                exchange(&code, "https://evil.example/callback", VERIFIER),
+                None,
+            )
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.error, "invalid_grant");
+    }
+
+    #[tokio::test]
+    async fn expired_codes_are_refused() {
+        let (oidc, _, _) = provider().await;
+        let code = authorization_code(&oidc).await;
+
+        tokio::time::pause();
+        tokio::time::advance(Duration::from_secs(AUTHORIZATION_CODE_TTL_SECS + 1)).await;
+
+        let error = oidc
+            .token(exchange(&code, REDIRECT_URI, VERIFIER), None)
+            .await
+            .unwrap_err();
+        assert_eq!(error.error, "invalid_grant");
+    }
+
+    #[tokio::test]
+    async fn wrong_passwords_are_audited_as_failed_logins() {
+        let (oidc, fakes, _) = provider().await;
+
+        let error = oidc
+            .authorize(&params(), EMAIL, "not-the-password", true, context())
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.error, "login_required");
+        assert_eq!(error.status, 401);
+        let audit = fakes.audit.all();
+        assert_eq!(audit.len(), 1);
+        assert_eq!(audit[0].action, "login");
+        assert_eq!(audit[0].outcome, "failure");
+        assert_eq!(audit[0].actor_id, None);
+        assert_eq!(audit[0].target.as_deref(), Some(EMAIL));
+        assert_eq!(audit[0].ip.as_deref(), Some("127.0.0.1"));
+        assert!(fakes.oauth_consents.all().is_empty());
+    }
+}
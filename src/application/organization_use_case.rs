@@ -0,0 +1,108 @@
+use crate::domain::dto::auth_dto::CreateOrganizationDto;
+use crate::domain::entity::membership::MembershipRole;
+use crate::domain::entity::organization::{Organization, UserOrganization};
+use crate::domain::port::db::organization_port::OrganizationPort;
+use crate::pb::auth::{
+    CreateOrganizationData, CreateOrganizationResponse, ListOrganizationsData,
+    ListOrganizationsResponse, Organization as OrganizationResponse,
+};
+use std::sync::Arc;
+use tonic::{Response, Status};
+use tracing::{error, info};
+use uuid::Uuid;
+
+pub struct OrganizationUseCase {
+    organizations: Arc<dyn OrganizationPort + Send + Sync>,
+}
+
+impl OrganizationUseCase {
+    pub fn new(organizations: Arc<dyn OrganizationPort + Send + Sync>) -> Self {
+        OrganizationUseCase { organizations }
+    }
+}
+
+impl From<UserOrganization> for OrganizationResponse {
+    fn from(organization: UserOrganization) -> Self {
+        OrganizationResponse {
+            id: organization.id.to_string(),
+            name: organization.name,
+            slug: organization.slug,
+            role: organization.role.as_str().to_string(),
+            joined_at: organization.joined_at.to_rfc3339(),
+        }
+    }
+}
+
+impl OrganizationUseCase {
+    pub(crate) async fn create_organization(
+        &self,
+        user_id: Uuid,
+        request: CreateOrganizationDto,
+    ) -> Result<Response<CreateOrganizationResponse>, Status> {
+        let existing = self
+            .organizations
+            .find_by_coll("slug", &request.slug)
+            .await
+            .map_err(|e| {
+                error!("Failed to query organization {}: {}", request.slug, e);
+                Status::internal("Failed to query organization")
+            })?;
+
+        if existing.is_some() {
+            error!("Organization with slug {} already exists", request.slug);
+            return Err(Status::already_exists("Organization slug is already taken"));
+        }
+
+        let organization = Organization::new(request.name, request.slug);
+
+        self.organizations
+            .create_with_owner(&organization, user_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to create organization {}: {}", organization.slug, e);
+                Status::internal("Failed to create organization")
+            })?;
+
+        info!(
+            "Organization {} created with owner: {}",
+            organization.slug, user_id
+        );
+
+        Ok(Response::new(CreateOrganizationResponse {
+            message: "Organization created successfully".to_string(),
+            data: Some(CreateOrganizationData {
+                organization: Some(
+                    UserOrganization {
+                        id: organization.id,
+                        name: organization.name,
+                        slug: organization.slug,
+                        role: MembershipRole::Owner,
+                        joined_at: organization.created_at,
+                    }
+                    .into(),
+                ),
+            }),
+        }))
+    }
+
+    pub(crate) async fn list_organizations(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Response<ListOrganizationsResponse>, Status> {
+        let organizations = self
+            .organizations
+            .list_by_user(user_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to list organizations for user {}: {}", user_id, e);
+                Status::internal("Failed to list organizations")
+            })?;
+
+        Ok(Response::new(ListOrganizationsResponse {
+            message: "Organizations retrieved successfully".to_string(),
+            data: Some(ListOrganizationsData {
+                organizations: organizations.into_iter().map(Into::into).collect(),
+            }),
+        }))
+    }
+}
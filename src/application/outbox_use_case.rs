@@ -0,0 +1,463 @@
+use crate::application::webhook_use_case::WebhookUseCase;
+use crate::config::env::EnvConfig;
+use crate::domain::dto::auth_dto::{ListOutboxMessagesDto, ReplayOutboxMessageDto};
+use crate::domain::entity::outbox_message::{OutboxMessage, OutboxPayload, OutboxStatus};
+use crate::domain::port::db::outbox_port::OutboxPort;
+use crate::domain::port::notification_port::{Notification, NotificationPort};
+use crate::domain::port::redis_port::RedisPort;
+use crate::domain::port::sms_port::SmsPort;
+use crate::pb::auth::{
+    ListOutboxMessagesData, ListOutboxMessagesResponse, OutboxMessage as OutboxMessageResponse,
+    ReplayOutboxMessageResponse,
+};
+use crate::util::util::{decode_cursor, encode_cursor};
+use chrono::{Duration as ChronoDuration, Utc};
+use std::error::Error;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
+use tonic::{Response, Status};
+use tracing::{error, info, instrument, warn};
+use uuid::Uuid;
+
+const DEFAULT_PAGE_SIZE: i64 = 50;
+/// How long a claimed message stays hidden from other dispatchers while it is delivered.
+const DELIVERY_LEASE: Duration = Duration::from_secs(300);
+const MAX_BACKOFF_SECS: u64 = 3600;
+const PURGE_INTERVAL: Duration = Duration::from_secs(3600);
+
+#[derive(Clone)]
+pub struct OutboxUseCase {
+    outbox: Arc<dyn OutboxPort + Send + Sync>,
+    notifications: Arc<dyn NotificationPort>,
+    sms: Arc<dyn SmsPort>,
+    redis: Arc<dyn RedisPort + Send + Sync>,
+    webhooks: WebhookUseCase,
+    config: Arc<EnvConfig>,
+}
+
+impl OutboxUseCase {
+    pub fn new(
+        outbox: Arc<dyn OutboxPort + Send + Sync>,
+        notifications: Arc<dyn NotificationPort>,
+        sms: Arc<dyn SmsPort>,
+        redis: Arc<dyn RedisPort + Send + Sync>,
+        webhooks: WebhookUseCase,
+        config: Arc<EnvConfig>,
+    ) -> Self {
+        OutboxUseCase {
+            outbox,
+            notifications,
+            sms,
+            redis,
+            webhooks,
+            config,
+        }
+    }
+}
+
+impl From<OutboxMessage> for OutboxMessageResponse {
+    fn from(message: OutboxMessage) -> Self {
+        OutboxMessageResponse {
+            id: message.id.to_string(),
+            kind: message.kind,
+            status: message.status,
+            attempts: message.attempts,
+            last_error: message.last_error.unwrap_or_default(),
+            next_attempt_at: message.next_attempt_at.to_rfc3339(),
+            delivered_at: message
+                .delivered_at
+                .map(|t| t.to_rfc3339())
+                .unwrap_or_default(),
+            created_at: message.created_at.to_rfc3339(),
+        }
+    }
+}
+
+/// `base * 2^(attempts - 1)` seconds, capped at an hour.
+fn backoff(base: u64, attempts: i32) -> ChronoDuration {
+    let exponent = attempts.saturating_sub(1).clamp(0, 16) as u32;
+    let secs = base.saturating_mul(1 << exponent).min(MAX_BACKOFF_SECS);
+    ChronoDuration::seconds(secs as i64)
+}
+
+impl OutboxUseCase {
+    /// Polls the outbox until `shutdown` is cancelled, delivering due messages batch by batch.
+    /// A batch in progress is always finished; anything not yet claimed stays in the outbox.
+    /// Delivered and dead messages are purged hourly once they are past the retention period.
+    pub async fn run_dispatcher(self, shutdown: CancellationToken) {
+        let poll_interval = Duration::from_secs(self.config.outbox_poll_interval);
+        let mut last_purge: Option<Instant> = None;
+        info!("Outbox dispatcher started");
+
+        while !shutdown.is_cancelled() {
+            if last_purge.is_none_or(|at| at.elapsed() >= PURGE_INTERVAL) {
+                self.purge_finished().await;
+                last_purge = Some(Instant::now());
+            }
+            match self.dispatch_due().await {
+                Ok(count) if count as i64 == self.config.outbox_batch_size => continue,
+                Ok(_) => {}
+                Err(e) => error!("Failed to claim outbox messages: {}", e),
+            }
+            tokio::select! {
+                _ = tokio::time::sleep(poll_interval) => {}
+                _ = shutdown.cancelled() => {}
+            }
+        }
+
+        info!("Outbox dispatcher stopped");
+    }
+
+    pub(crate) async fn dispatch_due(&self) -> Result<usize, sqlx::Error> {
+        let messages = self
+            .outbox
+            .claim_due(self.config.outbox_batch_size, DELIVERY_LEASE)
+            .await?;
+
+        for message in &messages {
+            self.dispatch(message).await;
+        }
+
+        Ok(messages.len())
+    }
+
+    async fn purge_finished(&self) {
+        let before = Utc::now() - ChronoDuration::seconds(self.config.outbox_retention);
+        match self.outbox.purge_finished(before).await {
+            Ok(0) => {}
+            Ok(count) => info!("Purged {} delivered or dead outbox messages", count),
+            Err(e) => error!("Failed to purge outbox messages: {}", e),
+        }
+    }
+
+    /// The code stored under `otp_key`, or `None` once it expired or was used.
+    async fn otp_code(
+        &self,
+        otp_key: &str,
+    ) -> Result<Option<String>, Box<dyn Error + Send + Sync>> {
+        Ok(self.redis.get_value(otp_key).await?)
+    }
+
+    async fn deliver(&self, message: &OutboxMessage) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let notification = match &*message.payload {
+            OutboxPayload::Webhook {
+                subscription_id,
+                event_id,
+                event,
+                body,
+            } => {
+                return self
+                    .webhooks
+                    .deliver(*subscription_id, *event_id, event, body, message.attempts)
+                    .await;
+            }
+            OutboxPayload::OtpSms {
+                phone_number,
+                otp_key,
+            } => {
+                let Some(otp_code) = self.otp_code(otp_key).await? else {
+                    info!(
+                        "OTP of outbox message {} is no longer valid, dropping it",
+                        message.id
+                    );
+                    return Ok(());
+                };
+                let body = format!(
+                    "Your {} verification code is {}",
+                    self.config.app_name, otp_code
+                );
+                return self.sms.send_sms(phone_number, &body).await;
+            }
+            OutboxPayload::OtpEmail {
+                recipient,
+                otp_key,
+                locale,
+            } => {
+                let Some(otp_code) = self.otp_code(otp_key).await? else {
+                    info!(
+                        "OTP of outbox message {} is no longer valid, dropping it",
+                        message.id
+                    );
+                    return Ok(());
+                };
+                Notification::OtpEmail {
+                    recipient: recipient.clone(),
+                    otp_code,
+                    locale: locale.clone(),
+                }
+            }
+            OutboxPayload::InvitationEmail {
+                recipient,
+                organization,
+                inviter,
+                invitation_link,
+                locale,
+            } => Notification::InvitationEmail {
+                recipient: recipient.clone(),
+                organization: organization.clone(),
+                inviter: inviter.clone(),
+                invitation_link: invitation_link.clone(),
+                locale: locale.clone(),
+            },
+        };
+
+        self.notifications.send(&notification).await
+    }
+
+    /// Each delivery is the root of its own trace, so its SMTP, SMS or webhook calls are exported.
+    #[instrument(
+        name = "OutboxUseCase::dispatch",
+        skip_all,
+        fields(otel.kind = "consumer", message.id = %message.id, message.kind = %message.kind)
+    )]
+    async fn dispatch(&self, message: &OutboxMessage) {
+        let result = match self.deliver(message).await.map_err(|e| e.to_string()) {
+            Ok(()) => {
+                info!("Outbox message {} ({}) delivered", message.id, message.kind);
+                self.outbox.mark_delivered(message.id).await
+            }
+            Err(e) if message.attempts >= self.config.outbox_max_attempts => {
+                error!(
+                    "Outbox message {} ({}) dead-lettered after {} attempts: {}",
+                    message.id, message.kind, message.attempts, e
+                );
+                self.outbox.mark_failed(message.id, &e, None).await
+            }
+            Err(e) => {
+                let retry_at =
+                    Utc::now() + backoff(self.config.outbox_backoff_base, message.attempts);
+                warn!(
+                    "Outbox message {} ({}) failed on attempt {}, retrying at {}: {}",
+                    message.id, message.kind, message.attempts, retry_at, e
+                );
+                self.outbox
+                    .mark_failed(message.id, &e, Some(retry_at))
+                    .await
+            }
+        };
+
+        if let Err(e) = result {
+            error!("Failed to update outbox message {}: {}", message.id, e);
+        }
+    }
+
+    pub(crate) async fn list_outbox_messages(
+        &self,
+        request: ListOutboxMessagesDto,
+    ) -> Result<Response<ListOutboxMessagesResponse>, Status> {
+        let status = OutboxStatus::from_str(&request.status);
+
+        let before = match request.cursor.as_str() {
+            "" => None,
+            cursor => Some(
+                decode_cursor(cursor).ok_or_else(|| Status::invalid_argument("Invalid cursor"))?,
+            ),
+        };
+
+        let page_size = match request.page_size {
+            0 => DEFAULT_PAGE_SIZE,
+            size => i64::from(size),
+        };
+
+        let messages = self
+            .outbox
+            .list(status, before, page_size)
+            .await
+            .map_err(|e| {
+                error!("Failed to list outbox messages: {}", e);
+                Status::internal("Failed to list outbox messages")
+            })?;
+
+        let next_cursor = if messages.len() as i64 == page_size {
+            messages
+                .last()
+                .map(|message| encode_cursor(message.created_at, message.id))
+                .unwrap_or_default()
+        } else {
+            String::new()
+        };
+
+        Ok(Response::new(ListOutboxMessagesResponse {
+            message: "Outbox messages retrieved successfully".to_string(),
+            data: Some(ListOutboxMessagesData {
+                messages: messages.into_iter().map(Into::into).collect(),
+                next_cursor,
+            }),
+        }))
+    }
+
+    pub(crate) async fn replay_outbox_message(
+        &self,
+        request: ReplayOutboxMessageDto,
+    ) -> Result<Response<ReplayOutboxMessageResponse>, Status> {
+        let id = Uuid::parse_str(&request.id)
+            .map_err(|_| Status::invalid_argument("Invalid outbox message id"))?;
+
+        let replayed = self.outbox.replay(id).await.map_err(|e| {
+            error!("Failed to replay outbox message {}: {}", id, e);
+            Status::internal("Failed to replay outbox message")
+        })?;
+
+        if !replayed {
+            return Err(Status::failed_precondition(
+                "Only dead-lettered messages can be replayed",
+            ));
+        }
+
+        info!("Outbox message {} replayed", id);
+
+        Ok(Response::new(ReplayOutboxMessageResponse {
+            message: "Outbox message queued for delivery".to_string(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::notification::memory_adapter::MemoryAdapter;
+    use crate::test_support::{Fakes, test_config};
+
+    const RECIPIENT: &str = "user@example.com";
+
+    struct FailingNotifications;
+
+    #[async_trait::async_trait]
+    impl NotificationPort for FailingNotifications {
+        async fn send(
+            &self,
+            _notification: &Notification,
+        ) -> Result<(), Box<dyn Error + Send + Sync>> {
+            Err("SMTP unavailable".into())
+        }
+    }
+
+    fn otp_email(otp_key: &str) -> OutboxMessage {
+        OutboxMessage::new(OutboxPayload::OtpEmail {
+            recipient: RECIPIENT.to_string(),
+            otp_key: otp_key.to_string(),
+            locale: None,
+        })
+    }
+
+    fn finished(status: OutboxStatus, age: ChronoDuration) -> OutboxMessage {
+        let mut message = otp_email("otp:old");
+        message.status = status.as_str().to_string();
+        message.updated_at = Utc::now() - age;
+        message
+    }
+
+    #[test]
+    fn backoff_doubles_per_attempt_up_to_an_hour() {
+        assert_eq!(backoff(30, 1), ChronoDuration::seconds(30));
+        assert_eq!(backoff(30, 2), ChronoDuration::seconds(60));
+        assert_eq!(backoff(30, 4), ChronoDuration::seconds(240));
+        assert_eq!(backoff(30, 8), ChronoDuration::seconds(3600));
+        assert_eq!(backoff(30, i32::MAX), ChronoDuration::seconds(3600));
+    }
+
+    #[tokio::test]
+    async fn otp_code_is_read_at_send_time_and_never_stored() {
+        let fakes = Fakes::default();
+        let config = Arc::new(test_config(""));
+        let notifications = Arc::new(MemoryAdapter::new());
+        let outbox = fakes.outbox_use_case(&config, notifications.clone());
+        fakes
+            .redis
+            .set_value("otp:user@example.com", "482913")
+            .await
+            .unwrap();
+        fakes.outbox.with_row(otp_email("otp:user@example.com"));
+
+        assert_eq!(outbox.dispatch_due().await.unwrap(), 1);
+
+        assert_eq!(
+            notifications.sent(),
+            vec![Notification::OtpEmail {
+                recipient: RECIPIENT.to_string(),
+                otp_code: "482913".to_string(),
+                locale: None,
+            }]
+        );
+        let message = &fakes.outbox.all()[0];
+        assert_eq!(message.status, "delivered");
+        assert!(
+            !serde_json::to_string(&message.payload)
+                .unwrap()
+                .contains("482913")
+        );
+    }
+
+    #[tokio::test]
+    async fn used_or_expired_otp_is_dropped() {
+        let fakes = Fakes::default();
+        let config = Arc::new(test_config(""));
+        let notifications = Arc::new(MemoryAdapter::new());
+        let outbox = fakes.outbox_use_case(&config, notifications.clone());
+        fakes.outbox.with_row(otp_email("otp:user@example.com"));
+
+        outbox.dispatch_due().await.unwrap();
+
+        assert!(notifications.sent().is_empty());
+        assert_eq!(fakes.outbox.all()[0].status, "delivered");
+    }
+
+    #[tokio::test]
+    async fn failed_delivery_is_retried_then_dead_lettered() {
+        let fakes = Fakes::default();
+        let config = Arc::new(test_config("outbox_max_attempts = 2"));
+        let outbox = fakes.outbox_use_case(&config, Arc::new(FailingNotifications));
+        fakes
+            .redis
+            .set_value("otp:user@example.com", "482913")
+            .await
+            .unwrap();
+        fakes.outbox.with_row(otp_email("otp:user@example.com"));
+
+        outbox.dispatch_due().await.unwrap();
+        let message = fakes.outbox.all()[0].clone();
+        assert_eq!(message.status, "pending");
+        assert_eq!(message.attempts, 1);
+        assert_eq!(message.last_error.as_deref(), Some("SMTP unavailable"));
+        assert!(message.next_attempt_at > Utc::now() + ChronoDuration::seconds(25));
+
+        fakes
+            .outbox
+            .modify(message.id, |message| message.next_attempt_at = Utc::now());
+        outbox.dispatch_due().await.unwrap();
+        let message = fakes.outbox.all()[0].clone();
+        assert_eq!(message.status, "dead");
+        assert_eq!(message.attempts, 2);
+
+        assert_eq!(outbox.dispatch_due().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn purge_keeps_pending_and_recent_messages() {
+        let fakes = Fakes::default();
+        let config = Arc::new(test_config("outbox_retention = 3600"));
+        let outbox = fakes.outbox_use_case(&config, Arc::new(MemoryAdapter::new()));
+        let two_hours = ChronoDuration::hours(2);
+        let pending = finished(OutboxStatus::Pending, two_hours);
+        let recent = finished(OutboxStatus::Delivered, ChronoDuration::minutes(5));
+        fakes.outbox.with_row(pending.clone());
+        fakes.outbox.with_row(recent.clone());
+        fakes
+            .outbox
+            .with_row(finished(OutboxStatus::Delivered, two_hours));
+        fakes
+            .outbox
+            .with_row(finished(OutboxStatus::Dead, two_hours));
+
+        outbox.purge_finished().await;
+
+        let kept: Vec<_> = fakes
+            .outbox
+            .all()
+            .iter()
+            .map(|message| message.id)
+            .collect();
+        assert_eq!(kept, vec![pending.id, recent.id]);
+    }
+}
@@ -0,0 +1,446 @@
+use crate::domain::dto::auth_dto::{
+    CreateWebhookSubscriptionDto, DeleteWebhookSubscriptionDto, ListWebhookDeliveriesDto,
+};
+use crate::domain::entity::domain_event::DomainEvent;
+use crate::domain::entity::outbox_message::{OutboxMessage, OutboxPayload};
+use crate::domain::entity::webhook::{WebhookDelivery, WebhookEvent, WebhookSubscription};
+use crate::domain::port::db::webhook_delivery_port::WebhookDeliveryPort;
+use crate::domain::port::db::webhook_subscription_port::WebhookSubscriptionPort;
+use crate::domain::port::event_subscriber_port::EventSubscriberPort;
+use crate::domain::port::webhook_sender_port::WebhookSenderPort;
+use crate::pb::auth::{
+    CreateWebhookSubscriptionData, CreateWebhookSubscriptionResponse,
+    DeleteWebhookSubscriptionResponse, ListWebhookDeliveriesData, ListWebhookDeliveriesResponse,
+    ListWebhookSubscriptionsData, ListWebhookSubscriptionsResponse,
+    WebhookDelivery as WebhookDeliveryResponse, WebhookSubscription as WebhookSubscriptionResponse,
+};
+use crate::util::util::{decode_cursor, encode_cursor, generate_random_string};
+use async_trait::async_trait;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use serde_json::json;
+use sha2::Sha256;
+use std::error::Error;
+use std::sync::Arc;
+use std::time::Instant;
+use tonic::{Response, Status};
+use tracing::{error, info};
+use uuid::Uuid;
+
+const DEFAULT_PAGE_SIZE: i64 = 50;
+
+#[derive(Clone)]
+pub struct WebhookUseCase {
+    subscriptions: Arc<dyn WebhookSubscriptionPort + Send + Sync>,
+    deliveries: Arc<dyn WebhookDeliveryPort + Send + Sync>,
+    sender: Arc<dyn WebhookSenderPort>,
+}
+
+impl WebhookUseCase {
+    pub fn new(
+        subscriptions: Arc<dyn WebhookSubscriptionPort + Send + Sync>,
+        deliveries: Arc<dyn WebhookDeliveryPort + Send + Sync>,
+        sender: Arc<dyn WebhookSenderPort>,
+    ) -> Self {
+        WebhookUseCase {
+            subscriptions,
+            deliveries,
+            sender,
+        }
+    }
+}
+
+impl From<WebhookSubscription> for WebhookSubscriptionResponse {
+    fn from(subscription: WebhookSubscription) -> Self {
+        WebhookSubscriptionResponse {
+            id: subscription.id.to_string(),
+            url: subscription.url,
+            events: subscription.events,
+            created_at: subscription.created_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryResponse {
+    fn from(delivery: WebhookDelivery) -> Self {
+        WebhookDeliveryResponse {
+            id: delivery.id.to_string(),
+            subscription_id: delivery.subscription_id.to_string(),
+            event_id: delivery.event_id.to_string(),
+            event: delivery.event,
+            attempt: delivery.attempt,
+            status_code: delivery.status_code.unwrap_or_default(),
+            error: delivery.error.unwrap_or_default(),
+            duration_ms: delivery.duration_ms,
+            created_at: delivery.created_at.to_rfc3339(),
+        }
+    }
+}
+
+/// Hex HMAC-SHA256 of `<timestamp>.<body>`, so receivers can reject replayed payloads.
+fn sign(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+    mac.finalize()
+        .into_bytes()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+fn user_data(user_id: Uuid, email: &str, name: &str, org_id: Option<Uuid>) -> serde_json::Value {
+    json!({
+        "user_id": user_id,
+        "email": email,
+        "name": name,
+        "org_id": org_id,
+    })
+}
+
+#[async_trait]
+impl EventSubscriberPort for WebhookUseCase {
+    async fn outbox_messages(&self, event: &DomainEvent) -> Vec<OutboxMessage> {
+        match event {
+            DomainEvent::UserRegistered {
+                user_id,
+                email,
+                name,
+                org_id,
+            } => {
+                let data = user_data(*user_id, email, name, *org_id);
+                self.messages(WebhookEvent::UserRegistered, data).await
+            }
+            DomainEvent::EmailVerified {
+                user_id,
+                email,
+                name,
+                org_id,
+            } => {
+                let data = user_data(*user_id, email, name, *org_id);
+                self.messages(WebhookEvent::UserEmailVerified, data).await
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl WebhookUseCase {
+    /// One outbox message per subscribed endpoint. Failures are logged but never fail the
+    /// operation that raised the event.
+    async fn messages(&self, event: WebhookEvent, data: serde_json::Value) -> Vec<OutboxMessage> {
+        let subscriptions = match self.subscriptions.list_for_event(event.as_str()).await {
+            Ok(subscriptions) => subscriptions,
+            Err(e) => {
+                error!(
+                    "Failed to list webhook subscriptions for {}: {}",
+                    event.as_str(),
+                    e
+                );
+                return Vec::new();
+            }
+        };
+        if subscriptions.is_empty() {
+            return Vec::new();
+        }
+
+        let event_id = Uuid::new_v4();
+        let body = json!({
+            "id": event_id,
+            "type": event.as_str(),
+            "created_at": Utc::now().to_rfc3339(),
+            "data": data,
+        })
+        .to_string();
+
+        subscriptions
+            .into_iter()
+            .map(|subscription| {
+                OutboxMessage::new(OutboxPayload::Webhook {
+                    subscription_id: subscription.id,
+                    event_id,
+                    event: event.as_str().to_string(),
+                    body: body.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Sends one delivery attempt and records it in the delivery log. Any non-2xx response is
+    /// an error so the outbox retries it.
+    pub(crate) async fn deliver(
+        &self,
+        subscription_id: Uuid,
+        event_id: Uuid,
+        event: &str,
+        body: &str,
+        attempt: i32,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let Some(subscription) = self.subscriptions.find_by_id(subscription_id).await? else {
+            info!(
+                "Webhook subscription {} was removed, dropping {} event {}",
+                subscription_id, event, event_id
+            );
+            return Ok(());
+        };
+
+        let timestamp = Utc::now().timestamp();
+        let headers = [
+            ("x-webhook-id", event_id.to_string()),
+            ("x-webhook-event", event.to_string()),
+            ("x-webhook-timestamp", timestamp.to_string()),
+            (
+                "x-webhook-signature",
+                format!("sha256={}", sign(&subscription.secret, timestamp, body)),
+            ),
+        ];
+
+        let started = Instant::now();
+        let result = self.sender.post(&subscription.url, &headers, body).await;
+
+        let (status_code, delivery_error) = match &result {
+            Ok(status) if (200..300).contains(status) => (Some(i32::from(*status)), None),
+            Ok(status) => (Some(i32::from(*status)), Some(format!("HTTP {}", status))),
+            Err(e) => (None, Some(e.to_string())),
+        };
+
+        let delivery = WebhookDelivery {
+            id: Uuid::new_v4(),
+            subscription_id,
+            event_id,
+            event: event.to_string(),
+            attempt,
+            status_code,
+            error: delivery_error.clone(),
+            duration_ms: started.elapsed().as_millis() as i64,
+            created_at: Utc::now(),
+        };
+        if let Err(e) = self.deliveries.record(&delivery).await {
+            error!("Failed to record webhook delivery {}: {}", delivery.id, e);
+        }
+
+        match delivery_error {
+            Some(e) => Err(e.into()),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) async fn create_webhook_subscription(
+        &self,
+        request: CreateWebhookSubscriptionDto,
+    ) -> Result<Response<CreateWebhookSubscriptionResponse>, Status> {
+        let secret = format!("whsec_{}", generate_random_string(32));
+        let subscription = WebhookSubscription::new(request.url, secret.clone(), request.events);
+
+        self.subscriptions.save(&subscription).await.map_err(|e| {
+            error!("Failed to save webhook subscription: {}", e);
+            Status::internal("Failed to save webhook subscription")
+        })?;
+
+        info!(
+            "Webhook subscription {} created for {}",
+            subscription.id, subscription.url
+        );
+
+        Ok(Response::new(CreateWebhookSubscriptionResponse {
+            message: "Webhook subscription created successfully".to_string(),
+            data: Some(CreateWebhookSubscriptionData {
+                subscription: Some(subscription.into()),
+                secret,
+            }),
+        }))
+    }
+
+    pub(crate) async fn list_webhook_subscriptions(
+        &self,
+    ) -> Result<Response<ListWebhookSubscriptionsResponse>, Status> {
+        let subscriptions = self.subscriptions.list_active().await.map_err(|e| {
+            error!("Failed to list webhook subscriptions: {}", e);
+            Status::internal("Failed to list webhook subscriptions")
+        })?;
+
+        Ok(Response::new(ListWebhookSubscriptionsResponse {
+            message: "Webhook subscriptions retrieved successfully".to_string(),
+            data: Some(ListWebhookSubscriptionsData {
+                subscriptions: subscriptions.into_iter().map(Into::into).collect(),
+            }),
+        }))
+    }
+
+    pub(crate) async fn delete_webhook_subscription(
+        &self,
+        request: DeleteWebhookSubscriptionDto,
+    ) -> Result<Response<DeleteWebhookSubscriptionResponse>, Status> {
+        let id = Uuid::parse_str(&request.id)
+            .map_err(|_| Status::invalid_argument("Invalid webhook subscription id"))?;
+
+        let removed = self.subscriptions.remove(id).await.map_err(|e| {
+            error!("Failed to delete webhook subscription {}: {}", id, e);
+            Status::internal("Failed to delete webhook subscription")
+        })?;
+
+        if !removed {
+            return Err(Status::not_found("Webhook subscription not found"));
+        }
+
+        info!("Webhook subscription {} deleted", id);
+
+        Ok(Response::new(DeleteWebhookSubscriptionResponse {
+            message: "Webhook subscription deleted successfully".to_string(),
+        }))
+    }
+
+    pub(crate) async fn list_webhook_deliveries(
+        &self,
+        request: ListWebhookDeliveriesDto,
+    ) -> Result<Response<ListWebhookDeliveriesResponse>, Status> {
+        let subscription_id = Uuid::parse_str(&request.subscription_id)
+            .map_err(|_| Status::invalid_argument("Invalid webhook subscription id"))?;
+
+        let before = match request.cursor.as_str() {
+            "" => None,
+            cursor => Some(
+                decode_cursor(cursor).ok_or_else(|| Status::invalid_argument("Invalid cursor"))?,
+            ),
+        };
+
+        let page_size = match request.page_size {
+            0 => DEFAULT_PAGE_SIZE,
+            size => i64::from(size),
+        };
+
+        let deliveries = self
+            .deliveries
+            .list_by_subscription(subscription_id, before, page_size)
+            .await
+            .map_err(|e| {
+                error!("Failed to list webhook deliveries: {}", e);
+                Status::internal("Failed to list webhook deliveries")
+            })?;
+
+        let next_cursor = if deliveries.len() as i64 == page_size {
+            deliveries
+                .last()
+                .map(|delivery| encode_cursor(delivery.created_at, delivery.id))
+                .unwrap_or_default()
+        } else {
+            String::new()
+        };
+
+        Ok(Response::new(ListWebhookDeliveriesResponse {
+            message: "Webhook deliveries retrieved successfully".to_string(),
+            data: Some(ListWebhookDeliveriesData {
+                deliveries: deliveries.into_iter().map(Into::into).collect(),
+                next_cursor,
+            }),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::outbox_use_case::OutboxUseCase;
+    use crate::infrastructure::notification::log_sms_adapter::LogSmsAdapter;
+    use crate::infrastructure::notification::memory_adapter::MemoryAdapter;
+    use crate::infrastructure::webhook::http_webhook_adapter::HttpWebhookAdapter;
+    use crate::test_support::{Fakes, WebhookReceiver, test_config};
+    use axum::http::StatusCode;
+
+    const SECRET: &str = "whsec_test";
+
+    fn webhooks(fakes: &Fakes) -> WebhookUseCase {
+        WebhookUseCase::new(
+            fakes.webhook_subscriptions.clone(),
+            fakes.webhook_deliveries.clone(),
+            Arc::new(HttpWebhookAdapter::new()),
+        )
+    }
+
+    fn subscribe(fakes: &Fakes, url: &str) -> WebhookSubscription {
+        let subscription = WebhookSubscription::new(
+            url.to_string(),
+            SECRET.to_string(),
+            vec![WebhookEvent::UserRegistered.as_str().to_string()],
+        );
+        fakes.webhook_subscriptions.with_row(subscription.clone());
+        subscription
+    }
+
+    #[test]
+    fn signature_is_hmac_sha256_of_timestamp_and_body() {
+        assert_eq!(
+            sign(SECRET, 1_700_000_000, r#"{"type":"user.registered"}"#),
+            "c9a7e486ce0fec71da4ac6573ff7a653d91a394d2ab292cb205cd323afdf36d1"
+        );
+    }
+
+    #[tokio::test]
+    async fn delivery_is_signed_over_its_timestamp_and_body() {
+        let fakes = Fakes::default();
+        let receiver = WebhookReceiver::start(StatusCode::OK).await;
+        let subscription = subscribe(&fakes, &receiver.url);
+        let event_id = Uuid::new_v4();
+        let body = r#"{"type":"user.registered"}"#;
+
+        webhooks(&fakes)
+            .deliver(subscription.id, event_id, "user.registered", body, 1)
+            .await
+            .unwrap();
+
+        let received = &receiver.received()[0];
+        let header = |name: &str| received.headers[name].to_str().unwrap().to_string();
+        let timestamp: i64 = header("x-webhook-timestamp").parse().unwrap();
+        assert_eq!(received.body, body);
+        assert_eq!(header("x-webhook-id"), event_id.to_string());
+        assert_eq!(header("x-webhook-event"), "user.registered");
+        assert_eq!(
+            header("x-webhook-signature"),
+            format!("sha256={}", sign(SECRET, timestamp, body))
+        );
+        let delivery = &fakes.webhook_deliveries.all()[0];
+        assert_eq!(delivery.status_code, Some(200));
+        assert_eq!(delivery.error, None);
+    }
+
+    #[tokio::test]
+    async fn failed_delivery_is_recorded_and_retried() {
+        let fakes = Fakes::default();
+        let receiver = WebhookReceiver::start(StatusCode::BAD_GATEWAY).await;
+        let subscription = subscribe(&fakes, &receiver.url);
+        let config = Arc::new(test_config(""));
+        let outbox = OutboxUseCase::new(
+            fakes.outbox.clone(),
+            Arc::new(MemoryAdapter::new()),
+            Arc::new(LogSmsAdapter),
+            fakes.redis.clone(),
+            webhooks(&fakes),
+            config,
+        );
+        fakes
+            .outbox
+            .with_row(OutboxMessage::new(OutboxPayload::Webhook {
+                subscription_id: subscription.id,
+                event_id: Uuid::new_v4(),
+                event: "user.registered".to_string(),
+                body: "{}".to_string(),
+            }));
+
+        let error = webhooks(&fakes)
+            .deliver(subscription.id, Uuid::new_v4(), "user.registered", "{}", 1)
+            .await
+            .unwrap_err();
+        assert_eq!(error.to_string(), "HTTP 502");
+        let delivery = &fakes.webhook_deliveries.all()[0];
+        assert_eq!(delivery.status_code, Some(502));
+        assert_eq!(delivery.error.as_deref(), Some("HTTP 502"));
+
+        outbox.dispatch_due().await.unwrap();
+        let message = &fakes.outbox.all()[0];
+        assert_eq!(message.status, "pending");
+        assert_eq!(message.attempts, 1);
+        assert_eq!(message.last_error.as_deref(), Some("HTTP 502"));
+        assert_eq!(fakes.webhook_deliveries.all().len(), 2);
+        assert_eq!(receiver.received().len(), 2);
+    }
+}
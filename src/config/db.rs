@@ -1,15 +1,17 @@
-use crate::cfg;
+use crate::config::env::EnvConfig;
 use sqlx::postgres::PgPoolOptions;
 use sqlx::{Error, PgPool};
 use std::time;
 
-pub async fn get_db_pool() -> Result<PgPool, Error> {
-    let config = cfg();
+pub async fn get_db_pool(config: &EnvConfig) -> Result<PgPool, Error> {
     let database_url = &config.database_url;
 
     PgPoolOptions::new()
-        .max_connections(10)
-        .acquire_timeout(time::Duration::from_secs(5))
-        .connect(&database_url)
+        .max_connections(config.db_max_connections)
+        .min_connections(config.db_min_connections)
+        .acquire_timeout(time::Duration::from_secs(config.db_acquire_timeout))
+        .idle_timeout(config.db_idle_timeout.map(time::Duration::from_secs))
+        .max_lifetime(config.db_max_lifetime.map(time::Duration::from_secs))
+        .connect(database_url)
         .await
 }
@@ -1,6 +1,10 @@
+use crate::config::source::ConfigSource;
+use crate::domain::service::id_token_service::read_signing_key;
 use dotenv::dotenv;
-use once_cell::sync::Lazy;
-use std::env;
+use std::net::SocketAddr;
+
+/// Shortest accepted signing secret, in bytes (256 bits for HS256).
+const MIN_SECRET_LENGTH: usize = 32;
 
 #[derive(Debug)]
 pub struct EnvConfig {
@@ -9,60 +13,637 @@ pub struct EnvConfig {
     pub refresh_secret: String,
     pub access_token_duration: i64,
     pub refresh_token_duration: i64,
+    pub client_token_duration: i64,
     pub redis_host: String,
     pub redis_port: u16,
     pub redis_password: Option<String>,
+    pub notification_transport: String,
+    pub notification_file_dir: String,
+    pub email_template_dir: Option<String>,
+    pub email_default_locale: String,
+    pub sms_transport: String,
+    pub sms_webhook_url: Option<String>,
+    pub sms_webhook_token: Option<String>,
     pub email_host: String,
     pub email_user: String,
     pub email_password: String,
-    pub email_port: String,
-    pub server_address: String,
+    pub email_port: u16,
+    pub server_address: SocketAddr,
+    pub grpc_request_timeout: u64,
+    pub grpc_concurrency_limit: usize,
+    pub grpc_max_message_size: usize,
+    pub grpc_http2_keepalive_interval: Option<u64>,
+    pub grpc_http2_keepalive_timeout: u64,
+    pub grpc_tcp_nodelay: bool,
+    pub grpc_web_enabled: bool,
+    pub grpc_web_allowed_origins: Vec<String>,
+    pub tls_cert_path: Option<String>,
+    pub tls_key_path: Option<String>,
+    pub tls_client_ca_path: Option<String>,
+    pub tls_client_auth_required: bool,
+    pub tls_reload_interval: u64,
+    pub db_max_connections: u32,
+    pub db_min_connections: u32,
+    pub db_acquire_timeout: u64,
+    pub db_idle_timeout: Option<u64>,
+    pub db_max_lifetime: Option<u64>,
     pub smtp_from: String,
     pub app_name: String,
     pub secret_key: String,
+    pub oidc_redirect_url: String,
+    pub oidc_providers: Vec<OidcProviderConfig>,
+    pub http_address: SocketAddr,
+    pub rest_enabled: bool,
+    pub metrics_enabled: bool,
+    pub metrics_address: SocketAddr,
+    pub otel_exporter_otlp_endpoint: Option<String>,
+    pub otel_service_name: String,
+    pub otel_traces_sample_ratio: f64,
+    pub oidc_issuer: Option<String>,
+    pub oidc_signing_key_path: String,
+    pub oidc_signing_key_id: String,
+    pub webauthn_rp_id: String,
+    pub webauthn_rp_origin: String,
+    pub tenant_scoped_users: bool,
+    pub invitation_url: String,
+    pub invitation_duration: i64,
+    pub outbox_poll_interval: u64,
+    pub outbox_batch_size: i64,
+    pub outbox_max_attempts: i32,
+    pub outbox_backoff_base: u64,
+    pub outbox_retention: i64,
+    pub health_check_interval: u64,
+    pub health_check_timeout: u64,
+    pub health_check_smtp: bool,
+    pub shutdown_drain_period: u64,
+    pub shutdown_timeout: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct OidcProviderConfig {
+    pub name: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub issuer: Option<String>,
+    pub auth_url: Option<String>,
+    pub token_url: Option<String>,
+    pub userinfo_url: Option<String>,
+    pub scopes: String,
+    pub trust_email: bool,
 }
 
 impl EnvConfig {
-    fn init() -> Self {
+    /// Reads `.env`, the environment and `CONFIG_FILE`, returning every problem found.
+    pub fn load() -> Result<Self, Vec<String>> {
         dotenv().ok();
 
+        let source = ConfigSource::load();
+        let config = Self::from_source(&source);
+        config.validate(&source);
+
+        source.finish().map(|()| config)
+    }
+
+    /// Builds a configuration from TOML alone, with the same validation as `load`.
+    #[cfg(test)]
+    pub(crate) fn from_toml(contents: &str) -> Result<Self, Vec<String>> {
+        let source = ConfigSource::from_toml(contents);
+        let config = Self::from_source(&source);
+        config.validate(&source);
+
+        source.finish().map(|()| config)
+    }
+
+    fn from_source(source: &ConfigSource) -> Self {
         EnvConfig {
-            database_url: env::var("DATABASE_URL").expect("DATABASE_URL must be set"),
-
-            access_secret: env::var("ACCESS_SECRET").expect("ACCESS_SECRET must be set"),
-            refresh_secret: env::var("REFRESH_SECRET").expect("REFRESH_SECRET must be set"),
-            access_token_duration: env::var("ACCESS_TOKEN_DURATION")
-                .expect("ACCESS_TOKEN_DURATION must be set")
-                .parse()
-                .expect("ACCESS_TOKEN_DURATION must be a valid integer"),
-            refresh_token_duration: env::var("REFRESH_TOKEN_DURATION")
-                .expect("REFRESH_TOKEN_DURATION must be set")
-                .parse()
-                .expect("REFRESH_TOKEN_DURATION must be a valid integer"),
-
-            redis_host: env::var("REDIS_HOST").expect("REDIS_HOST must be set"),
-            redis_port: env::var("REDIS_PORT")
-                .expect("REDIS_PORT must be set")
-                .parse()
-                .expect("REDIS_PORT must be a valid u16"),
-            redis_password: env::var("REDIS_PASSWORD").ok(),
-
-            email_host: env::var("EMAIL_HOST").expect("EMAIL_HOST must be set"),
-            email_user: env::var("EMAIL_USER").expect("EMAIL_USER must be set"),
-            email_password: env::var("EMAIL_PASSWORD").expect("EMAIL_PASSWORD must be set"),
-            email_port: env::var("EMAIL_PORT").unwrap_or_else(|_| "587".to_string()),
-
-            server_address: env::var("SERVER_ADDRESS")
-                .unwrap_or_else(|_| "0.0.0.0:50051".to_string()),
-            app_name: env::var("APP_NAME").unwrap_or_else(|_| "MyApp".to_string()),
-            secret_key: env::var("SECRET_KEY").expect("SECRET_KEY must be set"),
-            smtp_from: env::var("SMTP_FROM").unwrap_or_else(|_| "noreply@example.com".to_string()),
+            database_url: source.required("DATABASE_URL"),
+
+            access_secret: source.required("ACCESS_SECRET"),
+            refresh_secret: source.required("REFRESH_SECRET"),
+            access_token_duration: source.parse_required("ACCESS_TOKEN_DURATION"),
+            refresh_token_duration: source.parse_required("REFRESH_TOKEN_DURATION"),
+            client_token_duration: source.parse("CLIENT_TOKEN_DURATION", "300"),
+
+            redis_host: source.required("REDIS_HOST"),
+            redis_port: source.parse_required("REDIS_PORT"),
+            redis_password: source.var("REDIS_PASSWORD"),
+
+            notification_transport: source.string("NOTIFICATION_TRANSPORT", "smtp"),
+            notification_file_dir: source.string("NOTIFICATION_FILE_DIR", "mail"),
+            email_template_dir: source
+                .var("EMAIL_TEMPLATE_DIR")
+                .filter(|dir| !dir.is_empty()),
+            email_default_locale: source.string("EMAIL_DEFAULT_LOCALE", "id"),
+            sms_transport: source.string("SMS_TRANSPORT", "log"),
+            sms_webhook_url: source.var("SMS_WEBHOOK_URL"),
+            sms_webhook_token: source.var("SMS_WEBHOOK_TOKEN"),
+            email_host: source.string("EMAIL_HOST", ""),
+            email_user: source.string("EMAIL_USER", ""),
+            email_password: source.string("EMAIL_PASSWORD", ""),
+            email_port: source.parse("EMAIL_PORT", "587"),
+
+            server_address: source.parse("SERVER_ADDRESS", "0.0.0.0:50051"),
+            grpc_request_timeout: source.parse("GRPC_REQUEST_TIMEOUT", "10"),
+            grpc_concurrency_limit: source.parse("GRPC_CONCURRENCY_LIMIT", "64"),
+            grpc_max_message_size: source.parse("GRPC_MAX_MESSAGE_SIZE", "4194304"),
+            grpc_http2_keepalive_interval: Self::optional_secs(
+                source,
+                "GRPC_HTTP2_KEEPALIVE_INTERVAL",
+                "30",
+            ),
+            grpc_http2_keepalive_timeout: source.parse("GRPC_HTTP2_KEEPALIVE_TIMEOUT", "20"),
+            grpc_tcp_nodelay: source.flag("GRPC_TCP_NODELAY", true),
+            grpc_web_enabled: source.flag("GRPC_WEB_ENABLED", true),
+            grpc_web_allowed_origins: source
+                .string("GRPC_WEB_ALLOWED_ORIGINS", "")
+                .split(',')
+                .map(str::trim)
+                .filter(|origin| !origin.is_empty())
+                .map(String::from)
+                .collect(),
+
+            tls_cert_path: source.var("TLS_CERT_PATH").filter(|path| !path.is_empty()),
+            tls_key_path: source.var("TLS_KEY_PATH").filter(|path| !path.is_empty()),
+            tls_client_ca_path: source
+                .var("TLS_CLIENT_CA_PATH")
+                .filter(|path| !path.is_empty()),
+            tls_client_auth_required: source.flag("TLS_CLIENT_AUTH_REQUIRED", false),
+            tls_reload_interval: source.parse("TLS_RELOAD_INTERVAL", "30"),
+
+            db_max_connections: source.parse("DB_MAX_CONNECTIONS", "10"),
+            db_min_connections: source.parse("DB_MIN_CONNECTIONS", "0"),
+            db_acquire_timeout: source.parse("DB_ACQUIRE_TIMEOUT", "5"),
+            db_idle_timeout: Self::optional_secs(source, "DB_IDLE_TIMEOUT", "600"),
+            db_max_lifetime: Self::optional_secs(source, "DB_MAX_LIFETIME", "1800"),
+            app_name: source.string("APP_NAME", "MyApp"),
+            secret_key: source.required("SECRET_KEY"),
+            smtp_from: source.string("SMTP_FROM", "noreply@example.com"),
+
+            oidc_redirect_url: source
+                .string("OIDC_REDIRECT_URL", "http://localhost:3000/auth/callback"),
+            oidc_providers: Self::oidc_providers(source),
+
+            http_address: source.parse("HTTP_ADDRESS", "0.0.0.0:8080"),
+            rest_enabled: source.flag("REST_ENABLED", false),
+            metrics_enabled: source.flag("METRICS_ENABLED", false),
+            metrics_address: source.parse("METRICS_ADDRESS", "0.0.0.0:9090"),
+            otel_exporter_otlp_endpoint: source
+                .var("OTEL_EXPORTER_OTLP_ENDPOINT")
+                .filter(|endpoint| !endpoint.is_empty()),
+            otel_service_name: source.string("OTEL_SERVICE_NAME", "rusuh-grpc"),
+            otel_traces_sample_ratio: source.parse("OTEL_TRACES_SAMPLE_RATIO", "1.0"),
+            oidc_issuer: source.var("OIDC_ISSUER"),
+            oidc_signing_key_path: source
+                .string("OIDC_SIGNING_KEY_PATH", "keys/oidc_signing_key.pem"),
+            oidc_signing_key_id: source.string("OIDC_SIGNING_KEY_ID", "default"),
+
+            webauthn_rp_id: source.string("WEBAUTHN_RP_ID", "localhost"),
+            webauthn_rp_origin: source.string("WEBAUTHN_RP_ORIGIN", "http://localhost:3000"),
+
+            tenant_scoped_users: source.flag("TENANT_SCOPED_USERS", false),
+            invitation_url: source.string("INVITATION_URL", "http://localhost:3000/invite"),
+            invitation_duration: source.parse("INVITATION_DURATION", "259200"),
+
+            outbox_poll_interval: source.parse("OUTBOX_POLL_INTERVAL", "5"),
+            outbox_batch_size: source.parse("OUTBOX_BATCH_SIZE", "20"),
+            outbox_max_attempts: source.parse("OUTBOX_MAX_ATTEMPTS", "8"),
+            outbox_backoff_base: source.parse("OUTBOX_BACKOFF_BASE", "30"),
+            outbox_retention: source.parse("OUTBOX_RETENTION", "604800"),
+
+            health_check_interval: source.parse("HEALTH_CHECK_INTERVAL", "10"),
+            health_check_timeout: source.parse("HEALTH_CHECK_TIMEOUT", "3"),
+            health_check_smtp: source.flag("HEALTH_CHECK_SMTP", false),
+
+            shutdown_drain_period: source.parse("SHUTDOWN_DRAIN_PERIOD", "5"),
+            shutdown_timeout: source.parse("SHUTDOWN_TIMEOUT", "30"),
+        }
+    }
+
+    pub fn tls_enabled(&self) -> bool {
+        self.tls_cert_path.is_some()
+    }
+
+    /// Reads a duration in seconds where `0` disables the setting.
+    fn optional_secs(source: &ConfigSource, key: &str, default: &str) -> Option<u64> {
+        let secs: u64 = source.parse(key, default);
+        (secs > 0).then_some(secs)
+    }
+
+    /// Records every value that parses but cannot work, so a bad deployment fails at startup
+    /// with the full list instead of one problem per restart.
+    fn validate(&self, source: &ConfigSource) {
+        let check = |ok: bool, problem: &str| {
+            if !ok {
+                source.problem(problem.to_string());
+            }
+        };
+
+        for (key, secret) in [
+            ("ACCESS_SECRET", &self.access_secret),
+            ("REFRESH_SECRET", &self.refresh_secret),
+            ("SECRET_KEY", &self.secret_key),
+        ] {
+            // Missing secrets are already reported by `required`.
+            if !secret.is_empty() && secret.len() < MIN_SECRET_LENGTH {
+                source.problem(format!(
+                    "{} must be at least {} characters",
+                    key, MIN_SECRET_LENGTH
+                ));
+            }
         }
+        check(
+            self.access_secret.is_empty() || self.access_secret != self.refresh_secret,
+            "ACCESS_SECRET and REFRESH_SECRET must differ",
+        );
+
+        check(
+            self.redis_port > 0,
+            "REDIS_PORT must be between 1 and 65535",
+        );
+        check(
+            self.email_port > 0,
+            "EMAIL_PORT must be between 1 and 65535",
+        );
+        check(
+            self.server_address.port() > 0,
+            "SERVER_ADDRESS must name a port",
+        );
+        check(
+            self.http_address.port() > 0,
+            "HTTP_ADDRESS must name a port",
+        );
+        check(
+            self.metrics_address.port() > 0,
+            "METRICS_ADDRESS must name a port",
+        );
+
+        check(
+            self.access_token_duration > 0,
+            "ACCESS_TOKEN_DURATION must be greater than 0",
+        );
+        check(
+            self.refresh_token_duration > self.access_token_duration,
+            "REFRESH_TOKEN_DURATION must be longer than ACCESS_TOKEN_DURATION",
+        );
+        check(
+            self.client_token_duration > 0,
+            "CLIENT_TOKEN_DURATION must be greater than 0",
+        );
+        check(
+            self.invitation_duration > 0,
+            "INVITATION_DURATION must be greater than 0",
+        );
+
+        check(
+            self.grpc_request_timeout > 0,
+            "GRPC_REQUEST_TIMEOUT must be greater than 0",
+        );
+        check(
+            self.grpc_concurrency_limit > 0,
+            "GRPC_CONCURRENCY_LIMIT must be greater than 0",
+        );
+        check(
+            self.grpc_max_message_size > 0,
+            "GRPC_MAX_MESSAGE_SIZE must be greater than 0",
+        );
+        check(
+            self.grpc_web_allowed_origins
+                .iter()
+                .all(|origin| origin == "*" || http::HeaderValue::from_str(origin).is_ok()),
+            "GRPC_WEB_ALLOWED_ORIGINS must be a comma-separated list of origins or *",
+        );
+
+        check(
+            self.tls_cert_path.is_some() == self.tls_key_path.is_some(),
+            "TLS_CERT_PATH and TLS_KEY_PATH must be set together",
+        );
+        check(
+            self.tls_client_ca_path.is_none() || self.tls_cert_path.is_some(),
+            "TLS_CLIENT_CA_PATH requires TLS_CERT_PATH and TLS_KEY_PATH",
+        );
+        check(
+            !self.tls_client_auth_required || self.tls_client_ca_path.is_some(),
+            "TLS_CLIENT_AUTH_REQUIRED requires TLS_CLIENT_CA_PATH",
+        );
+        check(
+            self.tls_reload_interval > 0,
+            "TLS_RELOAD_INTERVAL must be greater than 0",
+        );
+
+        // The key is only used by the identity provider, which OIDC_ISSUER turns on.
+        if self.oidc_issuer.is_some()
+            && let Err(e) = read_signing_key(&self.oidc_signing_key_path)
+        {
+            source.problem(format!(
+                "OIDC_SIGNING_KEY_PATH {} {}",
+                self.oidc_signing_key_path, e
+            ));
+        }
+
+        check(
+            (0.0..=1.0).contains(&self.otel_traces_sample_ratio),
+            "OTEL_TRACES_SAMPLE_RATIO must be between 0 and 1",
+        );
+
+        check(
+            self.db_max_connections > 0,
+            "DB_MAX_CONNECTIONS must be greater than 0",
+        );
+        check(
+            self.db_min_connections <= self.db_max_connections,
+            "DB_MIN_CONNECTIONS must not exceed DB_MAX_CONNECTIONS",
+        );
+        check(
+            self.db_acquire_timeout > 0,
+            "DB_ACQUIRE_TIMEOUT must be greater than 0",
+        );
+
+        check(
+            self.outbox_poll_interval > 0,
+            "OUTBOX_POLL_INTERVAL must be greater than 0",
+        );
+        check(
+            self.outbox_batch_size > 0,
+            "OUTBOX_BATCH_SIZE must be greater than 0",
+        );
+        check(
+            self.outbox_max_attempts > 0,
+            "OUTBOX_MAX_ATTEMPTS must be greater than 0",
+        );
+        check(
+            self.outbox_retention > 0,
+            "OUTBOX_RETENTION must be greater than 0",
+        );
+        check(
+            self.health_check_interval > 0,
+            "HEALTH_CHECK_INTERVAL must be greater than 0",
+        );
+        check(
+            self.health_check_timeout > 0,
+            "HEALTH_CHECK_TIMEOUT must be greater than 0",
+        );
+
+        check(
+            matches!(
+                self.notification_transport.as_str(),
+                "smtp" | "file" | "log" | "memory"
+            ),
+            "NOTIFICATION_TRANSPORT must be smtp, file, log or memory",
+        );
+        check(
+            self.notification_transport != "smtp" || !self.email_host.is_empty(),
+            "EMAIL_HOST must be set when NOTIFICATION_TRANSPORT is smtp",
+        );
+        check(
+            matches!(self.sms_transport.as_str(), "log" | "webhook"),
+            "SMS_TRANSPORT must be log or webhook",
+        );
+        check(
+            self.sms_transport != "webhook" || self.sms_webhook_url.is_some(),
+            "SMS_WEBHOOK_URL must be set when SMS_TRANSPORT is webhook",
+        );
+    }
+
+    /// Reads `OIDC_PROVIDERS` (e.g. `google,github`) and the `OIDC_<NAME>_*` variables of each
+    /// listed provider. Endpoints are discovered from `OIDC_<NAME>_ISSUER` unless overridden.
+    fn oidc_providers(source: &ConfigSource) -> Vec<OidcProviderConfig> {
+        let names = source.string("OIDC_PROVIDERS", "");
+
+        names
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(|name| {
+                let prefix = format!("OIDC_{}", name.to_uppercase());
+                let key = |key: &str| format!("{}_{}", prefix, key);
+                let var = |key: &str| source.var(&format!("{}_{}", prefix, key));
+
+                let provider = OidcProviderConfig {
+                    name: name.to_lowercase(),
+                    client_id: source.required(&key("CLIENT_ID")),
+                    client_secret: source.required(&key("CLIENT_SECRET")),
+                    issuer: var("ISSUER"),
+                    auth_url: var("AUTH_URL"),
+                    token_url: var("TOKEN_URL"),
+                    userinfo_url: var("USERINFO_URL"),
+                    scopes: var("SCOPES").unwrap_or_else(|| "openid email profile".to_string()),
+                    trust_email: var("TRUST_EMAIL").is_some_and(|v| v == "true"),
+                };
+
+                let has_endpoints = provider.auth_url.is_some()
+                    && provider.token_url.is_some()
+                    && provider.userinfo_url.is_some();
+                if provider.issuer.is_none() && !has_endpoints {
+                    source.problem(format!(
+                        "{}_ISSUER or {0}_AUTH_URL, {0}_TOKEN_URL and {0}_USERINFO_URL must be set",
+                        prefix
+                    ));
+                }
+
+                provider
+            })
+            .collect()
     }
 }
 
-pub static ENV_CONFIG: Lazy<EnvConfig> = Lazy::new(EnvConfig::init);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID: &str = r#"
+database_url = "postgres://localhost/test"
+access_secret = "access-secret-access-secret-0123456789"
+refresh_secret = "refresh-secret-refresh-secret-0123456789"
+secret_key = "secret-key-secret-key-secret-key-0123456789"
+access_token_duration = 900
+refresh_token_duration = 86400
+redis_host = "localhost"
+redis_port = 6379
+notification_transport = "log"
+"#;
+
+    fn problems(toml: &str) -> Vec<String> {
+        EnvConfig::from_toml(toml).err().unwrap_or_default()
+    }
+
+    #[test]
+    fn valid_config_reads_tables_and_arrays() {
+        let config = EnvConfig::from_toml(&format!(
+            "{}\n[grpc]\nrequest_timeout = 3\nweb_allowed_origins = [\"https://a.example\", \"https://b.example\"]\n",
+            VALID
+        ))
+        .unwrap();
+
+        assert_eq!(config.grpc_request_timeout, 3);
+        assert_eq!(
+            config.grpc_web_allowed_origins,
+            vec!["https://a.example", "https://b.example"]
+        );
+        assert_eq!(config.outbox_max_attempts, 8);
+    }
+
+    #[test]
+    fn every_missing_key_is_reported_at_once() {
+        let problems = problems("");
+
+        for key in [
+            "DATABASE_URL",
+            "ACCESS_SECRET",
+            "REFRESH_SECRET",
+            "SECRET_KEY",
+            "ACCESS_TOKEN_DURATION",
+            "REFRESH_TOKEN_DURATION",
+            "REDIS_HOST",
+            "REDIS_PORT",
+        ] {
+            assert!(
+                problems.contains(&format!("{} must be set", key)),
+                "{} not reported in {:?}",
+                key,
+                problems
+            );
+        }
+    }
+
+    #[test]
+    fn unparsable_values_name_the_expected_type() {
+        let toml = format!("{}\ngrpc_request_timeout = \"soon\"\n", VALID);
+
+        assert_eq!(
+            problems(&toml),
+            vec!["GRPC_REQUEST_TIMEOUT must be a valid u64"]
+        );
+    }
 
-pub fn cfg() -> &'static EnvConfig {
-    &ENV_CONFIG
+    #[test]
+    fn listen_addresses_are_checked_together() {
+        let toml = format!(
+            "{}\nserver_address = \"0.0.0.0:0\"\nhttp_address = \"localhost:8080\"\nmetrics_address = \"0.0.0.0:0\"\n",
+            VALID
+        );
+
+        assert_eq!(
+            problems(&toml),
+            vec![
+                "HTTP_ADDRESS must be a valid SocketAddr",
+                "SERVER_ADDRESS must name a port",
+                "METRICS_ADDRESS must name a port",
+            ]
+        );
+    }
+
+    #[test]
+    fn weak_or_shared_secrets_are_rejected() {
+        let toml = VALID
+            .replace("access-secret-access-secret-0123456789", "short")
+            .replace(
+                "refresh-secret-refresh-secret-0123456789",
+                "secret-key-secret-key-secret-key-0123456789",
+            );
+        let problems = problems(&toml);
+
+        assert!(problems.contains(&format!(
+            "ACCESS_SECRET must be at least {} characters",
+            MIN_SECRET_LENGTH
+        )));
+        assert!(!problems.iter().any(|p| p.starts_with("REFRESH_SECRET")));
+
+        let shared = VALID.replace(
+            "refresh-secret-refresh-secret-0123456789",
+            "access-secret-access-secret-0123456789",
+        );
+        assert_eq!(
+            self::problems(&shared),
+            vec!["ACCESS_SECRET and REFRESH_SECRET must differ"]
+        );
+    }
+
+    #[test]
+    fn dependent_settings_must_be_set_together() {
+        let toml = format!(
+            "{}\n{}",
+            VALID
+                .replace(
+                    "refresh_token_duration = 86400",
+                    "refresh_token_duration = 900"
+                )
+                .replace(
+                    "notification_transport = \"log\"",
+                    "notification_transport = \"smtp\""
+                ),
+            "sms_transport = \"webhook\"\ntls_cert_path = \"cert.pem\"\noutbox_retention = 0\n"
+        );
+
+        assert_eq!(
+            problems(&toml),
+            vec![
+                "REFRESH_TOKEN_DURATION must be longer than ACCESS_TOKEN_DURATION",
+                "TLS_CERT_PATH and TLS_KEY_PATH must be set together",
+                "OUTBOX_RETENTION must be greater than 0",
+                "EMAIL_HOST must be set when NOTIFICATION_TRANSPORT is smtp",
+                "SMS_WEBHOOK_URL must be set when SMS_TRANSPORT is webhook",
+            ]
+        );
+    }
+
+    #[test]
+    fn oidc_providers_need_an_issuer_or_every_endpoint() {
+        let toml = format!(
+            "{}\n{}",
+            VALID,
+            r#"
+oidc_providers = "google"
+[oidc_google]
+client_id = "id"
+client_secret = "secret"
+auth_url = "https://accounts.example/auth"
+"#
+        );
+
+        assert_eq!(
+            problems(&toml),
+            vec![
+                "OIDC_GOOGLE_ISSUER or OIDC_GOOGLE_AUTH_URL, OIDC_GOOGLE_TOKEN_URL and OIDC_GOOGLE_USERINFO_URL must be set"
+            ]
+        );
+    }
+
+    #[test]
+    fn identity_provider_needs_a_readable_signing_key() {
+        let toml = format!(
+            "{}\noidc_issuer = \"https://id.example\"\noidc_signing_key_path = \"/nonexistent/key.pem\"\n",
+            VALID
+        );
+        let problems = problems(&toml);
+
+        assert_eq!(problems.len(), 1, "{:?}", problems);
+        assert!(
+            problems[0].starts_with("OIDC_SIGNING_KEY_PATH /nonexistent/key.pem could not be read")
+        );
+
+        // Without an issuer the key is never loaded, so it need not exist.
+        let unused = format!(
+            "{}\noidc_signing_key_path = \"/nonexistent/key.pem\"\n",
+            VALID
+        );
+        assert!(self::problems(&unused).is_empty());
+    }
+
+    #[test]
+    fn unreadable_secret_files_are_reported() {
+        let toml = VALID.replace(
+            "secret_key = \"secret-key-secret-key-secret-key-0123456789\"",
+            "secret_key_file = \"/nonexistent/secret_key\"",
+        );
+        let problems = problems(&toml);
+
+        assert!(
+            problems
+                .iter()
+                .any(|p| p.starts_with("SECRET_KEY_FILE /nonexistent/secret_key could not be read")),
+            "{:?}",
+            problems
+        );
+        assert!(problems.contains(&"SECRET_KEY must be set".to_string()));
+    }
 }
@@ -1,13 +1,13 @@
-use crate::cfg;
-use redis::{Client, RedisResult}; // Import cfg from the crate root
+use crate::config::env::EnvConfig;
+use redis::{Client, RedisResult};
 
+#[derive(Clone)]
 pub struct RedisClient {
     pub client: Client,
 }
 
 impl RedisClient {
-    pub fn new() -> RedisResult<Self> {
-        let config = cfg();
+    pub fn new(config: &EnvConfig) -> RedisResult<Self> {
         let host = &config.redis_host;
         let port = config.redis_port;
         let password = config.redis_password.as_deref(); // Use as_deref for Option<String>
@@ -0,0 +1,199 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Debug;
+use std::fs;
+use std::str::FromStr;
+
+const DEFAULT_CONFIG_FILE: &str = "config.toml";
+
+/// Looks configuration keys up in, highest precedence first:
+///
+/// 1. the environment (including `.env`), as `KEY`
+/// 2. the environment, as `KEY_FILE` naming a file that holds the value (for mounted secrets)
+/// 3. the TOML file at `CONFIG_FILE` (default `config.toml`, skipped when absent), as `key` or
+///    `key_file`
+///
+/// TOML tables prefix their keys, so `[grpc] request_timeout = 10` is `GRPC_REQUEST_TIMEOUT`.
+/// Arrays are joined with commas.
+///
+/// Problems are collected instead of panicking so they can all be reported together.
+pub struct ConfigSource {
+    file: HashMap<String, String>,
+    env: bool,
+    problems: RefCell<Vec<String>>,
+}
+
+fn flatten(prefix: &str, value: &toml::Value, out: &mut HashMap<String, String>) {
+    let scalar = |value: &toml::Value| match value {
+        toml::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+
+    match value {
+        toml::Value::Table(table) => {
+            for (key, value) in table {
+                let key = key.to_uppercase();
+                let key = if prefix.is_empty() {
+                    key
+                } else {
+                    format!("{}_{}", prefix, key)
+                };
+                flatten(&key, value, out);
+            }
+        }
+        toml::Value::Array(items) => {
+            let joined = items.iter().map(scalar).collect::<Vec<_>>().join(",");
+            out.insert(prefix.to_string(), joined);
+        }
+        value => {
+            out.insert(prefix.to_string(), scalar(value));
+        }
+    }
+}
+
+/// Short type name for error messages, e.g. `u16` or `SocketAddr`.
+fn type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+impl ConfigSource {
+    pub fn load() -> Self {
+        let mut problems = Vec::new();
+        let mut file = HashMap::new();
+
+        let (path, explicit) = match env::var("CONFIG_FILE") {
+            Ok(path) => (path, true),
+            Err(_) => (DEFAULT_CONFIG_FILE.to_string(), false),
+        };
+
+        match fs::read_to_string(&path) {
+            Ok(contents) => match contents.parse::<toml::Table>() {
+                Ok(table) => flatten("", &toml::Value::Table(table), &mut file),
+                Err(e) => problems.push(format!("{} is not valid TOML: {}", path, e)),
+            },
+            Err(e) if explicit => {
+                problems.push(format!("CONFIG_FILE {} could not be read: {}", path, e))
+            }
+            Err(_) => {}
+        }
+
+        ConfigSource {
+            file,
+            env: true,
+            problems: RefCell::new(problems),
+        }
+    }
+
+    /// Reads only `contents`, ignoring the environment, so tests see exactly the keys they set.
+    #[cfg(test)]
+    pub(crate) fn from_toml(contents: &str) -> Self {
+        let mut file = HashMap::new();
+        let mut problems = Vec::new();
+        match contents.parse::<toml::Table>() {
+            Ok(table) => flatten("", &toml::Value::Table(table), &mut file),
+            Err(e) => problems.push(format!("config is not valid TOML: {}", e)),
+        }
+
+        ConfigSource {
+            file,
+            env: false,
+            problems: RefCell::new(problems),
+        }
+    }
+
+    fn read_secret_file(&self, key: &str, path: &str) -> Option<String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Some(contents.trim_end_matches(['\r', '\n']).to_string()),
+            Err(e) => {
+                self.problem(format!("{}_FILE {} could not be read: {}", key, path, e));
+                None
+            }
+        }
+    }
+
+    pub fn var(&self, key: &str) -> Option<String> {
+        let file_key = format!("{}_FILE", key);
+
+        if self.env {
+            if let Ok(value) = env::var(key) {
+                return Some(value);
+            }
+            if let Ok(path) = env::var(&file_key) {
+                return self.read_secret_file(key, &path);
+            }
+        }
+        if let Some(value) = self.file.get(key) {
+            return Some(value.clone());
+        }
+        if let Some(path) = self.file.get(&file_key) {
+            return self.read_secret_file(key, path);
+        }
+        None
+    }
+
+    pub fn string(&self, key: &str, default: &str) -> String {
+        self.var(key).unwrap_or_else(|| default.to_string())
+    }
+
+    pub fn required(&self, key: &str) -> String {
+        self.var(key).unwrap_or_else(|| {
+            self.problem(format!("{} must be set", key));
+            String::new()
+        })
+    }
+
+    pub fn flag(&self, key: &str, default: bool) -> bool {
+        self.var(key).map_or(default, |v| v == "true")
+    }
+
+    fn parse_value<T>(&self, key: &str, value: &str) -> Option<T>
+    where
+        T: FromStr,
+    {
+        value.trim().parse().ok().or_else(|| {
+            self.problem(format!("{} must be a valid {}", key, type_name::<T>()));
+            None
+        })
+    }
+
+    /// Parses `key`, falling back to `default` when it is unset or (after recording the
+    /// problem) invalid.
+    pub fn parse<T>(&self, key: &str, default: &str) -> T
+    where
+        T: FromStr,
+        T::Err: Debug,
+    {
+        self.var(key)
+            .and_then(|value| self.parse_value(key, &value))
+            .unwrap_or_else(|| default.parse().expect("default must parse"))
+    }
+
+    pub fn parse_required<T>(&self, key: &str) -> T
+    where
+        T: FromStr + Default,
+    {
+        match self.var(key) {
+            Some(value) => self.parse_value(key, &value).unwrap_or_default(),
+            None => {
+                self.problem(format!("{} must be set", key));
+                T::default()
+            }
+        }
+    }
+
+    pub fn problem(&self, problem: String) {
+        self.problems.borrow_mut().push(problem);
+    }
+
+    /// Every problem found so far, or `Ok` if the configuration is usable.
+    pub fn finish(self) -> Result<(), Vec<String>> {
+        let problems = self.problems.into_inner();
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::config::env::EnvConfig;
+use opentelemetry::global;
+use opentelemetry::trace::TracerProvider;
+use opentelemetry_otlp::{ExporterBuildError, SpanExporter, WithExportConfig};
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::{Sampler, SdkTracerProvider};
+use tracing::Subscriber;
+use tracing_subscriber::filter::dynamic_filter_fn;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer};
+
+const CRATE_TARGET: &str = "rusuh_grpc";
+const ADAPTER_TARGET: &str = "rusuh_grpc::infrastructure";
+
+/// Exports this crate's spans through `provider`. Adapter spans (SQL, Redis, SMTP) are only
+/// exported inside a trace a request or delivery started, so background polling such as the
+/// outbox dispatcher and health probes does not produce a root trace per tick.
+///
+/// Tests can pass a provider built around `opentelemetry_sdk::trace::InMemorySpanExporter` and
+/// install the layer with `tracing::subscriber::set_default`.
+pub fn otel_layer<S>(provider: &SdkTracerProvider) -> impl Layer<S>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    tracing_opentelemetry::layer()
+        .with_tracer(provider.tracer(CRATE_TARGET))
+        .with_filter(dynamic_filter_fn(|metadata, cx| {
+            if !metadata.is_span() {
+                return *metadata.level() <= tracing::Level::INFO;
+            }
+            if metadata.target().starts_with(ADAPTER_TARGET) {
+                return cx.lookup_current().is_some();
+            }
+            metadata.target().starts_with(CRATE_TARGET)
+        }))
+}
+
+/// Batches spans to the OTLP/gRPC collector at `OTEL_EXPORTER_OTLP_ENDPOINT`.
+fn otlp_provider(
+    config: &EnvConfig,
+    endpoint: &str,
+) -> Result<SdkTracerProvider, ExporterBuildError> {
+    let exporter = SpanExporter::builder()
+        .with_tonic()
+        .with_endpoint(endpoint)
+        .build()?;
+
+    Ok(SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+            config.otel_traces_sample_ratio,
+        ))))
+        .with_resource(
+            Resource::builder()
+                .with_service_name(config.otel_service_name.clone())
+                .build(),
+        )
+        .build())
+}
+
+/// Installs the global subscriber: logs filtered by `RUST_LOG`, prefixed with the request span
+/// and its trace id, plus OTLP span export when an endpoint is configured. Must run inside the
+/// Tokio runtime. The returned provider should be shut down on exit to flush pending spans.
+pub fn init_tracing(config: &EnvConfig) -> Result<Option<SdkTracerProvider>, ExporterBuildError> {
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
+    let provider = config
+        .otel_exporter_otlp_endpoint
+        .as_deref()
+        .map(|endpoint| otlp_provider(config, endpoint))
+        .transpose()?;
+
+    let logs = tracing_subscriber::fmt::layer()
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(true)
+        .with_filter(EnvFilter::from_default_env());
+
+    tracing_subscriber::registry()
+        .with(logs)
+        .with(provider.as_ref().map(otel_layer))
+        .init();
+
+    Ok(provider)
+}
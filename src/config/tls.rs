@@ -0,0 +1,338 @@
+use crate::config::env::EnvConfig;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::BufReader;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime};
+use tokio_rustls::TlsAcceptor;
+use tokio_rustls::rustls::crypto::ring;
+use tokio_rustls::rustls::pki_types::CertificateDer;
+use tokio_rustls::rustls::server::WebPkiClientVerifier;
+use tokio_rustls::rustls::{RootCertStore, ServerConfig};
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
+
+const ALPN_H2: &[u8] = b"h2";
+const ALPN_HTTP1: &[u8] = b"http/1.1";
+
+struct TlsFiles {
+    cert_path: String,
+    key_path: String,
+    client_ca_path: Option<String>,
+    client_auth_required: bool,
+    /// Also offer HTTP/1.1, for gRPC-Web clients that cannot negotiate HTTP/2.
+    http1: bool,
+}
+
+/// TLS settings for the gRPC listener, re-read from `TLS_CERT_PATH`, `TLS_KEY_PATH` and
+/// `TLS_CLIENT_CA_PATH` when they change. Existing connections keep the settings they were
+/// accepted with; new connections pick up the reloaded ones.
+pub struct TlsReloader {
+    files: TlsFiles,
+    acceptor: RwLock<TlsAcceptor>,
+}
+
+fn read_certs(path: &str) -> Result<Vec<CertificateDer<'static>>, Box<dyn Error + Send + Sync>> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let certs = rustls_pemfile::certs(&mut reader).collect::<Result<Vec<_>, _>>()?;
+    if certs.is_empty() {
+        return Err(format!("{} contains no certificates", path).into());
+    }
+    Ok(certs)
+}
+
+fn server_config(files: &TlsFiles) -> Result<ServerConfig, Box<dyn Error + Send + Sync>> {
+    let certs = read_certs(&files.cert_path)?;
+    let key = rustls_pemfile::private_key(&mut BufReader::new(File::open(&files.key_path)?))?
+        .ok_or_else(|| format!("{} contains no private key", files.key_path))?;
+
+    let provider = Arc::new(ring::default_provider());
+    let builder = ServerConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()?;
+
+    let builder = match &files.client_ca_path {
+        Some(path) => {
+            let mut roots = RootCertStore::empty();
+            for cert in read_certs(path)? {
+                roots.add(cert)?;
+            }
+
+            let verifier = WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider);
+            // Without TLS_CLIENT_AUTH_REQUIRED, callers may skip the certificate, but one that is
+            // presented must still verify.
+            let verifier = if files.client_auth_required {
+                verifier
+            } else {
+                verifier.allow_unauthenticated()
+            };
+            builder.with_client_cert_verifier(verifier.build()?)
+        }
+        None => builder.with_no_client_auth(),
+    };
+
+    let mut config = builder.with_single_cert(certs, key)?;
+    config.alpn_protocols = vec![ALPN_H2.to_vec()];
+    if files.http1 {
+        config.alpn_protocols.push(ALPN_HTTP1.to_vec());
+    }
+    Ok(config)
+}
+
+impl TlsFiles {
+    fn paths(&self) -> impl Iterator<Item = &str> {
+        [
+            Some(&self.cert_path),
+            Some(&self.key_path),
+            self.client_ca_path.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(String::as_str)
+    }
+
+    fn modified(&self) -> Vec<Option<SystemTime>> {
+        self.paths()
+            .map(|path| fs::metadata(path).and_then(|m| m.modified()).ok())
+            .collect()
+    }
+}
+
+impl TlsReloader {
+    /// Loads the certificate and key named by the config. Only call when TLS is enabled.
+    pub fn new(config: &EnvConfig) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let files = TlsFiles {
+            cert_path: config.tls_cert_path.clone().unwrap_or_default(),
+            key_path: config.tls_key_path.clone().unwrap_or_default(),
+            client_ca_path: config.tls_client_ca_path.clone(),
+            client_auth_required: config.tls_client_auth_required,
+            http1: config.grpc_web_enabled,
+        };
+
+        let acceptor = TlsAcceptor::from(Arc::new(server_config(&files)?));
+        info!(
+            "TLS enabled with certificate {}{}",
+            files.cert_path,
+            match &files.client_ca_path {
+                Some(path) => format!(", verifying client certificates against {}", path),
+                None => String::new(),
+            }
+        );
+
+        Ok(TlsReloader {
+            files,
+            acceptor: RwLock::new(acceptor),
+        })
+    }
+
+    pub fn acceptor(&self) -> TlsAcceptor {
+        self.acceptor
+            .read()
+            .expect("TLS acceptor lock poisoned")
+            .clone()
+    }
+
+    /// Checks the files every `interval` until `shutdown` is cancelled and swaps in the new
+    /// settings when any of them changed. Invalid files are reported and the previous settings
+    /// stay in use until the files are fixed.
+    pub async fn watch(self: Arc<Self>, interval: Duration, shutdown: CancellationToken) {
+        let mut last_modified = self.files.modified();
+
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(interval) => {}
+                _ = shutdown.cancelled() => return,
+            }
+
+            let modified = self.files.modified();
+            if modified == last_modified {
+                continue;
+            }
+
+            match server_config(&self.files) {
+                Ok(config) => {
+                    *self.acceptor.write().expect("TLS acceptor lock poisoned") =
+                        TlsAcceptor::from(Arc::new(config));
+                    last_modified = modified;
+                    info!("TLS certificates reloaded");
+                }
+                Err(e) => error!(
+                    "Failed to reload TLS certificates, keeping the old ones: {}",
+                    e
+                ),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_config;
+    use std::path::{Path, PathBuf};
+    use tokio_rustls::TlsConnector;
+    use tokio_rustls::rustls::client::danger::{
+        HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier,
+    };
+    use tokio_rustls::rustls::crypto::{
+        CryptoProvider, verify_tls12_signature, verify_tls13_signature,
+    };
+    use tokio_rustls::rustls::pki_types::{ServerName, UnixTime};
+    use tokio_rustls::rustls::{ClientConfig, DigitallySignedStruct, SignatureScheme};
+    use uuid::Uuid;
+
+    const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+
+    /// Accepts whatever the server presents; the tests only look at which certificate it was.
+    #[derive(Debug)]
+    struct AnyCertificate(CryptoProvider);
+
+    impl ServerCertVerifier for AnyCertificate {
+        fn verify_server_cert(
+            &self,
+            _end_entity: &CertificateDer<'_>,
+            _intermediates: &[CertificateDer<'_>],
+            _server_name: &ServerName<'_>,
+            _ocsp_response: &[u8],
+            _now: UnixTime,
+        ) -> Result<ServerCertVerified, tokio_rustls::rustls::Error> {
+            Ok(ServerCertVerified::assertion())
+        }
+
+        fn verify_tls12_signature(
+            &self,
+            message: &[u8],
+            cert: &CertificateDer<'_>,
+            dss: &DigitallySignedStruct,
+        ) -> Result<HandshakeSignatureValid, tokio_rustls::rustls::Error> {
+            verify_tls12_signature(
+                message,
+                cert,
+                dss,
+                &self.0.signature_verification_algorithms,
+            )
+        }
+
+        fn verify_tls13_signature(
+            &self,
+            message: &[u8],
+            cert: &CertificateDer<'_>,
+            dss: &DigitallySignedStruct,
+        ) -> Result<HandshakeSignatureValid, tokio_rustls::rustls::Error> {
+            verify_tls13_signature(
+                message,
+                cert,
+                dss,
+                &self.0.signature_verification_algorithms,
+            )
+        }
+
+        fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+            self.0.signature_verification_algorithms.supported_schemes()
+        }
+    }
+
+    /// A scratch directory holding `cert.pem` and `key.pem`, copied from the named fixture.
+    fn tls_dir(fixture: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("tls-reload-{}", Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        install(&dir, fixture);
+        dir
+    }
+
+    fn install(dir: &Path, fixture: &str) {
+        for part in ["cert", "key"] {
+            fs::copy(
+                format!("{}/tls_{}_{}.pem", FIXTURES, fixture, part),
+                dir.join(format!("{}.pem", part)),
+            )
+            .unwrap();
+        }
+    }
+
+    fn fixture_cert(fixture: &str) -> CertificateDer<'static> {
+        read_certs(&format!("{}/tls_{}_cert.pem", FIXTURES, fixture))
+            .unwrap()
+            .remove(0)
+    }
+
+    fn reloader(dir: &Path) -> Arc<TlsReloader> {
+        let config = test_config(&format!(
+            "tls_cert_path = \"{}\"\ntls_key_path = \"{}\"\n",
+            dir.join("cert.pem").display(),
+            dir.join("key.pem").display()
+        ));
+        Arc::new(TlsReloader::new(&config).unwrap())
+    }
+
+    /// Runs a handshake against the current acceptor and returns the certificate it served.
+    async fn served_cert(reloader: &TlsReloader) -> CertificateDer<'static> {
+        let provider = ring::default_provider();
+        let client = ClientConfig::builder_with_provider(Arc::new(provider.clone()))
+            .with_safe_default_protocol_versions()
+            .unwrap()
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(AnyCertificate(provider)))
+            .with_no_client_auth();
+        let (client_io, server_io) = tokio::io::duplex(16 * 1024);
+
+        let (client, server) = tokio::join!(
+            TlsConnector::from(Arc::new(client))
+                .connect(ServerName::try_from("first.test").unwrap(), client_io),
+            reloader.acceptor().accept(server_io),
+        );
+        server.unwrap();
+        client.unwrap().get_ref().1.peer_certificates().unwrap()[0].clone()
+    }
+
+    /// Waits for the watcher to serve `expected`, giving up after two seconds.
+    async fn wait_for_cert(reloader: &TlsReloader, expected: &CertificateDer<'static>) -> bool {
+        for _ in 0..100 {
+            if served_cert(reloader).await == *expected {
+                return true;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        false
+    }
+
+    fn watch(reloader: &Arc<TlsReloader>) -> CancellationToken {
+        let shutdown = CancellationToken::new();
+        tokio::spawn(
+            reloader
+                .clone()
+                .watch(Duration::from_millis(10), shutdown.clone()),
+        );
+        shutdown
+    }
+
+    #[tokio::test]
+    async fn rewritten_files_are_served_to_new_connections() {
+        let dir = tls_dir("first");
+        let reloader = reloader(&dir);
+        let shutdown = watch(&reloader);
+        assert_eq!(served_cert(&reloader).await, fixture_cert("first"));
+
+        install(&dir, "second");
+
+        assert!(wait_for_cert(&reloader, &fixture_cert("second")).await);
+        shutdown.cancel();
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn broken_replacement_keeps_the_previous_certificate() {
+        let dir = tls_dir("first");
+        let reloader = reloader(&dir);
+        let shutdown = watch(&reloader);
+
+        fs::write(dir.join("cert.pem"), "not a certificate").unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(served_cert(&reloader).await, fixture_cert("first"));
+
+        // Fixing the files afterwards is still picked up.
+        install(&dir, "second");
+        assert!(wait_for_cert(&reloader, &fixture_cert("second")).await);
+        shutdown.cancel();
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
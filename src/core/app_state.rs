@@ -0,0 +1,256 @@
+use crate::application::account_event_use_case::AccountEventUseCase;
+use crate::application::api_key_use_case::ApiKeyUseCase;
+use crate::application::audit_use_case::AuditUseCase;
+use crate::application::auth_use_case::AuthUseCase;
+use crate::application::client_credentials_use_case::ClientCredentialsUseCase;
+use crate::application::invitation_use_case::InvitationUseCase;
+use crate::application::metrics_use_case::MetricsUseCase;
+use crate::application::oidc_provider_use_case::OidcProviderUseCase;
+use crate::application::organization_use_case::OrganizationUseCase;
+use crate::application::outbox_use_case::OutboxUseCase;
+use crate::application::webhook_use_case::WebhookUseCase;
+use crate::config::db::get_db_pool;
+use crate::config::env::EnvConfig;
+use crate::config::redis::RedisClient;
+use crate::config::tls::TlsReloader;
+use crate::domain::port::db::api_key_port::ApiKeyPort;
+use crate::domain::port::health_check_port::HealthCheckPort;
+use crate::domain::port::notification_port::NotificationPort;
+use crate::domain::port::redis_port::RedisPort;
+use crate::domain::port::sms_port::SmsPort;
+use crate::domain::service::event_bus_service::EventBus;
+use crate::domain::service::otp_service::OtpEmail;
+use crate::domain::service::totp_service::Totp;
+use crate::domain::service::webauthn_service::WebauthnService;
+use crate::infrastructure::db::api_key_adapter::ApiKeyAdapter;
+use crate::infrastructure::db::audit_adapter::AuditAdapter;
+use crate::infrastructure::db::invitation_adapter::InvitationAdapter;
+use crate::infrastructure::db::membership_adapter::MembershipAdapter;
+use crate::infrastructure::db::oauth_client_adapter::OAuthClientAdapter;
+use crate::infrastructure::db::oauth_consent_adapter::OAuthConsentAdapter;
+use crate::infrastructure::db::organization_adapter::OrganizationAdapter;
+use crate::infrastructure::db::outbox_adapter::OutboxAdapter;
+use crate::infrastructure::db::passkey_credential_adapter::PasskeyCredentialAdapter;
+use crate::infrastructure::db::service_client_adapter::ServiceClientAdapter;
+use crate::infrastructure::db::user_adapter::UserAdapter;
+use crate::infrastructure::db::user_identity_adapter::UserIdentityAdapter;
+use crate::infrastructure::db::user_info_adapter::UserInfoAdapter;
+use crate::infrastructure::db::user_session_adapter::UserSessionAdapter;
+use crate::infrastructure::db::webhook_delivery_adapter::WebhookDeliveryAdapter;
+use crate::infrastructure::db::webhook_subscription_adapter::WebhookSubscriptionAdapter;
+use crate::infrastructure::health::postgres_health_adapter::PostgresHealthAdapter;
+use crate::infrastructure::health::redis_health_adapter::RedisHealthAdapter;
+use crate::infrastructure::metrics::prometheus_adapter::PrometheusAdapter;
+use crate::infrastructure::notification::email_template::EmailRenderer;
+use crate::infrastructure::notification::file_adapter::FileAdapter;
+use crate::infrastructure::notification::log_adapter::LogAdapter;
+use crate::infrastructure::notification::log_sms_adapter::LogSmsAdapter;
+use crate::infrastructure::notification::memory_adapter::MemoryAdapter;
+use crate::infrastructure::notification::smtp_adapter::SmtpAdapter;
+use crate::infrastructure::notification::webhook_sms_adapter::WebhookSmsAdapter;
+use crate::infrastructure::oidc::oidc_adapter::OidcAdapter;
+use crate::infrastructure::redis::account_event_adapter::RedisAccountEventAdapter;
+use crate::infrastructure::redis::redis_adapter::RedisAdapter;
+use crate::infrastructure::webhook::http_webhook_adapter::HttpWebhookAdapter;
+use sqlx::PgPool;
+use std::error;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+/// Everything the server runs on, wired explicitly from one `EnvConfig`. Nothing here is global,
+/// so differently configured instances can live side by side in one process.
+pub struct AppState {
+    pub config: Arc<EnvConfig>,
+    pub pool: PgPool,
+    /// Cancelled to stop the outbox dispatcher, account event feeds and HTTP listeners.
+    pub shutdown: CancellationToken,
+    /// Only built when `TLS_CERT_PATH` is set; the listener is plaintext h2c otherwise.
+    pub tls: Option<Arc<TlsReloader>>,
+    pub redis: Arc<dyn RedisPort + Send + Sync>,
+    /// Looked up by the handler to authenticate `x-api-key` callers.
+    pub api_key_repo: Arc<dyn ApiKeyPort + Send + Sync>,
+    pub health_checks: Vec<Arc<dyn HealthCheckPort>>,
+    /// Recorded whether or not `METRICS_ENABLED` serves them.
+    pub metrics: Arc<PrometheusAdapter>,
+    pub totp: Arc<Totp>,
+    pub auth: AuthUseCase,
+    pub client_credentials: ClientCredentialsUseCase,
+    pub api_keys: ApiKeyUseCase,
+    pub organizations: OrganizationUseCase,
+    pub invitations: InvitationUseCase,
+    pub audit: AuditUseCase,
+    pub outbox: OutboxUseCase,
+    pub webhooks: WebhookUseCase,
+    pub account_events: AccountEventUseCase,
+    /// Only built when `OIDC_ISSUER` is set.
+    pub oidc_provider: Option<OidcProviderUseCase>,
+}
+
+/// Picks the notification transport named by `NOTIFICATION_TRANSPORT`.
+fn notification_adapter(
+    config: &EnvConfig,
+    renderer: Arc<EmailRenderer>,
+) -> Arc<dyn NotificationPort> {
+    match config.notification_transport.as_str() {
+        "smtp" => Arc::new(SmtpAdapter::new(config, renderer)),
+        "file" => Arc::new(FileAdapter::new(&config.notification_file_dir, renderer)),
+        "log" => Arc::new(LogAdapter::new(renderer)),
+        "memory" => Arc::new(MemoryAdapter::new()),
+        transport => panic!(
+            "NOTIFICATION_TRANSPORT must be smtp, file, log or memory, got {}",
+            transport
+        ),
+    }
+}
+
+/// Picks the SMS transport named by `SMS_TRANSPORT`.
+fn sms_adapter(config: &EnvConfig) -> Arc<dyn SmsPort> {
+    match config.sms_transport.as_str() {
+        "webhook" => Arc::new(WebhookSmsAdapter::new(
+            config
+                .sms_webhook_url
+                .clone()
+                .expect("SMS_WEBHOOK_URL must be set when SMS_TRANSPORT is webhook"),
+            config.sms_webhook_token.clone(),
+        )),
+        "log" => Arc::new(LogSmsAdapter),
+        transport => panic!("SMS_TRANSPORT must be log or webhook, got {}", transport),
+    }
+}
+
+impl AppState {
+    /// Connects to Postgres and Redis and builds every adapter, service and use case.
+    pub async fn build(config: EnvConfig) -> Result<Self, Box<dyn error::Error>> {
+        let config = Arc::new(config);
+        let tls = if config.tls_enabled() {
+            let tls = TlsReloader::new(&config)
+                .map_err(|e| format!("Failed to load TLS certificates: {}", e))?;
+            Some(Arc::new(tls))
+        } else {
+            None
+        };
+        let pool = get_db_pool(&config).await?;
+        let redis_client = RedisClient::new(&config)?;
+        let shutdown = CancellationToken::new();
+        let renderer = Arc::new(EmailRenderer::new(&config));
+
+        let mut health_checks: Vec<Arc<dyn HealthCheckPort>> = vec![
+            Arc::new(PostgresHealthAdapter::new(pool.clone())),
+            Arc::new(RedisHealthAdapter::new(redis_client.clone())),
+        ];
+        if config.health_check_smtp {
+            health_checks.push(Arc::new(SmtpAdapter::new(&config, renderer.clone())));
+        }
+        let metrics = Arc::new(PrometheusAdapter::new(
+            pool.clone(),
+            Arc::new(RedisHealthAdapter::new(redis_client.clone())),
+            Duration::from_secs(config.health_check_timeout),
+        )?);
+
+        let user_repo = Arc::new(UserAdapter::new(pool.clone()));
+        let redis_repo = Arc::new(RedisAdapter::new(redis_client.clone()));
+        let organization_repo = Arc::new(OrganizationAdapter::new(pool.clone()));
+        let membership_repo = Arc::new(MembershipAdapter::new(pool.clone()));
+        let outbox_repo = Arc::new(OutboxAdapter::new(pool.clone()));
+
+        let webhooks = WebhookUseCase::new(
+            Arc::new(WebhookSubscriptionAdapter::new(pool.clone())),
+            Arc::new(WebhookDeliveryAdapter::new(pool.clone())),
+            Arc::new(HttpWebhookAdapter::new()),
+        );
+        let account_events = AccountEventUseCase::new(
+            Arc::new(RedisAccountEventAdapter::new(redis_client)),
+            shutdown.clone(),
+        );
+        let events = Arc::new(
+            EventBus::new(outbox_repo.clone())
+                .subscribe(Arc::new(webhooks.clone()))
+                .subscribe(Arc::new(account_events.clone()))
+                .subscribe(Arc::new(MetricsUseCase::new(metrics.clone()))),
+        );
+
+        let auth = AuthUseCase::new(
+            user_repo.clone(),
+            Arc::new(UserSessionAdapter::new(pool.clone())),
+            redis_repo.clone(),
+            Arc::new(UserIdentityAdapter::new(pool.clone())),
+            Arc::new(OidcAdapter::new(&config)),
+            Arc::new(PasskeyCredentialAdapter::new(pool.clone())),
+            organization_repo.clone(),
+            membership_repo.clone(),
+            outbox_repo.clone(),
+            events.clone(),
+            Arc::new(OtpEmail::init()),
+            Arc::new(WebauthnService::new(&config)),
+            config.clone(),
+        );
+        let client_credentials = ClientCredentialsUseCase::new(
+            Arc::new(ServiceClientAdapter::new(pool.clone())),
+            config.clone(),
+        );
+        let api_key_repo = Arc::new(ApiKeyAdapter::new(pool.clone()));
+        let api_keys = ApiKeyUseCase::new(api_key_repo.clone());
+        let invitations = InvitationUseCase::new(
+            Arc::new(InvitationAdapter::new(pool.clone())),
+            organization_repo.clone(),
+            membership_repo.clone(),
+            user_repo.clone(),
+            events,
+            config.clone(),
+        );
+        let audit = AuditUseCase::new(
+            Arc::new(AuditAdapter::new(pool.clone())),
+            user_repo.clone(),
+            membership_repo,
+        );
+        let organizations = OrganizationUseCase::new(organization_repo);
+        let outbox = OutboxUseCase::new(
+            outbox_repo,
+            notification_adapter(&config, renderer),
+            sms_adapter(&config),
+            redis_repo.clone(),
+            webhooks.clone(),
+            config.clone(),
+        );
+
+        let oidc_provider = config
+            .oidc_issuer
+            .is_some()
+            .then(|| {
+                OidcProviderUseCase::new(
+                    auth.clone(),
+                    audit.clone(),
+                    user_repo,
+                    Arc::new(UserInfoAdapter::new(pool.clone())),
+                    Arc::new(OAuthClientAdapter::new(pool.clone())),
+                    Arc::new(OAuthConsentAdapter::new(pool.clone())),
+                    redis_repo.clone(),
+                    config.clone(),
+                )
+            })
+            .transpose()?;
+
+        Ok(AppState {
+            totp: Arc::new(Totp::new(config.app_name.clone())),
+            config,
+            pool,
+            shutdown,
+            tls,
+            redis: redis_repo,
+            api_key_repo,
+            health_checks,
+            metrics,
+            auth,
+            client_credentials,
+            api_keys,
+            organizations,
+            invitations,
+            audit,
+            outbox,
+            webhooks,
+            account_events,
+            oidc_provider,
+        })
+    }
+}
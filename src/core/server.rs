@@ -1,48 +1,317 @@
-use crate::config::db::get_db_pool;
-use crate::infrastructure::db::user_adapter::UserAdapter;
-use crate::infrastructure::db::user_session_adapter::UserSessionAdapter;
-use crate::infrastructure::redis::redis_adapter::RedisAdapter;
+use crate::application::health_use_case::HealthUseCase;
+use crate::config::env::EnvConfig;
+use crate::config::telemetry::init_tracing;
+use crate::config::tls::TlsReloader;
+use crate::core::app_state::AppState;
 use crate::interface::grpc::handler::auth_handler::AuthHandler;
 use crate::interface::grpc::layer::logging_layer::LoggingLayer;
+use crate::interface::grpc::layer::metrics_layer::MetricsLayer;
+use crate::interface::grpc::layer::trace_layer::TraceLayer;
+use crate::interface::http::metrics_router::metrics_router;
+use crate::interface::http::oidc_router::oidc_router;
+use crate::interface::http::rest_router::rest_router;
 use crate::pb::auth::auth_handler_server::AuthHandlerServer;
+use axum::Router;
+use futures::Stream;
+use http::{HeaderName, HeaderValue, Method};
 use std::error;
+use std::io;
+use std::net::SocketAddr;
+use std::pin::Pin;
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio_rustls::server::TlsStream;
+use tokio_util::sync::CancellationToken;
+use tonic::server::NamedService;
 use tonic::transport::Server;
 use tonic_reflection::server::Builder as ReflectionBuilder;
+use tonic_web::GrpcWebLayer;
 use tower::ServiceBuilder;
 use tower::limit::ConcurrencyLimitLayer;
 use tower::timeout::TimeoutLayer;
-use tracing::info;
-use tracing_subscriber::EnvFilter;
-use tracing_subscriber::fmt::format::FmtSpan;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+use tracing::{error, info, warn};
 
 const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("../../descriptor.bin");
+/// How long a client gets to complete the TLS handshake before the connection is dropped.
+const TLS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Request headers browsers may send to `AuthHandler` through gRPC-Web.
+const GRPC_WEB_ALLOW_HEADERS: [&str; 8] = [
+    "content-type",
+    "x-grpc-web",
+    "x-user-agent",
+    "grpc-timeout",
+    "authorization",
+    "x-api-key",
+    "x-locale",
+    "accept-language",
+];
+/// Response headers browsers must be able to read to get the call status.
+const GRPC_WEB_EXPOSE_HEADERS: [&str; 3] =
+    ["grpc-status", "grpc-message", "grpc-status-details-bin"];
+const GRPC_WEB_CORS_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// CORS for gRPC-Web callers from `GRPC_WEB_ALLOWED_ORIGINS`.
+fn grpc_web_cors(config: &EnvConfig) -> CorsLayer {
+    let origins = &config.grpc_web_allowed_origins;
+    let allow_origin = if origins.iter().any(|origin| origin == "*") {
+        AllowOrigin::any()
+    } else {
+        AllowOrigin::list(
+            origins
+                .iter()
+                .filter_map(|origin| HeaderValue::from_str(origin).ok()),
+        )
+    };
+
+    CorsLayer::new()
+        .allow_origin(allow_origin)
+        .allow_methods([Method::POST])
+        .allow_headers(GRPC_WEB_ALLOW_HEADERS.map(HeaderName::from_static))
+        .expose_headers(GRPC_WEB_EXPOSE_HEADERS.map(HeaderName::from_static))
+        .max_age(GRPC_WEB_CORS_MAX_AGE)
+}
+
+/// Accepts TCP connections on `addr` until `shutdown` is cancelled and yields them once their
+/// TLS handshake completes. Handshakes run concurrently so a slow client cannot stall the
+/// listener, and each one uses the acceptor current at the time, so reloads apply immediately.
+async fn tls_incoming(
+    addr: SocketAddr,
+    tls: Arc<TlsReloader>,
+    nodelay: bool,
+    shutdown: CancellationToken,
+) -> io::Result<impl Stream<Item = Result<TlsStream<TcpStream>, io::Error>>> {
+    let listener = TcpListener::bind(addr).await?;
+    let (sender, mut receiver) = mpsc::channel(64);
+
+    tokio::spawn(async move {
+        loop {
+            let (stream, peer) = tokio::select! {
+                accepted = listener.accept() => match accepted {
+                    Ok(accepted) => accepted,
+                    Err(e) => {
+                        error!("Failed to accept connection: {}", e);
+                        continue;
+                    }
+                },
+                _ = shutdown.cancelled() => return,
+            };
+
+            if let Err(e) = stream.set_nodelay(nodelay) {
+                warn!("Failed to set TCP_NODELAY for {}: {}", peer, e);
+            }
+
+            let acceptor = tls.acceptor();
+            let sender = sender.clone();
+            tokio::spawn(async move {
+                match tokio::time::timeout(TLS_HANDSHAKE_TIMEOUT, acceptor.accept(stream)).await {
+                    Ok(Ok(stream)) => {
+                        let _ = sender.send(Ok(stream)).await;
+                    }
+                    Ok(Err(e)) => warn!("TLS handshake with {} failed: {}", peer, e),
+                    Err(_) => warn!("TLS handshake with {} timed out", peer),
+                }
+            });
+        }
+    });
+
+    Ok(futures::stream::poll_fn(move |cx| receiver.poll_recv(cx)))
+}
+
+/// Resolves on SIGINT (Ctrl-C) or SIGTERM.
+async fn wait_for_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            error!("Failed to listen for Ctrl-C: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                error!("Failed to listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+}
+
+/// On a signal, reports NOT_SERVING and waits out the drain period so load balancers stop
+/// routing here, then cancels `shutdown` to close the listeners and stop the workers.
+async fn shutdown_on_signal(
+    health: HealthUseCase,
+    shutdown: CancellationToken,
+    drain_period: Duration,
+) {
+    tokio::select! {
+        _ = wait_for_signal() => {}
+        _ = shutdown.cancelled() => return,
+    }
+
+    info!(
+        "Shutdown signal received, draining for {}s",
+        drain_period.as_secs()
+    );
+    health.mark_shutting_down().await;
+    tokio::time::sleep(drain_period).await;
+
+    info!("Closing listeners and waiting for in-flight requests");
+    shutdown.cancel();
+}
+
+/// Resolves `SHUTDOWN_TIMEOUT` after shutdown starts, bounding how long it may take.
+async fn shutdown_deadline(shutdown: CancellationToken, timeout: Duration) {
+    shutdown.cancelled().await;
+    tokio::time::sleep(timeout).await;
+}
 
 pub async fn server() -> Result<(), Box<dyn error::Error>> {
-    dotenv::dotenv().ok();
+    let config = EnvConfig::load().unwrap_or_else(|problems| {
+        panic!("Invalid configuration:\n  - {}", problems.join("\n  - "))
+    });
+    let tracer_provider = init_tracing(&config)?;
+
+    let result = serve(AppState::build(config).await?).await;
+
+    if let Some(provider) = tracer_provider
+        && let Err(e) = provider.shutdown()
+    {
+        error!("Failed to flush traces: {}", e);
+    }
+    result
+}
+
+/// Runs the gRPC server, background workers and (when configured) the HTTP listener for the REST
+/// gateway and OIDC provider and the metrics listener until a shutdown signal arrives.
+pub async fn serve(state: AppState) -> Result<(), Box<dyn error::Error>> {
+    let AppState {
+        config,
+        shutdown,
+        tls,
+        redis,
+        api_key_repo,
+        health_checks,
+        metrics,
+        auth,
+        client_credentials,
+        api_keys,
+        organizations,
+        invitations,
+        audit,
+        outbox,
+        webhooks,
+        account_events,
+        oidc_provider,
+        ..
+    } = state;
+
+    let (health_reporter, health_server) = tonic_health::server::health_reporter();
+    let health_service = HealthUseCase::new(
+        health_reporter,
+        vec![AuthHandlerServer::<AuthHandler>::NAME],
+        health_checks,
+        config.clone(),
+    );
+    let health_probes = tokio::spawn(health_service.clone().run_probes());
 
-    tracing_subscriber::fmt()
-        .with_env_filter(EnvFilter::from_default_env())
-        .with_span_events(FmtSpan::CLOSE)
-        .with_ansi(true)
-        .init();
+    let mut workers = vec![tokio::spawn(
+        outbox.clone().run_dispatcher(shutdown.clone()),
+    )];
 
-    let pool = get_db_pool().await?;
+    let auth_handler = Arc::new(AuthHandler::new(
+        auth,
+        client_credentials,
+        api_keys,
+        organizations,
+        invitations,
+        audit,
+        outbox,
+        webhooks,
+        account_events,
+        redis,
+        api_key_repo,
+        config.clone(),
+    ));
 
-    let user_repo = Arc::new(UserAdapter::new(pool.clone()));
-    let redis_repo = Arc::new(RedisAdapter::new());
-    let session_repo = Arc::new(UserSessionAdapter::new(pool.clone()));
+    let mut http_router = Router::new();
+    let mut http_surfaces = Vec::new();
+    if config.rest_enabled {
+        http_router = http_router.merge(rest_router(auth_handler.clone()));
+        http_surfaces.push("REST gateway");
+    }
+    if let Some(oidc_provider) = oidc_provider {
+        http_router = http_router.merge(oidc_router(Arc::new(oidc_provider)));
+        http_surfaces.push("OIDC provider");
+    }
 
-    let auth_handler = AuthHandler::new(user_repo, session_repo, redis_repo);
+    if !http_surfaces.is_empty() {
+        let listener = tokio::net::TcpListener::bind(config.http_address).await?;
+        info!(
+            "HTTP listening on {} ({})",
+            config.http_address,
+            http_surfaces.join(", ")
+        );
 
-    let addr = "0.0.0.0:50051".parse()?;
-    info!("Server listening on {}", addr);
+        let http_shutdown = shutdown.clone().cancelled_owned();
+        let http_service = http_router.into_make_service_with_connect_info::<SocketAddr>();
+        workers.push(tokio::spawn(async move {
+            if let Err(e) = axum::serve(listener, http_service)
+                .with_graceful_shutdown(http_shutdown)
+                .await
+            {
+                error!("HTTP server error: {}", e);
+            }
+        }));
+    }
+
+    if config.metrics_enabled {
+        let listener = tokio::net::TcpListener::bind(config.metrics_address).await?;
+        info!("Metrics listening on {}", config.metrics_address);
+
+        let metrics_shutdown = shutdown.clone().cancelled_owned();
+        let metrics_router = metrics_router(metrics.clone());
+        workers.push(tokio::spawn(async move {
+            if let Err(e) = axum::serve(listener, metrics_router)
+                .with_graceful_shutdown(metrics_shutdown)
+                .await
+            {
+                error!("Metrics server error: {}", e);
+            }
+        }));
+    }
+
+    let addr = config.server_address;
+    info!(
+        "Server listening on {} ({})",
+        addr,
+        if tls.is_some() { "TLS" } else { "plaintext" }
+    );
 
     let middleware_stack = ServiceBuilder::new()
+        .option_layer(config.grpc_web_enabled.then(|| grpc_web_cors(&config)))
+        .option_layer(config.grpc_web_enabled.then(GrpcWebLayer::new))
+        .layer(TraceLayer)
         .layer(LoggingLayer)
-        .layer(TimeoutLayer::new(Duration::from_secs(10)))
-        .layer(ConcurrencyLimitLayer::new(64))
+        .layer(MetricsLayer::new(metrics))
+        .layer(TimeoutLayer::new(Duration::from_secs(
+            config.grpc_request_timeout,
+        )))
+        .layer(ConcurrencyLimitLayer::new(config.grpc_concurrency_limit))
         .into_inner();
 
     let reflection_service = ReflectionBuilder::configure()
@@ -55,13 +324,70 @@ pub async fn server() -> Result<(), Box<dyn error::Error>> {
         .build_v1alpha()
         .unwrap();
 
-    Server::builder()
+    let shutdown_timeout = Duration::from_secs(config.shutdown_timeout);
+    tokio::spawn(shutdown_on_signal(
+        health_service,
+        shutdown.clone(),
+        Duration::from_secs(config.shutdown_drain_period),
+    ));
+
+    let auth_server = AuthHandlerServer::from_arc(auth_handler)
+        .max_decoding_message_size(config.grpc_max_message_size)
+        .max_encoding_message_size(config.grpc_max_message_size);
+
+    let router = Server::builder()
+        .accept_http1(config.grpc_web_enabled)
+        .tcp_nodelay(config.grpc_tcp_nodelay)
+        .http2_keepalive_interval(
+            config
+                .grpc_http2_keepalive_interval
+                .map(Duration::from_secs),
+        )
+        .http2_keepalive_timeout(Some(Duration::from_secs(
+            config.grpc_http2_keepalive_timeout,
+        )))
         .layer(middleware_stack)
-        .add_service(AuthHandlerServer::new(auth_handler))
+        .add_service(auth_server)
+        .add_service(health_server)
         .add_service(reflection_service)
-        .add_service(reflection_alpha)
-        .serve(addr)
-        .await?;
+        .add_service(reflection_alpha);
+
+    let grpc_server: Pin<Box<dyn Future<Output = Result<(), tonic::transport::Error>> + Send>> =
+        match tls {
+            Some(tls) => {
+                workers.push(tokio::spawn(tls.clone().watch(
+                    Duration::from_secs(config.tls_reload_interval),
+                    shutdown.clone(),
+                )));
+                let incoming =
+                    tls_incoming(addr, tls, config.grpc_tcp_nodelay, shutdown.clone()).await?;
+                Box::pin(
+                    router
+                        .serve_with_incoming_shutdown(incoming, shutdown.clone().cancelled_owned()),
+                )
+            }
+            None => Box::pin(router.serve_with_shutdown(addr, shutdown.clone().cancelled_owned())),
+        };
+
+    tokio::select! {
+        result = grpc_server => {
+            // Stop the workers too if the server exits on its own.
+            shutdown.cancel();
+            result?;
+        }
+        _ = shutdown_deadline(shutdown.clone(), shutdown_timeout) => {
+            error!("In-flight requests did not finish within SHUTDOWN_TIMEOUT, dropping them");
+        }
+    }
+
+    health_probes.abort();
+    if tokio::time::timeout(shutdown_timeout, futures::future::join_all(workers))
+        .await
+        .is_err()
+    {
+        error!("Background workers did not stop within SHUTDOWN_TIMEOUT");
+    }
 
+    info!("Server stopped");
     Ok(())
 }
@@ -1,9 +1,25 @@
+use crate::domain::entity::membership::MembershipRole;
+use crate::domain::entity::outbox_message::OutboxStatus;
+use crate::domain::entity::webhook::WebhookEvent;
 use crate::domain::validator::ValidateFromRequest;
 use crate::impl_from_request;
 use crate::pb::auth::{
-    ForgotPasswordRequest, LoginRequest, LogoutRequest, RegisterRequest, SendOtpRequest,
-    VerifyEmailRequest,
+    AcceptInvitationRequest, CreateOrganizationRequest, CreateWebhookSubscriptionRequest,
+    DeleteWebhookSubscriptionRequest, InviteMemberRequest, ListAuditEventsRequest,
+    ListInvitationsRequest, ListOutboxMessagesRequest, ListWebhookDeliveriesRequest,
+    ReplayOutboxMessageRequest, RevokeInvitationRequest, SwitchOrganizationRequest,
+    UpdatePhoneNumberRequest, VerifyPhoneNumberRequest,
 };
+use crate::pb::auth::{
+    BeginExternalLoginRequest, ClientCredentialsTokenRequest, CompleteExternalLoginRequest,
+    CreateApiKeyRequest, ForgotPasswordRequest, LoginRequest, LogoutRequest, RegisterRequest,
+    RevokeApiKeyRequest, SendOtpRequest, VerifyEmailRequest,
+};
+use crate::pb::auth::{
+    BeginPasskeyLoginRequest, FinishPasskeyLoginRequest, FinishPasskeyRegistrationRequest,
+};
+use crate::util::util::primary_language_tag;
+use uuid::Uuid;
 use validator::{Validate, ValidationError};
 
 fn validate_password(pw: &str) -> bool {
@@ -25,6 +41,92 @@ fn password_validator(password: &str) -> Result<(), ValidationError> {
     Ok(())
 }
 
+/// Organization ids are optional on most requests; an empty string means the default tenant.
+fn org_id_validator(org_id: &str) -> Result<(), ValidationError> {
+    if !org_id.is_empty() && Uuid::parse_str(org_id).is_err() {
+        return Err(ValidationError::new("org_id"));
+    }
+    Ok(())
+}
+
+/// Existing users accept invitations without a password; new users must choose a strong one.
+fn optional_password_validator(password: &str) -> Result<(), ValidationError> {
+    if password.is_empty() {
+        return Ok(());
+    }
+    password_validator(password)
+}
+
+fn role_validator(role: &str) -> Result<(), ValidationError> {
+    if MembershipRole::from_str(role).is_none() {
+        return Err(ValidationError::new("role"));
+    }
+    Ok(())
+}
+
+fn optional_locale_validator(locale: &str) -> Result<(), ValidationError> {
+    if !locale.is_empty() && primary_language_tag(locale).is_none() {
+        return Err(ValidationError::new("locale"));
+    }
+    Ok(())
+}
+
+fn optional_otp_channel_validator(channel: &str) -> Result<(), ValidationError> {
+    if !matches!(channel, "" | "email" | "sms") {
+        return Err(ValidationError::new("channel"));
+    }
+    Ok(())
+}
+
+/// E.164: a leading `+`, then 8 to 15 digits without a leading zero.
+fn phone_number_validator(phone_number: &str) -> Result<(), ValidationError> {
+    let digits = phone_number
+        .strip_prefix('+')
+        .ok_or_else(|| ValidationError::new("phone_number"))?;
+    let valid = (8..=15).contains(&digits.len())
+        && digits.chars().all(|c| c.is_ascii_digit())
+        && !digits.starts_with('0');
+    if !valid {
+        return Err(ValidationError::new("phone_number"));
+    }
+    Ok(())
+}
+
+fn optional_outbox_status_validator(status: &str) -> Result<(), ValidationError> {
+    if !status.is_empty() && OutboxStatus::from_str(status).is_none() {
+        return Err(ValidationError::new("status"));
+    }
+    Ok(())
+}
+
+fn webhook_events_validator(events: &[String]) -> Result<(), ValidationError> {
+    if events.is_empty()
+        || events
+            .iter()
+            .any(|event| WebhookEvent::from_str(event).is_none())
+    {
+        return Err(ValidationError::new("events"));
+    }
+    Ok(())
+}
+
+fn optional_timestamp_validator(timestamp: &str) -> Result<(), ValidationError> {
+    if !timestamp.is_empty() && chrono::DateTime::parse_from_rfc3339(timestamp).is_err() {
+        return Err(ValidationError::new("timestamp"));
+    }
+    Ok(())
+}
+
+fn slug_validator(slug: &str) -> Result<(), ValidationError> {
+    let valid = slug
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if !valid || slug.starts_with('-') || slug.ends_with('-') {
+        return Err(ValidationError::new("slug"));
+    }
+    Ok(())
+}
+
 #[derive(Debug, Validate)]
 pub struct RegisterDto {
     #[validate(length(min = 1, message = "Name cannot be empty"))]
@@ -41,6 +143,12 @@ pub struct RegisterDto {
         )
     )]
     pub password: String,
+
+    #[validate(custom(function = "org_id_validator", message = "Invalid organization id"))]
+    pub org_id: String,
+
+    #[validate(custom(function = "optional_locale_validator", message = "Invalid locale"))]
+    pub locale: String,
 }
 
 #[derive(Debug, Validate)]
@@ -56,6 +164,9 @@ pub struct LoginDto {
         )
     )]
     pub password: String,
+
+    #[validate(custom(function = "org_id_validator", message = "Invalid organization id"))]
+    pub org_id: String,
 }
 
 #[derive(Debug, Validate)]
@@ -68,6 +179,15 @@ pub struct LogoutDto {
 pub struct SendOtpDto {
     #[validate(email(message = "Invalid email format"))]
     pub email: String,
+
+    #[validate(custom(function = "org_id_validator", message = "Invalid organization id"))]
+    pub org_id: String,
+
+    #[validate(custom(
+        function = "optional_otp_channel_validator",
+        message = "Channel must be email or sms"
+    ))]
+    pub channel: String,
 }
 
 #[derive(Debug, Validate)]
@@ -77,6 +197,9 @@ pub struct VerifyEmailDto {
 
     #[validate(length(min = 6, max = 6, message = "OTP must be exactly 6 digits"))]
     pub otp: String,
+
+    #[validate(custom(function = "org_id_validator", message = "Invalid organization id"))]
+    pub org_id: String,
 }
 
 #[derive(Debug, Validate)]
@@ -95,11 +218,322 @@ pub struct ForgotPasswordDto {
 
     #[validate(length(min = 6, max = 6, message = "OTP must be exactly 6 digits"))]
     pub otp: String,
+
+    #[validate(custom(function = "org_id_validator", message = "Invalid organization id"))]
+    pub org_id: String,
+}
+
+#[derive(Debug, Validate)]
+pub struct BeginExternalLoginDto {
+    #[validate(length(min = 1, message = "Provider cannot be empty"))]
+    pub provider: String,
+}
+
+#[derive(Debug, Validate)]
+pub struct CompleteExternalLoginDto {
+    #[validate(length(min = 1, message = "State cannot be empty"))]
+    pub state: String,
+
+    #[validate(length(min = 1, message = "Code cannot be empty"))]
+    pub code: String,
+}
+
+#[derive(Debug, Validate)]
+pub struct ClientCredentialsTokenDto {
+    #[validate(length(min = 1, message = "Client id cannot be empty"))]
+    pub client_id: String,
+
+    #[validate(length(min = 1, message = "Client secret cannot be empty"))]
+    pub client_secret: String,
+
+    pub scope: String,
+}
+
+#[derive(Debug, Validate)]
+pub struct CreateApiKeyDto {
+    #[validate(length(min = 1, max = 100, message = "Name must be 1 to 100 characters"))]
+    pub name: String,
+
+    pub scopes: Vec<String>,
+
+    #[validate(range(
+        min = 0,
+        max = 3650,
+        message = "Expiry must be between 0 and 3650 days"
+    ))]
+    pub expires_in_days: i64,
+}
+
+#[derive(Debug, Validate)]
+pub struct RevokeApiKeyDto {
+    #[validate(length(min = 1, message = "Id cannot be empty"))]
+    pub id: String,
+}
+
+#[derive(Debug, Validate)]
+pub struct FinishPasskeyRegistrationDto {
+    #[validate(length(min = 1, max = 100, message = "Name must be 1 to 100 characters"))]
+    pub name: String,
+
+    #[validate(length(min = 1, message = "Credential cannot be empty"))]
+    pub credential_json: String,
+}
+
+#[derive(Debug, Validate)]
+pub struct BeginPasskeyLoginDto {
+    #[validate(email(message = "Invalid email format"))]
+    pub email: String,
+
+    #[validate(custom(function = "org_id_validator", message = "Invalid organization id"))]
+    pub org_id: String,
+}
+
+#[derive(Debug, Validate)]
+pub struct FinishPasskeyLoginDto {
+    #[validate(length(min = 1, message = "Session id cannot be empty"))]
+    pub session_id: String,
+
+    #[validate(length(min = 1, message = "Credential cannot be empty"))]
+    pub credential_json: String,
+}
+
+#[derive(Debug, Validate)]
+pub struct CreateOrganizationDto {
+    #[validate(length(min = 1, max = 100, message = "Name must be 1 to 100 characters"))]
+    pub name: String,
+
+    #[validate(
+        length(min = 3, max = 100, message = "Slug must be 3 to 100 characters"),
+        custom(
+            function = "slug_validator",
+            message = "Slug may only contain lowercase letters, digits and inner hyphens"
+        )
+    )]
+    pub slug: String,
+}
+
+#[derive(Debug, Validate)]
+pub struct SwitchOrganizationDto {
+    #[validate(custom(function = "org_id_validator", message = "Invalid organization id"))]
+    pub org_id: String,
+}
+
+#[derive(Debug, Validate)]
+pub struct InviteMemberDto {
+    #[validate(
+        length(min = 1, message = "Organization id cannot be empty"),
+        custom(function = "org_id_validator", message = "Invalid organization id")
+    )]
+    pub org_id: String,
+
+    #[validate(email(message = "Invalid email format"))]
+    pub email: String,
+
+    #[validate(custom(
+        function = "role_validator",
+        message = "Role must be one of owner, admin or member"
+    ))]
+    pub role: String,
+}
+
+#[derive(Debug, Validate)]
+pub struct ListInvitationsDto {
+    #[validate(
+        length(min = 1, message = "Organization id cannot be empty"),
+        custom(function = "org_id_validator", message = "Invalid organization id")
+    )]
+    pub org_id: String,
+}
+
+#[derive(Debug, Validate)]
+pub struct RevokeInvitationDto {
+    #[validate(length(min = 1, message = "Id cannot be empty"))]
+    pub id: String,
+}
+
+#[derive(Debug, Validate)]
+pub struct AcceptInvitationDto {
+    #[validate(length(min = 1, message = "Token cannot be empty"))]
+    pub token: String,
+
+    #[validate(length(max = 100, message = "Name must be at most 100 characters"))]
+    pub name: String,
+
+    #[validate(custom(
+        function = "optional_password_validator",
+        message = "Password must have at least 8 characters, uppercase, lowercase, number, and special character"
+    ))]
+    pub password: String,
+}
+
+#[derive(Debug, Validate)]
+pub struct ListAuditEventsDto {
+    #[validate(custom(function = "org_id_validator", message = "Invalid organization id"))]
+    pub org_id: String,
+
+    pub action: String,
+
+    pub outcome: String,
+
+    #[validate(custom(
+        function = "optional_timestamp_validator",
+        message = "Since must be an RFC 3339 timestamp"
+    ))]
+    pub since: String,
+
+    #[validate(custom(
+        function = "optional_timestamp_validator",
+        message = "Until must be an RFC 3339 timestamp"
+    ))]
+    pub until: String,
+
+    #[validate(range(min = 0, max = 200, message = "Page size must be between 0 and 200"))]
+    pub page_size: i32,
+
+    pub cursor: String,
+}
+
+#[derive(Debug, Validate)]
+pub struct UpdatePhoneNumberDto {
+    #[validate(custom(
+        function = "phone_number_validator",
+        message = "Phone number must be in E.164 format, e.g. +6281234567890"
+    ))]
+    pub phone_number: String,
+}
+
+#[derive(Debug, Validate)]
+pub struct VerifyPhoneNumberDto {
+    #[validate(length(min = 6, max = 6, message = "OTP must be exactly 6 digits"))]
+    pub otp: String,
+}
+
+#[derive(Debug, Validate)]
+pub struct ListOutboxMessagesDto {
+    #[validate(custom(
+        function = "optional_outbox_status_validator",
+        message = "Status must be pending, delivered or dead"
+    ))]
+    pub status: String,
+
+    #[validate(range(min = 0, max = 200, message = "Page size must be between 0 and 200"))]
+    pub page_size: i32,
+
+    pub cursor: String,
+}
+
+#[derive(Debug, Validate)]
+pub struct ReplayOutboxMessageDto {
+    #[validate(length(min = 1, message = "Id cannot be empty"))]
+    pub id: String,
+}
+
+#[derive(Debug, Validate)]
+pub struct CreateWebhookSubscriptionDto {
+    #[validate(url(message = "Url must be a valid URL"))]
+    pub url: String,
+
+    #[validate(custom(
+        function = "webhook_events_validator",
+        message = "Events must be one or more of user.registered, user.email_verified"
+    ))]
+    pub events: Vec<String>,
+}
+
+#[derive(Debug, Validate)]
+pub struct DeleteWebhookSubscriptionDto {
+    #[validate(length(min = 1, message = "Id cannot be empty"))]
+    pub id: String,
+}
+
+#[derive(Debug, Validate)]
+pub struct ListWebhookDeliveriesDto {
+    #[validate(length(min = 1, message = "Subscription id cannot be empty"))]
+    pub subscription_id: String,
+
+    #[validate(range(min = 0, max = 200, message = "Page size must be between 0 and 200"))]
+    pub page_size: i32,
+
+    pub cursor: String,
 }
 
-impl_from_request!(RegisterDto, RegisterRequest, { name, email, password });
-impl_from_request!(LoginDto, LoginRequest, { email, password });
+impl_from_request!(RegisterDto, RegisterRequest, {
+    name,
+    email,
+    password,
+    org_id,
+    locale
+});
+impl_from_request!(LoginDto, LoginRequest, { email, password, org_id });
 impl_from_request!(LogoutDto, LogoutRequest, { refresh_token });
-impl_from_request!(SendOtpDto, SendOtpRequest, { email });
-impl_from_request!(VerifyEmailDto, VerifyEmailRequest, { email, otp });
-impl_from_request!(ForgotPasswordDto, ForgotPasswordRequest, { email, password, otp });
+impl_from_request!(SendOtpDto, SendOtpRequest, { email, org_id, channel });
+impl_from_request!(VerifyEmailDto, VerifyEmailRequest, { email, otp, org_id });
+impl_from_request!(ForgotPasswordDto, ForgotPasswordRequest, {
+    email,
+    password,
+    otp,
+    org_id
+});
+impl_from_request!(BeginExternalLoginDto, BeginExternalLoginRequest, {
+    provider
+});
+impl_from_request!(CompleteExternalLoginDto, CompleteExternalLoginRequest, { state, code });
+impl_from_request!(ClientCredentialsTokenDto, ClientCredentialsTokenRequest, {
+    client_id,
+    client_secret,
+    scope
+});
+impl_from_request!(CreateApiKeyDto, CreateApiKeyRequest, { name, scopes, expires_in_days });
+impl_from_request!(RevokeApiKeyDto, RevokeApiKeyRequest, { id });
+impl_from_request!(FinishPasskeyRegistrationDto, FinishPasskeyRegistrationRequest, {
+    name,
+    credential_json
+});
+impl_from_request!(BeginPasskeyLoginDto, BeginPasskeyLoginRequest, { email, org_id });
+impl_from_request!(FinishPasskeyLoginDto, FinishPasskeyLoginRequest, {
+    session_id,
+    credential_json
+});
+impl_from_request!(CreateOrganizationDto, CreateOrganizationRequest, { name, slug });
+impl_from_request!(SwitchOrganizationDto, SwitchOrganizationRequest, { org_id });
+impl_from_request!(InviteMemberDto, InviteMemberRequest, { org_id, email, role });
+impl_from_request!(ListInvitationsDto, ListInvitationsRequest, { org_id });
+impl_from_request!(RevokeInvitationDto, RevokeInvitationRequest, { id });
+impl_from_request!(AcceptInvitationDto, AcceptInvitationRequest, {
+    token,
+    name,
+    password
+});
+impl_from_request!(ListAuditEventsDto, ListAuditEventsRequest, {
+    org_id,
+    action,
+    outcome,
+    since,
+    until,
+    page_size,
+    cursor
+});
+impl_from_request!(ListOutboxMessagesDto, ListOutboxMessagesRequest, {
+    status,
+    page_size,
+    cursor
+});
+impl_from_request!(ReplayOutboxMessageDto, ReplayOutboxMessageRequest, { id });
+impl_from_request!(UpdatePhoneNumberDto, UpdatePhoneNumberRequest, {
+    phone_number
+});
+impl_from_request!(VerifyPhoneNumberDto, VerifyPhoneNumberRequest, { otp });
+impl_from_request!(CreateWebhookSubscriptionDto, CreateWebhookSubscriptionRequest, {
+    url,
+    events
+});
+impl_from_request!(
+    DeleteWebhookSubscriptionDto,
+    DeleteWebhookSubscriptionRequest,
+    { id }
+);
+impl_from_request!(ListWebhookDeliveriesDto, ListWebhookDeliveriesRequest, {
+    subscription_id,
+    page_size,
+    cursor
+});
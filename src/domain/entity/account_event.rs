@@ -0,0 +1,63 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountEventKind {
+    Login,
+    SessionRevoked,
+    PasswordChanged,
+    MfaChanged,
+}
+
+impl AccountEventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccountEventKind::Login => "login",
+            AccountEventKind::SessionRevoked => "session_revoked",
+            AccountEventKind::PasswordChanged => "password_changed",
+            AccountEventKind::MfaChanged => "mfa_changed",
+        }
+    }
+}
+
+/// Security-relevant activity shown to the account owner as it happens.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountEvent {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub kind: String,
+    /// Login method for logins, the changed factor for MFA changes.
+    #[serde(default)]
+    pub detail: String,
+    #[serde(default)]
+    pub ip: String,
+    #[serde(default)]
+    pub device: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl AccountEvent {
+    pub fn new(user_id: Uuid, kind: AccountEventKind) -> Self {
+        AccountEvent {
+            id: Uuid::new_v4(),
+            user_id,
+            kind: kind.as_str().to_string(),
+            detail: String::new(),
+            ip: String::new(),
+            device: String::new(),
+            occurred_at: Utc::now(),
+        }
+    }
+
+    pub fn with_detail(mut self, detail: &str) -> Self {
+        self.detail = detail.to_string();
+        self
+    }
+
+    pub fn from_client(mut self, ip: &str, device: &str) -> Self {
+        self.ip = ip.to_string();
+        self.device = device.to_string();
+        self
+    }
+}
@@ -0,0 +1,68 @@
+use crate::util::util::{generate_random_string, sha256_hex};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+pub const API_KEY_PREFIX: &str = "rsh";
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ApiKey {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub prefix: String,
+    pub key_hash: String,
+    pub scopes: Vec<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl ApiKey {
+    pub fn new(
+        user_id: Uuid,
+        name: String,
+        prefix: String,
+        key_hash: String,
+        scopes: Vec<String>,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            name,
+            prefix,
+            key_hash,
+            scopes,
+            expires_at,
+            last_used_at: None,
+            revoked_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+        }
+    }
+
+    /// Generates a new `rsh_<id>_<secret>` key, returning the plaintext key (shown once), its
+    /// lookup prefix and the hash that is stored in place of the key.
+    pub fn generate_key() -> (String, String, String) {
+        let prefix = format!("{}_{}", API_KEY_PREFIX, generate_random_string(8));
+        let key = format!("{}_{}", prefix, generate_random_string(40));
+        let key_hash = sha256_hex(&key);
+        (key, prefix, key_hash)
+    }
+
+    pub fn prefix_of(key: &str) -> Option<&str> {
+        key.rsplit_once('_')
+            .map(|(prefix, _)| prefix)
+            .filter(|prefix| prefix.starts_with(API_KEY_PREFIX))
+    }
+
+    pub fn is_usable(&self) -> bool {
+        self.revoked_at.is_none() && self.expires_at.is_none_or(|exp| exp > Utc::now())
+    }
+}
@@ -0,0 +1,113 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditAction {
+    Register,
+    Login,
+    Logout,
+    OtpSend,
+    OtpVerify,
+    PasswordReset,
+    PhoneUpdate,
+    PhoneVerify,
+    ExternalLogin,
+    PasskeyRegister,
+    PasskeyLogin,
+    ClientToken,
+    ApiKeyCreate,
+    ApiKeyRevoke,
+    OrganizationCreate,
+    OrganizationSwitch,
+    InvitationCreate,
+    InvitationRevoke,
+    InvitationAccept,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::Register => "register",
+            AuditAction::Login => "login",
+            AuditAction::Logout => "logout",
+            AuditAction::OtpSend => "otp_send",
+            AuditAction::OtpVerify => "otp_verify",
+            AuditAction::PasswordReset => "password_reset",
+            AuditAction::PhoneUpdate => "phone_update",
+            AuditAction::PhoneVerify => "phone_verify",
+            AuditAction::ExternalLogin => "external_login",
+            AuditAction::PasskeyRegister => "passkey_register",
+            AuditAction::PasskeyLogin => "passkey_login",
+            AuditAction::ClientToken => "client_token",
+            AuditAction::ApiKeyCreate => "api_key_create",
+            AuditAction::ApiKeyRevoke => "api_key_revoke",
+            AuditAction::OrganizationCreate => "organization_create",
+            AuditAction::OrganizationSwitch => "organization_switch",
+            AuditAction::InvitationCreate => "invitation_create",
+            AuditAction::InvitationRevoke => "invitation_revoke",
+            AuditAction::InvitationAccept => "invitation_accept",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditOutcome {
+    Success,
+    Failure,
+}
+
+impl AuditOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditOutcome::Success => "success",
+            AuditOutcome::Failure => "failure",
+        }
+    }
+}
+
+/// Who did what to whom, from where, and whether it worked.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct AuditEvent {
+    pub id: Uuid,
+    pub action: String,
+    pub outcome: String,
+    pub actor_id: Option<Uuid>,
+    pub target: Option<String>,
+    pub org_id: Option<Uuid>,
+    pub ip: Option<String>,
+    pub device: Option<String>,
+    pub detail: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AuditEvent {
+    pub fn new(action: AuditAction, outcome: AuditOutcome) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            action: action.as_str().to_string(),
+            outcome: outcome.as_str().to_string(),
+            actor_id: None,
+            target: None,
+            org_id: None,
+            ip: None,
+            device: None,
+            detail: None,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// Filters for listing audit events. Results are ordered newest first and `before` is the
+/// keyset cursor of the last event on the previous page.
+#[derive(Debug, Clone, Default)]
+pub struct AuditFilter {
+    pub actor_id: Option<Uuid>,
+    pub org_id: Option<Uuid>,
+    pub action: Option<String>,
+    pub outcome: Option<String>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub before: Option<(DateTime<Utc>, Uuid)>,
+}
@@ -0,0 +1,137 @@
+use uuid::Uuid;
+
+/// How a session was started.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoginMethod {
+    Password,
+    Passkey,
+    External(String),
+}
+
+impl LoginMethod {
+    pub fn as_str(&self) -> &str {
+        match self {
+            LoginMethod::Password => "password",
+            LoginMethod::Passkey => "passkey",
+            LoginMethod::External(provider) => provider,
+        }
+    }
+}
+
+/// Why a sign-in was refused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoginFailure {
+    InvalidCredentials,
+    /// The email address was never verified.
+    Inactive,
+    /// The account is suspended or banned.
+    Locked,
+}
+
+impl LoginFailure {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LoginFailure::InvalidCredentials => "invalid_credentials",
+            LoginFailure::Inactive => "inactive",
+            LoginFailure::Locked => "locked",
+        }
+    }
+}
+
+/// Where a one-time code was sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OtpChannel {
+    Email,
+    Sms,
+}
+
+impl OtpChannel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OtpChannel::Email => "email",
+            OtpChannel::Sms => "sms",
+        }
+    }
+}
+
+/// Something that happened to an account. Published once the change has been persisted.
+#[derive(Debug, Clone)]
+pub enum DomainEvent {
+    UserRegistered {
+        user_id: Uuid,
+        email: String,
+        name: String,
+        org_id: Option<Uuid>,
+    },
+    EmailVerified {
+        user_id: Uuid,
+        email: String,
+        name: String,
+        org_id: Option<Uuid>,
+    },
+    LoggedIn {
+        user_id: Uuid,
+        org_id: Option<Uuid>,
+        method: LoginMethod,
+        ip: String,
+        device: String,
+    },
+    /// A refused sign-in. `user_id` is `None` when no account has the email.
+    LoginFailed {
+        user_id: Option<Uuid>,
+        reason: LoginFailure,
+    },
+    LoggedOut {
+        user_id: Uuid,
+    },
+    OtpSent {
+        user_id: Uuid,
+        channel: OtpChannel,
+    },
+    PasswordReset {
+        user_id: Uuid,
+    },
+    PhoneNumberVerified {
+        user_id: Uuid,
+    },
+    PasskeyRegistered {
+        user_id: Uuid,
+        passkey_id: Uuid,
+    },
+    OrganizationSwitched {
+        user_id: Uuid,
+        org_id: Option<Uuid>,
+    },
+}
+
+impl DomainEvent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DomainEvent::UserRegistered { .. } => "user_registered",
+            DomainEvent::EmailVerified { .. } => "email_verified",
+            DomainEvent::LoggedIn { .. } => "logged_in",
+            DomainEvent::LoginFailed { .. } => "login_failed",
+            DomainEvent::LoggedOut { .. } => "logged_out",
+            DomainEvent::OtpSent { .. } => "otp_sent",
+            DomainEvent::PasswordReset { .. } => "password_reset",
+            DomainEvent::PhoneNumberVerified { .. } => "phone_number_verified",
+            DomainEvent::PasskeyRegistered { .. } => "passkey_registered",
+            DomainEvent::OrganizationSwitched { .. } => "organization_switched",
+        }
+    }
+
+    pub fn user_id(&self) -> Option<Uuid> {
+        match self {
+            DomainEvent::LoginFailed { user_id, .. } => *user_id,
+            DomainEvent::UserRegistered { user_id, .. }
+            | DomainEvent::EmailVerified { user_id, .. }
+            | DomainEvent::LoggedIn { user_id, .. }
+            | DomainEvent::LoggedOut { user_id }
+            | DomainEvent::OtpSent { user_id, .. }
+            | DomainEvent::PasswordReset { user_id }
+            | DomainEvent::PhoneNumberVerified { user_id }
+            | DomainEvent::PasskeyRegistered { user_id, .. }
+            | DomainEvent::OrganizationSwitched { user_id, .. } => Some(*user_id),
+        }
+    }
+}
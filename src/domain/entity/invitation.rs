@@ -0,0 +1,60 @@
+use crate::domain::entity::membership::MembershipRole;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct Invitation {
+    pub id: Uuid,
+    pub org_id: Uuid,
+    pub email: String,
+    pub role: MembershipRole,
+    pub invited_by: Uuid,
+    pub expires_at: DateTime<Utc>,
+    pub accepted_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl Invitation {
+    pub fn new(
+        org_id: Uuid,
+        email: String,
+        role: MembershipRole,
+        invited_by: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            org_id,
+            email,
+            role,
+            invited_by,
+            expires_at,
+            accepted_at: None,
+            revoked_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+        }
+    }
+
+    pub fn status(&self) -> &'static str {
+        if self.accepted_at.is_some() {
+            "accepted"
+        } else if self.revoked_at.is_some() {
+            "revoked"
+        } else if self.expires_at <= Utc::now() {
+            "expired"
+        } else {
+            "pending"
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.status() == "pending"
+    }
+}
@@ -0,0 +1,61 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, sqlx::Type, PartialEq, Eq)]
+#[sqlx(type_name = "membership_role", rename_all = "snake_case")]
+pub enum MembershipRole {
+    Owner,
+    Admin,
+    Member,
+}
+
+impl MembershipRole {
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "owner" => Some(MembershipRole::Owner),
+            "admin" => Some(MembershipRole::Admin),
+            "member" => Some(MembershipRole::Member),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MembershipRole::Owner => "owner",
+            MembershipRole::Admin => "admin",
+            MembershipRole::Member => "member",
+        }
+    }
+
+    pub fn can_manage_members(&self) -> bool {
+        matches!(self, MembershipRole::Owner | MembershipRole::Admin)
+    }
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct Membership {
+    pub id: Uuid,
+    pub org_id: Uuid,
+    pub user_id: Uuid,
+    pub role: MembershipRole,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl Membership {
+    pub fn new(org_id: Uuid, user_id: Uuid, role: MembershipRole) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            org_id,
+            user_id,
+            role,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+        }
+    }
+}
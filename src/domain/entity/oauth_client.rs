@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct OAuthClient {
+    pub id: Uuid,
+    pub client_id: String,
+    pub client_secret_hash: Option<String>,
+    pub name: String,
+    pub redirect_uris: Vec<String>,
+    pub allowed_scopes: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl OAuthClient {
+    pub fn new(
+        client_id: String,
+        client_secret_hash: Option<String>,
+        name: String,
+        redirect_uris: Vec<String>,
+        allowed_scopes: Vec<String>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            client_id,
+            client_secret_hash,
+            name,
+            redirect_uris,
+            allowed_scopes,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+        }
+    }
+
+    /// Public clients (SPAs, native apps) have no secret and must use PKCE.
+    pub fn is_public(&self) -> bool {
+        self.client_secret_hash.is_none()
+    }
+}
@@ -0,0 +1,38 @@
+use crate::domain::entity::membership::MembershipRole;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct Organization {
+    pub id: Uuid,
+    pub name: String,
+    pub slug: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl Organization {
+    pub fn new(name: String, slug: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            slug,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+        }
+    }
+}
+
+/// An organization as seen by one of its members.
+#[derive(Debug, Clone, FromRow)]
+pub struct UserOrganization {
+    pub id: Uuid,
+    pub name: String,
+    pub slug: String,
+    pub role: MembershipRole,
+    pub joined_at: DateTime<Utc>,
+}
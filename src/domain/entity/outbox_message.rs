@@ -0,0 +1,107 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutboxStatus {
+    Pending,
+    Delivered,
+    Dead,
+}
+
+impl OutboxStatus {
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "pending" => Some(OutboxStatus::Pending),
+            "delivered" => Some(OutboxStatus::Delivered),
+            "dead" => Some(OutboxStatus::Dead),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutboxStatus::Pending => "pending",
+            OutboxStatus::Delivered => "delivered",
+            OutboxStatus::Dead => "dead",
+        }
+    }
+}
+
+/// What the dispatcher has to deliver. The variant name is stored as the message kind.
+/// One-time codes stay in Redis under `otp_key` and are read at send time, so the outbox never
+/// stores them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum OutboxPayload {
+    OtpEmail {
+        recipient: String,
+        otp_key: String,
+        #[serde(default)]
+        locale: Option<String>,
+    },
+    OtpSms {
+        phone_number: String,
+        otp_key: String,
+    },
+    InvitationEmail {
+        recipient: String,
+        organization: String,
+        inviter: String,
+        invitation_link: String,
+        #[serde(default)]
+        locale: Option<String>,
+    },
+    Webhook {
+        subscription_id: Uuid,
+        event_id: Uuid,
+        event: String,
+        body: String,
+    },
+}
+
+impl OutboxPayload {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            OutboxPayload::OtpEmail { .. } => "otp_email",
+            OutboxPayload::OtpSms { .. } => "otp_sms",
+            OutboxPayload::InvitationEmail { .. } => "invitation_email",
+            OutboxPayload::Webhook { .. } => "webhook",
+        }
+    }
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct OutboxMessage {
+    pub id: Uuid,
+    pub kind: String,
+    pub payload: Json<OutboxPayload>,
+    pub status: String,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub next_attempt_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl OutboxMessage {
+    pub fn new(payload: OutboxPayload) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            kind: payload.kind().to_string(),
+            payload: Json(payload),
+            status: OutboxStatus::Pending.as_str().to_string(),
+            attempts: 0,
+            last_error: None,
+            next_attempt_at: now,
+            delivered_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A registered WebAuthn credential. `credential` holds the serialized passkey, including its
+/// COSE public key; `sign_count` mirrors the authenticator counter for clone detection.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct PasskeyCredential {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub credential_id: String,
+    pub credential: String,
+    pub sign_count: i64,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl PasskeyCredential {
+    pub fn new(
+        user_id: Uuid,
+        name: String,
+        credential_id: String,
+        credential: String,
+        sign_count: i64,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            name,
+            credential_id,
+            credential,
+            sign_count,
+            last_used_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ServiceClient {
+    pub id: Uuid,
+    pub client_id: String,
+    pub client_secret_hash: String,
+    pub name: String,
+    pub allowed_scopes: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl ServiceClient {
+    pub fn new(
+        client_id: String,
+        client_secret_hash: String,
+        name: String,
+        allowed_scopes: Vec<String>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            client_id,
+            client_secret_hash,
+            name,
+            allowed_scopes,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+        }
+    }
+}
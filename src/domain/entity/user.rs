@@ -13,6 +13,7 @@ pub enum UserStatus {
 }
 
 impl UserStatus {
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
             "active" => Some(UserStatus::Active),
@@ -39,6 +40,16 @@ pub struct User {
     pub email: String,
     pub password: String,
     pub status: UserStatus,
+    /// The tenant this account belongs to; `None` is the default tenant.
+    #[serde(default)]
+    pub org_id: Option<Uuid>,
+    #[serde(default)]
+    pub phone_number: Option<String>,
+    #[serde(default)]
+    pub phone_verified_at: Option<DateTime<Utc>>,
+    /// Preferred email language as a primary language tag, e.g. `en`.
+    #[serde(default)]
+    pub locale: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub deleted_at: Option<DateTime<Utc>>,
@@ -52,9 +63,28 @@ impl User {
             email,
             password,
             status,
+            org_id: None,
+            phone_number: None,
+            phone_verified_at: None,
+            locale: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
             deleted_at: None,
         }
     }
+
+    /// The phone number OTPs may be sent to, once the user has verified it.
+    pub fn verified_phone_number(&self) -> Option<&str> {
+        self.phone_verified_at.and(self.phone_number.as_deref())
+    }
+
+    pub fn in_tenant(mut self, org_id: Option<Uuid>) -> Self {
+        self.org_id = org_id;
+        self
+    }
+
+    pub fn with_locale(mut self, locale: Option<String>) -> Self {
+        self.locale = locale;
+        self
+    }
 }
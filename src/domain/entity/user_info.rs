@@ -12,6 +12,7 @@ pub enum UserGender {
 }
 
 impl UserGender {
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
             "male" => Some(Self::Male),
@@ -0,0 +1,19 @@
+use crate::domain::entity::account_event::AccountEvent;
+use async_trait::async_trait;
+use futures::Stream;
+use std::error::Error;
+use std::pin::Pin;
+use uuid::Uuid;
+
+pub type AccountEventStream = Pin<Box<dyn Stream<Item = AccountEvent> + Send>>;
+
+/// Fans account events out to every replica holding a watcher for the user.
+#[async_trait]
+pub trait AccountEventPort: Send + Sync {
+    async fn publish(&self, event: &AccountEvent) -> Result<(), Box<dyn Error + Send + Sync>>;
+
+    async fn subscribe(
+        &self,
+        user_id: Uuid,
+    ) -> Result<AccountEventStream, Box<dyn Error + Send + Sync>>;
+}
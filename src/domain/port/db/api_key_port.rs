@@ -0,0 +1,12 @@
+use crate::domain::entity::api_key::ApiKey;
+use crate::domain::port::db_port::DbPort;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ApiKeyPort: DbPort<ApiKey> {
+    async fn find_by_prefix(&self, prefix: &str) -> Result<Option<ApiKey>, sqlx::Error>;
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<ApiKey>, sqlx::Error>;
+    async fn revoke(&self, id: Uuid, user_id: Uuid) -> Result<bool, sqlx::Error>;
+    async fn touch_last_used(&self, id: Uuid) -> Result<(), sqlx::Error>;
+}
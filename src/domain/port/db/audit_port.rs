@@ -0,0 +1,8 @@
+use crate::domain::entity::audit_event::{AuditEvent, AuditFilter};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait AuditPort {
+    async fn record(&self, event: &AuditEvent) -> Result<(), sqlx::Error>;
+    async fn list(&self, filter: &AuditFilter, limit: i64) -> Result<Vec<AuditEvent>, sqlx::Error>;
+}
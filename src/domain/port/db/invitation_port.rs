@@ -0,0 +1,23 @@
+use crate::domain::entity::invitation::Invitation;
+use crate::domain::entity::outbox_message::OutboxMessage;
+use crate::domain::port::db_port::DbPort;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait InvitationPort: DbPort<Invitation> {
+    /// Saves the invitation and enqueues its email in one transaction.
+    async fn create_with_message(
+        &self,
+        data: &Invitation,
+        message: &OutboxMessage,
+    ) -> Result<(), sqlx::Error>;
+    async fn list_by_org(&self, org_id: Uuid) -> Result<Vec<Invitation>, sqlx::Error>;
+    async fn find_pending(
+        &self,
+        org_id: Uuid,
+        email: &str,
+    ) -> Result<Option<Invitation>, sqlx::Error>;
+    async fn mark_accepted(&self, id: Uuid) -> Result<(), sqlx::Error>;
+    async fn revoke(&self, id: Uuid) -> Result<bool, sqlx::Error>;
+}
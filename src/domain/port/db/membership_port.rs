@@ -0,0 +1,13 @@
+use crate::domain::entity::membership::Membership;
+use crate::domain::port::db_port::DbPort;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait MembershipPort: DbPort<Membership> {
+    async fn find_membership(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<Membership>, sqlx::Error>;
+}
@@ -0,0 +1,8 @@
+use crate::domain::entity::oauth_client::OAuthClient;
+use crate::domain::port::db_port::DbPort;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait OAuthClientPort: DbPort<OAuthClient> {
+    async fn find_by_client_id(&self, client_id: &str) -> Result<Option<OAuthClient>, sqlx::Error>;
+}
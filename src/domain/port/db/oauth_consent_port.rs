@@ -0,0 +1,14 @@
+use crate::domain::entity::oauth_consent::OAuthConsent;
+use crate::domain::port::db_port::DbPort;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait OAuthConsentPort: DbPort<OAuthConsent> {
+    async fn find_by_user_client(
+        &self,
+        user_id: Uuid,
+        client_id: &str,
+    ) -> Result<Option<OAuthConsent>, sqlx::Error>;
+    async fn grant(&self, data: &OAuthConsent) -> Result<(), sqlx::Error>;
+}
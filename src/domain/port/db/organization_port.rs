@@ -0,0 +1,14 @@
+use crate::domain::entity::organization::{Organization, UserOrganization};
+use crate::domain::port::db_port::DbPort;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait OrganizationPort: DbPort<Organization> {
+    async fn create_with_owner(
+        &self,
+        data: &Organization,
+        owner_id: Uuid,
+    ) -> Result<(), sqlx::Error>;
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<UserOrganization>, sqlx::Error>;
+}
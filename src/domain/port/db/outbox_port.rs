@@ -0,0 +1,36 @@
+use crate::domain::entity::outbox_message::{OutboxMessage, OutboxStatus};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait OutboxPort {
+    async fn enqueue(&self, message: &OutboxMessage) -> Result<(), sqlx::Error>;
+    /// Locks up to `limit` due messages, counts the attempt and hides them from other
+    /// dispatchers for `lease`, so a crashed delivery is retried once the lease runs out.
+    async fn claim_due(
+        &self,
+        limit: i64,
+        lease: Duration,
+    ) -> Result<Vec<OutboxMessage>, sqlx::Error>;
+    async fn mark_delivered(&self, id: Uuid) -> Result<(), sqlx::Error>;
+    /// Schedules another attempt at `retry_at`, or dead-letters the message when `None`.
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), sqlx::Error>;
+    async fn list(
+        &self,
+        status: Option<OutboxStatus>,
+        before: Option<(DateTime<Utc>, Uuid)>,
+        limit: i64,
+    ) -> Result<Vec<OutboxMessage>, sqlx::Error>;
+    /// Moves a dead-lettered message back to pending. Returns false if it is not dead.
+    async fn replay(&self, id: Uuid) -> Result<bool, sqlx::Error>;
+    /// Deletes delivered and dead-lettered messages last updated before `before`, returning
+    /// how many were removed.
+    async fn purge_finished(&self, before: DateTime<Utc>) -> Result<u64, sqlx::Error>;
+}
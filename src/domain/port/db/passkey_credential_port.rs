@@ -0,0 +1,19 @@
+use crate::domain::entity::passkey_credential::PasskeyCredential;
+use crate::domain::port::db_port::DbPort;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait PasskeyCredentialPort: DbPort<PasskeyCredential> {
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<PasskeyCredential>, sqlx::Error>;
+    async fn find_by_credential_id(
+        &self,
+        credential_id: &str,
+    ) -> Result<Option<PasskeyCredential>, sqlx::Error>;
+    async fn record_use(
+        &self,
+        id: Uuid,
+        credential: &str,
+        sign_count: i64,
+    ) -> Result<(), sqlx::Error>;
+}
@@ -0,0 +1,11 @@
+use crate::domain::entity::service_client::ServiceClient;
+use crate::domain::port::db_port::DbPort;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ServiceClientPort: DbPort<ServiceClient> {
+    async fn find_by_client_id(
+        &self,
+        client_id: &str,
+    ) -> Result<Option<ServiceClient>, sqlx::Error>;
+}
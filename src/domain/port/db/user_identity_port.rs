@@ -0,0 +1,12 @@
+use crate::domain::entity::user_identity::UserIdentity;
+use crate::domain::port::db_port::DbPort;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait UserIdentityPort: DbPort<UserIdentity> {
+    async fn find_by_provider_subject(
+        &self,
+        provider: &str,
+        subject: &str,
+    ) -> Result<Option<UserIdentity>, sqlx::Error>;
+}
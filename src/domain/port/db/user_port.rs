@@ -1,3 +1,5 @@
+use crate::domain::entity::membership::Membership;
+use crate::domain::entity::outbox_message::OutboxMessage;
 use crate::domain::entity::user::User;
 use crate::domain::port::db_port::DbPort;
 use async_trait::async_trait;
@@ -5,6 +7,23 @@ use uuid::Uuid;
 
 #[async_trait]
 pub trait UserPort: DbPort<User> {
-    async fn verify_email(&self, id: Uuid) -> Result<(), sqlx::Error>;
+    /// Inserts a new user with its membership and outbox messages in one transaction.
+    async fn register(
+        &self,
+        data: &User,
+        membership: Option<&Membership>,
+        messages: &[OutboxMessage],
+    ) -> Result<(), sqlx::Error>;
+    /// Looks a user up by email inside a tenant; `None` is the default tenant.
+    async fn find_by_email(
+        &self,
+        email: &str,
+        org_id: Option<Uuid>,
+    ) -> Result<Option<User>, sqlx::Error>;
+    /// Activates the account and inserts its outbox messages in one transaction.
+    async fn verify_email(&self, id: Uuid, messages: &[OutboxMessage]) -> Result<(), sqlx::Error>;
+    /// Stores a new, unverified phone number.
+    async fn set_phone_number(&self, id: Uuid, phone_number: &str) -> Result<(), sqlx::Error>;
+    async fn verify_phone_number(&self, id: Uuid) -> Result<(), sqlx::Error>;
     async fn update_password(&self, id: Uuid, data: &User) -> Result<(), sqlx::Error>;
 }
@@ -0,0 +1,15 @@
+use crate::domain::entity::webhook::WebhookDelivery;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[async_trait]
+pub trait WebhookDeliveryPort {
+    async fn record(&self, delivery: &WebhookDelivery) -> Result<(), sqlx::Error>;
+    async fn list_by_subscription(
+        &self,
+        subscription_id: Uuid,
+        before: Option<(DateTime<Utc>, Uuid)>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, sqlx::Error>;
+}
@@ -0,0 +1,12 @@
+use crate::domain::entity::webhook::WebhookSubscription;
+use crate::domain::port::db_port::DbPort;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait WebhookSubscriptionPort: DbPort<WebhookSubscription> {
+    async fn list_active(&self) -> Result<Vec<WebhookSubscription>, sqlx::Error>;
+    async fn list_for_event(&self, event: &str) -> Result<Vec<WebhookSubscription>, sqlx::Error>;
+    /// Soft-deletes the subscription. Returns false if it does not exist.
+    async fn remove(&self, id: Uuid) -> Result<bool, sqlx::Error>;
+}
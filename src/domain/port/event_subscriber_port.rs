@@ -0,0 +1,16 @@
+use crate::domain::entity::domain_event::DomainEvent;
+use crate::domain::entity::outbox_message::OutboxMessage;
+use async_trait::async_trait;
+
+/// Reacts to domain events. Handlers run inline with the publishing request, so they should
+/// only do quick work (such as enqueueing) and log their own failures.
+#[async_trait]
+pub trait EventSubscriberPort: Send + Sync {
+    /// Messages to deliver for `event`. The publisher writes them to the outbox, in the same
+    /// transaction as the change that raised the event where it can.
+    async fn outbox_messages(&self, _event: &DomainEvent) -> Vec<OutboxMessage> {
+        Vec::new()
+    }
+
+    async fn handle(&self, _event: &DomainEvent) {}
+}
@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use std::error::Error;
+
+#[derive(Debug, Clone)]
+pub struct ExternalIdentity {
+    pub provider: String,
+    pub subject: String,
+    pub email: Option<String>,
+    pub email_verified: bool,
+    pub name: Option<String>,
+}
+
+#[async_trait]
+pub trait ExternalIdentityPort: Send + Sync {
+    fn has_provider(&self, provider: &str) -> bool;
+
+    async fn authorization_url(
+        &self,
+        provider: &str,
+        state: &str,
+        nonce: &str,
+        code_challenge: &str,
+    ) -> Result<String, Box<dyn Error + Send + Sync>>;
+
+    async fn exchange_code(
+        &self,
+        provider: &str,
+        code: &str,
+        code_verifier: &str,
+        nonce: &str,
+    ) -> Result<ExternalIdentity, Box<dyn Error + Send + Sync>>;
+}
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use std::error::Error;
+
+/// A dependency the server cannot work without, probed by the health service.
+#[async_trait]
+pub trait HealthCheckPort: Send + Sync {
+    /// Name the dependency is reported under in `grpc.health.v1.Health`.
+    fn name(&self) -> &'static str;
+
+    async fn check(&self) -> Result<(), Box<dyn Error + Send + Sync>>;
+}
@@ -0,0 +1,16 @@
+/// Business events counted for monitoring. Recording must never fail or block a request.
+pub trait MetricsPort: Send + Sync {
+    fn user_registered(&self);
+
+    /// `method` is `password`, `passkey` or `external`.
+    fn login_succeeded(&self, method: &str);
+
+    /// `reason` is `invalid_credentials`, `inactive` or `locked`.
+    fn login_failed(&self, reason: &str);
+
+    /// `channel` is `email` or `sms`.
+    fn otp_sent(&self, channel: &str);
+
+    /// A sign-in refused because the account is suspended or banned.
+    fn account_locked(&self);
+}
@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use std::error::Error;
+
+/// A message addressed to a user. Transports decide how it is rendered and delivered; `locale`
+/// picks the template language and falls back to the default locale.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Notification {
+    OtpEmail {
+        recipient: String,
+        otp_code: String,
+        locale: Option<String>,
+    },
+    InvitationEmail {
+        recipient: String,
+        organization: String,
+        inviter: String,
+        invitation_link: String,
+        locale: Option<String>,
+    },
+}
+
+impl Notification {
+    pub fn recipient(&self) -> &str {
+        match self {
+            Notification::OtpEmail { recipient, .. } => recipient,
+            Notification::InvitationEmail { recipient, .. } => recipient,
+        }
+    }
+}
+
+#[async_trait]
+pub trait NotificationPort: Send + Sync {
+    async fn send(&self, notification: &Notification) -> Result<(), Box<dyn Error + Send + Sync>>;
+}
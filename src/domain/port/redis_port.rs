@@ -4,6 +4,7 @@ use tonic::Status;
 #[async_trait::async_trait]
 pub trait RedisPort {
     async fn set_value(&self, key: &str, value: &str) -> RedisResult<()>;
+    async fn set_value_with_ttl(&self, key: &str, value: &str, ttl_secs: u64) -> RedisResult<()>;
     async fn get_value(&self, key: &str) -> RedisResult<Option<String>>;
     async fn delete_value(&self, key: &str) -> RedisResult<()>;
     async fn exists(&self, key: &str) -> RedisResult<Option<bool>>;
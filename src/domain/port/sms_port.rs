@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use std::error::Error;
+
+#[async_trait]
+pub trait SmsPort: Send + Sync {
+    async fn send_sms(
+        &self,
+        phone_number: &str,
+        body: &str,
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
+}
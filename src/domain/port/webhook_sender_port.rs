@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use std::error::Error;
+
+#[async_trait]
+pub trait WebhookSenderPort: Send + Sync {
+    /// POSTs a JSON body and returns the response status code.
+    async fn post(
+        &self,
+        url: &str,
+        headers: &[(&str, String)],
+        body: &str,
+    ) -> Result<u16, Box<dyn Error + Send + Sync>>;
+}
@@ -0,0 +1,67 @@
+use crate::domain::entity::domain_event::DomainEvent;
+use crate::domain::entity::outbox_message::OutboxMessage;
+use crate::domain::port::db::outbox_port::OutboxPort;
+use crate::domain::port::event_subscriber_port::EventSubscriberPort;
+use std::sync::Arc;
+use tracing::{debug, error};
+
+/// In-process registry that fans domain events out to every subscriber, in subscription order.
+#[derive(Clone)]
+pub struct EventBus {
+    outbox: Arc<dyn OutboxPort + Send + Sync>,
+    subscribers: Vec<Arc<dyn EventSubscriberPort>>,
+}
+
+impl EventBus {
+    pub fn new(outbox: Arc<dyn OutboxPort + Send + Sync>) -> Self {
+        EventBus {
+            outbox,
+            subscribers: Vec::new(),
+        }
+    }
+
+    pub fn subscribe(mut self, subscriber: Arc<dyn EventSubscriberPort>) -> Self {
+        self.subscribers.push(subscriber);
+        self
+    }
+
+    /// Collects the subscribers' outbox messages for `event`, for the caller to commit with
+    /// its own change before calling `publish_committed`.
+    pub async fn outbox_messages(&self, event: &DomainEvent) -> Vec<OutboxMessage> {
+        let mut messages = Vec::new();
+        for subscriber in &self.subscribers {
+            messages.extend(subscriber.outbox_messages(event).await);
+        }
+        messages
+    }
+
+    /// Enqueues the event's outbox messages on their own, then runs the handlers. Only for
+    /// events that record no change of their own, such as sign-ins; events raised by a change
+    /// commit `outbox_messages` with it and then call `publish_committed`.
+    pub async fn publish(&self, event: DomainEvent) {
+        for message in self.outbox_messages(&event).await {
+            if let Err(e) = self.outbox.enqueue(&message).await {
+                error!(
+                    "Failed to enqueue {} message for {}: {}",
+                    message.kind,
+                    event.name(),
+                    e
+                );
+            }
+        }
+        self.publish_committed(event).await;
+    }
+
+    /// Runs the handlers for an event whose outbox messages are already committed.
+    pub async fn publish_committed(&self, event: DomainEvent) {
+        debug!(
+            "Publishing {} for user {:?} to {} subscribers",
+            event.name(),
+            event.user_id(),
+            self.subscribers.len()
+        );
+        for subscriber in &self.subscribers {
+            subscriber.handle(&event).await;
+        }
+    }
+}
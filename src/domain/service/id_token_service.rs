@@ -0,0 +1,70 @@
+use crate::config::env::EnvConfig;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
+use rsa::RsaPrivateKey;
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::pkcs8::DecodePrivateKey;
+use rsa::traits::PublicKeyParts;
+use serde_json::{Value, json};
+use tonic::Status;
+use tracing::info;
+
+/// Signs OpenID Connect ID tokens with RS256 so relying parties can verify them through JWKS.
+pub struct IdTokenService {
+    key_id: String,
+    encoding_key: EncodingKey,
+    jwk: Value,
+}
+
+/// Reads the RSA private key at `path`, in PKCS#8 or PKCS#1 PEM format.
+pub(crate) fn read_signing_key(path: &str) -> Result<(RsaPrivateKey, EncodingKey), String> {
+    let pem = std::fs::read_to_string(path).map_err(|e| format!("could not be read: {}", e))?;
+
+    let private_key = RsaPrivateKey::from_pkcs8_pem(&pem)
+        .or_else(|_| RsaPrivateKey::from_pkcs1_pem(&pem))
+        .map_err(|_| "is not an RSA private key in PEM format".to_string())?;
+    let encoding_key = EncodingKey::from_rsa_pem(pem.as_bytes())
+        .map_err(|e| format!("could not be loaded: {}", e))?;
+
+    Ok((private_key, encoding_key))
+}
+
+impl IdTokenService {
+    pub fn new(config: &EnvConfig) -> Result<Self, String> {
+        let (private_key, encoding_key) = read_signing_key(&config.oidc_signing_key_path)
+            .map_err(|e| format!("OIDC signing key {}", e))?;
+
+        let jwk = json!({
+            "kty": "RSA",
+            "use": "sig",
+            "alg": "RS256",
+            "kid": config.oidc_signing_key_id,
+            "n": URL_SAFE_NO_PAD.encode(private_key.n().to_bytes_be()),
+            "e": URL_SAFE_NO_PAD.encode(private_key.e().to_bytes_be()),
+        });
+
+        info!(
+            "OIDC signing key loaded with id: {}",
+            config.oidc_signing_key_id
+        );
+
+        Ok(Self {
+            key_id: config.oidc_signing_key_id.clone(),
+            encoding_key,
+            jwk,
+        })
+    }
+
+    pub fn sign(&self, claims: &Value) -> Result<String, Status> {
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = Some(self.key_id.clone());
+
+        encode(&header, claims, &self.encoding_key)
+            .map_err(|_| Status::internal("Failed to sign ID token"))
+    }
+
+    pub fn jwks(&self) -> Value {
+        json!({ "keys": [self.jwk] })
+    }
+}
@@ -1,13 +1,33 @@
-use crate::cfg; // Import cfg from the crate root
+use crate::config::env::EnvConfig;
 use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
 use std::time::{Duration, SystemTime};
 use tokio;
 use tonic::Status;
 
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SubjectType {
+    #[default]
+    User,
+    Client,
+    Invitation,
+    /// A user's access token issued to an OAuth client by the OpenID provider. It is only
+    /// accepted at `/userinfo`, never by the gRPC API.
+    OidcUser,
+}
+
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct Token {
     sub: String,
     exp: i64,
+    #[serde(default)]
+    sub_type: SubjectType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    scope: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    org_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    aud: Option<String>,
 }
 
 impl Token {
@@ -16,7 +36,14 @@ impl Token {
             .duration_since(SystemTime::UNIX_EPOCH)
             .unwrap()
             .as_secs() as i64;
-        Token { sub, exp }
+        Token {
+            sub,
+            exp,
+            sub_type: SubjectType::User,
+            scope: None,
+            org_id: None,
+            aud: None,
+        }
     }
 
     async fn create_token(
@@ -24,7 +51,13 @@ impl Token {
         expiration: SystemTime,
         secret_key: &str,
     ) -> Result<String, Status> {
-        let claims = Token::new(self.sub.clone(), expiration);
+        let claims = Token {
+            sub_type: self.sub_type,
+            scope: self.scope.clone(),
+            org_id: self.org_id.clone(),
+            aud: self.aud.clone(),
+            ..Token::new(self.sub.clone(), expiration)
+        };
         encode(
             &Header::new(Algorithm::HS256),
             &claims,
@@ -33,16 +66,26 @@ impl Token {
         .map_err(|_| Status::internal("Failed to create token"))
     }
 
-    pub async fn create_tokens(user_id: String) -> Result<(String, String), Status> {
-        let config = cfg();
+    /// Issues an access/refresh pair; `org_id` selects the organization the session acts in.
+    pub async fn create_tokens(
+        config: &EnvConfig,
+        user_id: String,
+        org_id: Option<String>,
+    ) -> Result<(String, String), Status> {
         let access_token_duration_secs = config.access_token_duration as u64;
         let refresh_token_duration_secs = config.refresh_token_duration as u64;
 
         let expiration = SystemTime::now() + Duration::new(access_token_duration_secs, 0);
         let expiration_refresh = SystemTime::now() + Duration::new(refresh_token_duration_secs, 0);
 
-        let access_token_claims = Token::new(user_id.clone(), expiration);
-        let refresh_token_claims = Token::new(user_id, expiration_refresh);
+        let access_token_claims = Token {
+            org_id: org_id.clone(),
+            ..Token::new(user_id.clone(), expiration)
+        };
+        let refresh_token_claims = Token {
+            org_id,
+            ..Token::new(user_id, expiration_refresh)
+        };
 
         let (access_token, refresh_token) = tokio::try_join!(
             access_token_claims.create_token(expiration, &config.access_secret),
@@ -52,11 +95,103 @@ impl Token {
         Ok((access_token, refresh_token))
     }
 
+    /// Issues an OpenID provider access token for `client_id`, carrying the granted `scope`.
+    pub async fn create_oidc_access_token(
+        config: &EnvConfig,
+        user_id: String,
+        client_id: String,
+        scope: String,
+    ) -> Result<String, Status> {
+        let expiration = SystemTime::now() + Duration::new(config.access_token_duration as u64, 0);
+
+        let claims = Token {
+            sub_type: SubjectType::OidcUser,
+            scope: Some(scope),
+            aud: Some(client_id),
+            ..Token::new(user_id, expiration)
+        };
+        claims.create_token(expiration, &config.access_secret).await
+    }
+
+    pub async fn create_client_token(
+        config: &EnvConfig,
+        client_id: String,
+        scope: String,
+    ) -> Result<String, Status> {
+        let expiration = SystemTime::now() + Duration::new(config.client_token_duration as u64, 0);
+
+        let claims = Token {
+            sub_type: SubjectType::Client,
+            scope: Some(scope),
+            ..Token::new(client_id, expiration)
+        };
+        claims.create_token(expiration, &config.access_secret).await
+    }
+
+    /// Signs an organization invite link. Invite tokens use `SECRET_KEY`, so they can never be
+    /// mistaken for access tokens.
+    pub async fn create_invitation_token(
+        config: &EnvConfig,
+        invitation_id: String,
+        expiration: SystemTime,
+    ) -> Result<String, Status> {
+        let claims = Token {
+            sub_type: SubjectType::Invitation,
+            ..Token::new(invitation_id, expiration)
+        };
+        claims.create_token(expiration, &config.secret_key).await
+    }
+
+    pub fn subject(&self) -> &str {
+        &self.sub
+    }
+
+    pub fn subject_type(&self) -> SubjectType {
+        self.sub_type
+    }
+
+    pub fn scope(&self) -> Option<&str> {
+        self.scope.as_deref()
+    }
+
+    pub fn org_id(&self) -> Option<&str> {
+        self.org_id.as_deref()
+    }
+
+    pub fn audience(&self) -> Option<&str> {
+        self.aud.as_deref()
+    }
+
+    /// Validates a token without an audience. OpenID provider access tokens carry one and are
+    /// rejected here.
     pub fn validate_token(token_str: &str, secret_key: &str) -> Result<Token, Status> {
+        Self::decode(
+            token_str,
+            secret_key,
+            &jsonwebtoken::Validation::new(Algorithm::HS256),
+        )
+    }
+
+    /// Validates an OpenID provider access token issued to any client.
+    pub fn validate_oidc_access_token(token_str: &str, secret_key: &str) -> Result<Token, Status> {
+        let mut validation = jsonwebtoken::Validation::new(Algorithm::HS256);
+        validation.validate_aud = false;
+
+        Self::decode(token_str, secret_key, &validation)
+            .ok()
+            .filter(|claims| claims.sub_type == SubjectType::OidcUser && claims.aud.is_some())
+            .ok_or_else(|| Status::unauthenticated("Invalid token"))
+    }
+
+    fn decode(
+        token_str: &str,
+        secret_key: &str,
+        validation: &jsonwebtoken::Validation,
+    ) -> Result<Token, Status> {
         jsonwebtoken::decode::<Token>(
             token_str,
             &jsonwebtoken::DecodingKey::from_secret(secret_key.as_ref()),
-            &jsonwebtoken::Validation::new(Algorithm::HS256),
+            validation,
         )
         .map(|data| data.claims)
         .map_err(|e| match e.kind() {
@@ -67,3 +202,70 @@ impl Token {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_config;
+
+    async fn oidc_token(config: &EnvConfig) -> String {
+        Token::create_oidc_access_token(
+            config,
+            "user-1".to_string(),
+            "client-1".to_string(),
+            "openid email".to_string(),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn oidc_access_token_carries_audience_and_scope() {
+        let config = test_config("");
+        let token = oidc_token(&config).await;
+
+        let claims = Token::validate_oidc_access_token(&token, &config.access_secret).unwrap();
+
+        assert_eq!(claims.subject(), "user-1");
+        assert_eq!(claims.subject_type(), SubjectType::OidcUser);
+        assert_eq!(claims.audience(), Some("client-1"));
+        assert_eq!(claims.scope(), Some("openid email"));
+    }
+
+    #[tokio::test]
+    async fn oidc_access_token_is_not_an_api_access_token() {
+        let config = test_config("");
+        let token = oidc_token(&config).await;
+
+        assert!(Token::validate_token(&token, &config.access_secret).is_err());
+    }
+
+    #[tokio::test]
+    async fn api_access_token_is_not_an_oidc_access_token() {
+        let config = test_config("");
+        let (access_token, _) = Token::create_tokens(&config, "user-1".to_string(), None)
+            .await
+            .unwrap();
+        let client_token =
+            Token::create_client_token(&config, "client-1".to_string(), "read".to_string())
+                .await
+                .unwrap();
+
+        for token in [access_token, client_token] {
+            assert!(Token::validate_token(&token, &config.access_secret).is_ok());
+            assert!(Token::validate_oidc_access_token(&token, &config.access_secret).is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn tokens_signed_with_another_secret_are_rejected() {
+        let config = test_config("");
+        let (access_token, refresh_token) =
+            Token::create_tokens(&config, "user-1".to_string(), None)
+                .await
+                .unwrap();
+
+        assert!(Token::validate_token(&refresh_token, &config.access_secret).is_err());
+        assert!(Token::validate_token(&access_token, &config.refresh_secret).is_err());
+    }
+}
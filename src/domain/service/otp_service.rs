@@ -1,4 +1,3 @@
-use once_cell::sync::Lazy;
 use rand::distr::Alphanumeric;
 use rand::{Rng, rng};
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -50,9 +49,3 @@ impl OtpEmail {
         true
     }
 }
-
-static EMAIL_OTP_HELPER: Lazy<OtpEmail> = Lazy::new(|| OtpEmail::init());
-
-pub fn email_otp() -> &'static OtpEmail {
-    &EMAIL_OTP_HELPER
-}
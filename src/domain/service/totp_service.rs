@@ -1,6 +1,4 @@
-use crate::cfg;
 use base32::{Alphabet, encode};
-use once_cell::sync::Lazy;
 use otpauth::TOTP;
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -9,10 +7,8 @@ pub struct Totp {
 }
 
 impl Totp {
-    pub fn init() -> Self {
-        Self {
-            issuer: cfg().app_name.clone(),
-        }
+    pub fn new(issuer: String) -> Self {
+        Self { issuer }
     }
 
     pub fn generate_secret(&self) -> String {
@@ -46,9 +42,3 @@ impl Totp {
         }
     }
 }
-
-static OTP_HELPER: Lazy<Totp> = Lazy::new(|| Totp::init());
-
-pub fn totp() -> &'static Totp {
-    &OTP_HELPER
-}
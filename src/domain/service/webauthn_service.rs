@@ -0,0 +1,180 @@
+use crate::config::env::EnvConfig;
+use crate::domain::entity::passkey_credential::PasskeyCredential;
+use crate::domain::entity::user::User;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use tonic::Status;
+use tracing::{error, info};
+use webauthn_rs::prelude::{
+    AuthenticationResult, CredentialID, Passkey, PasskeyAuthentication, PasskeyRegistration,
+    PublicKeyCredential, RegisterPublicKeyCredential, Url, Webauthn, WebauthnBuilder,
+};
+
+/// A passkey that passed registration, ready to be stored as a [`PasskeyCredential`].
+pub struct RegisteredPasskey {
+    pub credential_id: String,
+    pub credential: String,
+    pub sign_count: i64,
+}
+
+/// A verified login assertion for the credential identified by `credential_id`.
+pub struct PasskeyAssertion {
+    pub credential_id: String,
+    pub sign_count: i64,
+    result: AuthenticationResult,
+}
+
+/// Wraps the WebAuthn relying party. Options, challenge state and credentials cross this
+/// boundary as JSON strings so callers can ship them over gRPC and keep them in Redis.
+pub struct WebauthnService {
+    webauthn: Webauthn,
+}
+
+impl WebauthnService {
+    pub fn new(config: &EnvConfig) -> Self {
+        let origin =
+            Url::parse(&config.webauthn_rp_origin).expect("WEBAUTHN_RP_ORIGIN must be a valid URL");
+
+        let webauthn = WebauthnBuilder::new(&config.webauthn_rp_id, &origin)
+            .expect("Invalid WebAuthn relying party configuration")
+            .rp_name(&config.app_name)
+            .build()
+            .expect("Failed to build WebAuthn relying party");
+
+        info!(
+            "WebAuthn relying party configured for: {}",
+            config.webauthn_rp_id
+        );
+
+        Self { webauthn }
+    }
+
+    pub fn start_registration(
+        &self,
+        user: &User,
+        existing: &[PasskeyCredential],
+    ) -> Result<(String, String), Status> {
+        let exclude = existing
+            .iter()
+            .filter_map(|c| URL_SAFE_NO_PAD.decode(&c.credential_id).ok())
+            .map(CredentialID::from)
+            .collect::<Vec<_>>();
+
+        let (options, state) = self
+            .webauthn
+            .start_passkey_registration(user.id, &user.email, &user.name, Some(exclude))
+            .map_err(|e| {
+                error!("Failed to start passkey registration: {}", e);
+                Status::internal("Failed to start passkey registration")
+            })?;
+
+        Ok((to_json(&options)?, to_json(&state)?))
+    }
+
+    pub fn finish_registration(
+        &self,
+        credential_json: &str,
+        state_json: &str,
+    ) -> Result<RegisteredPasskey, Status> {
+        let credential: RegisterPublicKeyCredential = serde_json::from_str(credential_json)
+            .map_err(|_| Status::invalid_argument("Invalid passkey credential"))?;
+        let state: PasskeyRegistration = from_json(state_json)?;
+
+        let passkey = self
+            .webauthn
+            .finish_passkey_registration(&credential, &state)
+            .map_err(|e| {
+                error!("Passkey registration rejected: {}", e);
+                Status::invalid_argument("Passkey registration failed")
+            })?;
+
+        let credential = to_json(&passkey)?;
+
+        Ok(RegisteredPasskey {
+            credential_id: encode_credential_id(passkey.cred_id()),
+            sign_count: stored_counter(&credential),
+            credential,
+        })
+    }
+
+    pub fn start_authentication(
+        &self,
+        credentials: &[PasskeyCredential],
+    ) -> Result<(String, String), Status> {
+        let passkeys = credentials
+            .iter()
+            .map(|c| from_json::<Passkey>(&c.credential))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (options, state) = self
+            .webauthn
+            .start_passkey_authentication(&passkeys)
+            .map_err(|e| {
+                error!("Failed to start passkey authentication: {}", e);
+                Status::internal("Failed to start passkey login")
+            })?;
+
+        Ok((to_json(&options)?, to_json(&state)?))
+    }
+
+    pub fn finish_authentication(
+        &self,
+        credential_json: &str,
+        state_json: &str,
+    ) -> Result<PasskeyAssertion, Status> {
+        let credential: PublicKeyCredential = serde_json::from_str(credential_json)
+            .map_err(|_| Status::invalid_argument("Invalid passkey credential"))?;
+        let state: PasskeyAuthentication = from_json(state_json)?;
+
+        let result = self
+            .webauthn
+            .finish_passkey_authentication(&credential, &state)
+            .map_err(|e| {
+                error!("Passkey assertion rejected: {}", e);
+                Status::unauthenticated("Passkey login failed")
+            })?;
+
+        Ok(PasskeyAssertion {
+            credential_id: encode_credential_id(result.cred_id()),
+            sign_count: i64::from(result.counter()),
+            result,
+        })
+    }
+
+    /// Applies the counter and backup state of a successful assertion to a stored credential.
+    pub fn refresh_credential(
+        &self,
+        credential: &str,
+        assertion: &PasskeyAssertion,
+    ) -> Result<String, Status> {
+        let mut passkey: Passkey = from_json(credential)?;
+        passkey.update_credential(&assertion.result);
+        to_json(&passkey)
+    }
+}
+
+fn encode_credential_id(id: &CredentialID) -> String {
+    URL_SAFE_NO_PAD.encode(id.as_ref())
+}
+
+/// The passkey type keeps its counter private; read it back from the serialized form.
+fn stored_counter(credential: &str) -> i64 {
+    serde_json::from_str::<serde_json::Value>(credential)
+        .ok()
+        .and_then(|v| v["cred"]["counter"].as_i64())
+        .unwrap_or_default()
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Result<String, Status> {
+    serde_json::to_string(value).map_err(|_| {
+        error!("Failed to serialize WebAuthn data");
+        Status::internal("Failed to serialize WebAuthn data")
+    })
+}
+
+fn from_json<T: serde::de::DeserializeOwned>(value: &str) -> Result<T, Status> {
+    serde_json::from_str(value).map_err(|_| {
+        error!("Failed to deserialize WebAuthn data");
+        Status::internal("Failed to deserialize WebAuthn data")
+    })
+}
@@ -0,0 +1,188 @@
+use crate::domain::entity::api_key::ApiKey;
+use crate::domain::port::db::api_key_port::ApiKeyPort;
+use crate::domain::port::db_port::DbPort;
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::Error;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct ApiKeyAdapter {
+    pub pool: sqlx::PgPool,
+}
+
+impl ApiKeyAdapter {
+    pub fn new(pool: sqlx::PgPool) -> Self {
+        ApiKeyAdapter { pool }
+    }
+}
+
+#[async_trait]
+impl DbPort<ApiKey> for ApiKeyAdapter {
+    #[instrument(
+        name = "ApiKeyAdapter::save",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn save(&self, data: &ApiKey) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO api_keys (id, user_id, name, prefix, key_hash, scopes, expires_at, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+        )
+        .bind(data.id)
+        .bind(data.user_id)
+        .bind(&data.name)
+        .bind(&data.prefix)
+        .bind(&data.key_hash)
+        .bind(&data.scopes)
+        .bind(data.expires_at)
+        .bind(data.created_at)
+        .bind(data.updated_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "ApiKeyAdapter::find_by_id",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ApiKey>, Error> {
+        let result = sqlx::query_as::<_, ApiKey>(
+            "SELECT id, user_id, name, prefix, key_hash, scopes, expires_at, last_used_at, revoked_at, created_at, updated_at, deleted_at FROM api_keys WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "ApiKeyAdapter::find_by_coll",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_coll(&self, coll: &str, value: &str) -> Result<Option<ApiKey>, Error> {
+        let query = match coll {
+            "prefix" => {
+                "SELECT id, user_id, name, prefix, key_hash, scopes, expires_at, last_used_at, revoked_at, created_at, updated_at, deleted_at FROM api_keys WHERE prefix = $1 AND deleted_at IS NULL"
+            }
+            "id" => {
+                "SELECT id, user_id, name, prefix, key_hash, scopes, expires_at, last_used_at, revoked_at, created_at, updated_at, deleted_at FROM api_keys WHERE id = $1::uuid AND deleted_at IS NULL"
+            }
+            _ => return Err(Error::RowNotFound),
+        };
+
+        let result = sqlx::query_as::<_, ApiKey>(query)
+            .bind(value)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "ApiKeyAdapter::update",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn update(&self, id: Uuid, data: &ApiKey) -> Result<(), Error> {
+        sqlx::query(
+            "UPDATE api_keys
+             SET name = $1, scopes = $2, expires_at = $3, updated_at = $4
+             WHERE id = $5 AND deleted_at IS NULL",
+        )
+        .bind(&data.name)
+        .bind(&data.scopes)
+        .bind(data.expires_at)
+        .bind(data.updated_at)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "ApiKeyAdapter::delete",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn delete(&self, id: Uuid) -> Result<(), Error> {
+        let query = sqlx::query("DELETE FROM api_keys WHERE id = $1").bind(id);
+
+        query.execute(&self.pool).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ApiKeyPort for ApiKeyAdapter {
+    #[instrument(
+        name = "ApiKeyAdapter::find_by_prefix",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_prefix(&self, prefix: &str) -> Result<Option<ApiKey>, Error> {
+        self.find_by_coll("prefix", prefix).await
+    }
+
+    #[instrument(
+        name = "ApiKeyAdapter::list_by_user",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<ApiKey>, Error> {
+        let result = sqlx::query_as::<_, ApiKey>(
+            "SELECT id, user_id, name, prefix, key_hash, scopes, expires_at, last_used_at, revoked_at, created_at, updated_at, deleted_at FROM api_keys WHERE user_id = $1 AND deleted_at IS NULL ORDER BY created_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "ApiKeyAdapter::revoke",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn revoke(&self, id: Uuid, user_id: Uuid) -> Result<bool, Error> {
+        let result = sqlx::query(
+            "UPDATE api_keys SET revoked_at = $1, updated_at = $1
+             WHERE id = $2 AND user_id = $3 AND revoked_at IS NULL AND deleted_at IS NULL",
+        )
+        .bind(Utc::now())
+        .bind(id)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(
+        name = "ApiKeyAdapter::touch_last_used",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn touch_last_used(&self, id: Uuid) -> Result<(), Error> {
+        sqlx::query("UPDATE api_keys SET last_used_at = $1 WHERE id = $2")
+            .bind(Utc::now())
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use crate::domain::entity::audit_event::{AuditEvent, AuditFilter};
+use crate::domain::port::db::audit_port::AuditPort;
+use async_trait::async_trait;
+use sqlx::{Error, Postgres, QueryBuilder};
+use tracing::instrument;
+
+pub struct AuditAdapter {
+    pub pool: sqlx::PgPool,
+}
+
+impl AuditAdapter {
+    pub fn new(pool: sqlx::PgPool) -> Self {
+        AuditAdapter { pool }
+    }
+}
+
+#[async_trait]
+impl AuditPort for AuditAdapter {
+    #[instrument(
+        name = "AuditAdapter::record",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn record(&self, event: &AuditEvent) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO audit_events (id, action, outcome, actor_id, target, org_id, ip, device, detail, created_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)",
+        )
+        .bind(event.id)
+        .bind(&event.action)
+        .bind(&event.outcome)
+        .bind(event.actor_id)
+        .bind(&event.target)
+        .bind(event.org_id)
+        .bind(&event.ip)
+        .bind(&event.device)
+        .bind(&event.detail)
+        .bind(event.created_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "AuditAdapter::list",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn list(&self, filter: &AuditFilter, limit: i64) -> Result<Vec<AuditEvent>, Error> {
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "SELECT id, action, outcome, actor_id, target, org_id, ip, device, detail, created_at FROM audit_events WHERE TRUE",
+        );
+
+        if let Some(actor_id) = filter.actor_id {
+            query.push(" AND actor_id = ").push_bind(actor_id);
+        }
+        if let Some(org_id) = filter.org_id {
+            query.push(" AND org_id = ").push_bind(org_id);
+        }
+        if let Some(action) = &filter.action {
+            query.push(" AND action = ").push_bind(action.clone());
+        }
+        if let Some(outcome) = &filter.outcome {
+            query.push(" AND outcome = ").push_bind(outcome.clone());
+        }
+        if let Some(since) = filter.since {
+            query.push(" AND created_at >= ").push_bind(since);
+        }
+        if let Some(until) = filter.until {
+            query.push(" AND created_at < ").push_bind(until);
+        }
+        if let Some((created_at, id)) = filter.before {
+            query
+                .push(" AND (created_at, id) < (")
+                .push_bind(created_at)
+                .push(", ")
+                .push_bind(id)
+                .push(")");
+        }
+
+        query
+            .push(" ORDER BY created_at DESC, id DESC LIMIT ")
+            .push_bind(limit);
+
+        let result = query
+            .build_query_as::<AuditEvent>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,229 @@
+use crate::domain::entity::invitation::Invitation;
+use crate::domain::entity::outbox_message::OutboxMessage;
+use crate::domain::port::db::invitation_port::InvitationPort;
+use crate::domain::port::db_port::DbPort;
+use crate::infrastructure::db::outbox_adapter::insert_outbox_message;
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::Error;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct InvitationAdapter {
+    pub pool: sqlx::PgPool,
+}
+
+impl InvitationAdapter {
+    pub fn new(pool: sqlx::PgPool) -> Self {
+        InvitationAdapter { pool }
+    }
+}
+
+#[async_trait]
+impl DbPort<Invitation> for InvitationAdapter {
+    #[instrument(
+        name = "InvitationAdapter::save",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn save(&self, data: &Invitation) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO invitations (id, org_id, email, role, invited_by, expires_at, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+        )
+        .bind(data.id)
+        .bind(data.org_id)
+        .bind(&data.email)
+        .bind(data.role)
+        .bind(data.invited_by)
+        .bind(data.expires_at)
+        .bind(data.created_at)
+        .bind(data.updated_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "InvitationAdapter::find_by_id",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Invitation>, Error> {
+        let result = sqlx::query_as::<_, Invitation>(
+            "SELECT id, org_id, email, role, invited_by, expires_at, accepted_at, revoked_at, created_at, updated_at, deleted_at FROM invitations WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "InvitationAdapter::find_by_coll",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_coll(&self, coll: &str, value: &str) -> Result<Option<Invitation>, Error> {
+        let query = match coll {
+            "id" => {
+                "SELECT id, org_id, email, role, invited_by, expires_at, accepted_at, revoked_at, created_at, updated_at, deleted_at FROM invitations WHERE id = $1::uuid AND deleted_at IS NULL"
+            }
+            _ => return Err(Error::RowNotFound),
+        };
+
+        let result = sqlx::query_as::<_, Invitation>(query)
+            .bind(value)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "InvitationAdapter::update",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn update(&self, id: Uuid, data: &Invitation) -> Result<(), Error> {
+        sqlx::query(
+            "UPDATE invitations
+             SET role = $1, expires_at = $2, updated_at = $3
+             WHERE id = $4 AND deleted_at IS NULL",
+        )
+        .bind(data.role)
+        .bind(data.expires_at)
+        .bind(data.updated_at)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "InvitationAdapter::delete",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn delete(&self, id: Uuid) -> Result<(), Error> {
+        let query = sqlx::query("DELETE FROM invitations WHERE id = $1").bind(id);
+
+        query.execute(&self.pool).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl InvitationPort for InvitationAdapter {
+    #[instrument(
+        name = "InvitationAdapter::create_with_message",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn create_with_message(
+        &self,
+        data: &Invitation,
+        message: &OutboxMessage,
+    ) -> Result<(), Error> {
+        let mut transaction = self.pool.begin().await?;
+
+        sqlx::query(
+            "INSERT INTO invitations (id, org_id, email, role, invited_by, expires_at, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+        )
+        .bind(data.id)
+        .bind(data.org_id)
+        .bind(&data.email)
+        .bind(data.role)
+        .bind(data.invited_by)
+        .bind(data.expires_at)
+        .bind(data.created_at)
+        .bind(data.updated_at)
+        .execute(&mut *transaction)
+        .await?;
+
+        insert_outbox_message(&mut *transaction, message).await?;
+
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "InvitationAdapter::list_by_org",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn list_by_org(&self, org_id: Uuid) -> Result<Vec<Invitation>, Error> {
+        let result = sqlx::query_as::<_, Invitation>(
+            "SELECT id, org_id, email, role, invited_by, expires_at, accepted_at, revoked_at, created_at, updated_at, deleted_at FROM invitations WHERE org_id = $1 AND deleted_at IS NULL ORDER BY created_at DESC",
+        )
+        .bind(org_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "InvitationAdapter::find_pending",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_pending(&self, org_id: Uuid, email: &str) -> Result<Option<Invitation>, Error> {
+        let result = sqlx::query_as::<_, Invitation>(
+            "SELECT id, org_id, email, role, invited_by, expires_at, accepted_at, revoked_at, created_at, updated_at, deleted_at FROM invitations
+             WHERE org_id = $1 AND email = $2 AND accepted_at IS NULL AND revoked_at IS NULL AND expires_at > NOW() AND deleted_at IS NULL",
+        )
+        .bind(org_id)
+        .bind(email)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "InvitationAdapter::mark_accepted",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn mark_accepted(&self, id: Uuid) -> Result<(), Error> {
+        sqlx::query(
+            "UPDATE invitations SET accepted_at = $1, updated_at = $1 WHERE id = $2 AND deleted_at IS NULL",
+        )
+        .bind(Utc::now())
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "InvitationAdapter::revoke",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn revoke(&self, id: Uuid) -> Result<bool, Error> {
+        let result = sqlx::query(
+            "UPDATE invitations SET revoked_at = $1, updated_at = $1
+             WHERE id = $2 AND accepted_at IS NULL AND revoked_at IS NULL AND deleted_at IS NULL",
+        )
+        .bind(Utc::now())
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,147 @@
+use crate::domain::entity::membership::Membership;
+use crate::domain::port::db::membership_port::MembershipPort;
+use crate::domain::port::db_port::DbPort;
+use async_trait::async_trait;
+use sqlx::{Error, PgExecutor};
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct MembershipAdapter {
+    pub pool: sqlx::PgPool,
+}
+
+impl MembershipAdapter {
+    pub fn new(pool: sqlx::PgPool) -> Self {
+        MembershipAdapter { pool }
+    }
+}
+
+/// Inserts a membership on any executor, so other adapters can add it inside their transaction.
+pub(crate) async fn insert_membership<'e>(
+    executor: impl PgExecutor<'e>,
+    data: &Membership,
+) -> Result<(), Error> {
+    sqlx::query(
+        "INSERT INTO memberships (id, org_id, user_id, role, created_at, updated_at)
+         VALUES ($1, $2, $3, $4, $5, $6)",
+    )
+    .bind(data.id)
+    .bind(data.org_id)
+    .bind(data.user_id)
+    .bind(data.role)
+    .bind(data.created_at)
+    .bind(data.updated_at)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+#[async_trait]
+impl DbPort<Membership> for MembershipAdapter {
+    #[instrument(
+        name = "MembershipAdapter::save",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn save(&self, data: &Membership) -> Result<(), Error> {
+        insert_membership(&self.pool, data).await
+    }
+
+    #[instrument(
+        name = "MembershipAdapter::find_by_id",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Membership>, Error> {
+        let result = sqlx::query_as::<_, Membership>(
+            "SELECT id, org_id, user_id, role, created_at, updated_at, deleted_at FROM memberships WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "MembershipAdapter::find_by_coll",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_coll(&self, coll: &str, value: &str) -> Result<Option<Membership>, Error> {
+        let query = match coll {
+            "id" => {
+                "SELECT id, org_id, user_id, role, created_at, updated_at, deleted_at FROM memberships WHERE id = $1::uuid AND deleted_at IS NULL"
+            }
+            _ => return Err(Error::RowNotFound),
+        };
+
+        let result = sqlx::query_as::<_, Membership>(query)
+            .bind(value)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "MembershipAdapter::update",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn update(&self, id: Uuid, data: &Membership) -> Result<(), Error> {
+        sqlx::query(
+            "UPDATE memberships
+             SET role = $1, updated_at = $2
+             WHERE id = $3 AND deleted_at IS NULL",
+        )
+        .bind(data.role)
+        .bind(data.updated_at)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "MembershipAdapter::delete",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn delete(&self, id: Uuid) -> Result<(), Error> {
+        let query = sqlx::query("DELETE FROM memberships WHERE id = $1").bind(id);
+
+        query.execute(&self.pool).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl MembershipPort for MembershipAdapter {
+    #[instrument(
+        name = "MembershipAdapter::find_membership",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_membership(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<Membership>, Error> {
+        let result = sqlx::query_as::<_, Membership>(
+            "SELECT id, org_id, user_id, role, created_at, updated_at, deleted_at FROM memberships WHERE org_id = $1 AND user_id = $2 AND deleted_at IS NULL",
+        )
+        .bind(org_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,135 @@
+use crate::domain::entity::oauth_client::OAuthClient;
+use crate::domain::port::db::oauth_client_port::OAuthClientPort;
+use crate::domain::port::db_port::DbPort;
+use async_trait::async_trait;
+use sqlx::Error;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct OAuthClientAdapter {
+    pub pool: sqlx::PgPool,
+}
+
+impl OAuthClientAdapter {
+    pub fn new(pool: sqlx::PgPool) -> Self {
+        OAuthClientAdapter { pool }
+    }
+}
+
+#[async_trait]
+impl DbPort<OAuthClient> for OAuthClientAdapter {
+    #[instrument(
+        name = "OAuthClientAdapter::save",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn save(&self, data: &OAuthClient) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO oauth_clients (id, client_id, client_secret_hash, name, redirect_uris, allowed_scopes, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+        )
+        .bind(data.id)
+        .bind(&data.client_id)
+        .bind(&data.client_secret_hash)
+        .bind(&data.name)
+        .bind(&data.redirect_uris)
+        .bind(&data.allowed_scopes)
+        .bind(data.created_at)
+        .bind(data.updated_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "OAuthClientAdapter::find_by_id",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<OAuthClient>, Error> {
+        let result = sqlx::query_as::<_, OAuthClient>(
+            "SELECT id, client_id, client_secret_hash, name, redirect_uris, allowed_scopes, created_at, updated_at, deleted_at FROM oauth_clients WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "OAuthClientAdapter::find_by_coll",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_coll(&self, coll: &str, value: &str) -> Result<Option<OAuthClient>, Error> {
+        let query = match coll {
+            "client_id" => {
+                "SELECT id, client_id, client_secret_hash, name, redirect_uris, allowed_scopes, created_at, updated_at, deleted_at FROM oauth_clients WHERE client_id = $1 AND deleted_at IS NULL"
+            }
+            "name" => {
+                "SELECT id, client_id, client_secret_hash, name, redirect_uris, allowed_scopes, created_at, updated_at, deleted_at FROM oauth_clients WHERE name = $1 AND deleted_at IS NULL"
+            }
+            _ => return Err(Error::RowNotFound),
+        };
+
+        let result = sqlx::query_as::<_, OAuthClient>(query)
+            .bind(value)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "OAuthClientAdapter::update",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn update(&self, id: Uuid, data: &OAuthClient) -> Result<(), Error> {
+        sqlx::query(
+            "UPDATE oauth_clients
+             SET client_secret_hash = $1, name = $2, redirect_uris = $3, allowed_scopes = $4, updated_at = $5
+             WHERE id = $6 AND deleted_at IS NULL",
+        )
+        .bind(&data.client_secret_hash)
+        .bind(&data.name)
+        .bind(&data.redirect_uris)
+        .bind(&data.allowed_scopes)
+        .bind(data.updated_at)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "OAuthClientAdapter::delete",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn delete(&self, id: Uuid) -> Result<(), Error> {
+        let query = sqlx::query("DELETE FROM oauth_clients WHERE id = $1").bind(id);
+
+        query.execute(&self.pool).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl OAuthClientPort for OAuthClientAdapter {
+    #[instrument(
+        name = "OAuthClientAdapter::find_by_client_id",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_client_id(&self, client_id: &str) -> Result<Option<OAuthClient>, Error> {
+        self.find_by_coll("client_id", client_id).await
+    }
+}
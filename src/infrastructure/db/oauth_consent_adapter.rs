@@ -0,0 +1,166 @@
+use crate::domain::entity::oauth_consent::OAuthConsent;
+use crate::domain::port::db::oauth_consent_port::OAuthConsentPort;
+use crate::domain::port::db_port::DbPort;
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::Error;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct OAuthConsentAdapter {
+    pub pool: sqlx::PgPool,
+}
+
+impl OAuthConsentAdapter {
+    pub fn new(pool: sqlx::PgPool) -> Self {
+        OAuthConsentAdapter { pool }
+    }
+}
+
+#[async_trait]
+impl DbPort<OAuthConsent> for OAuthConsentAdapter {
+    #[instrument(
+        name = "OAuthConsentAdapter::save",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn save(&self, data: &OAuthConsent) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO oauth_consents (id, user_id, client_id, scopes, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6)",
+        )
+        .bind(data.id)
+        .bind(data.user_id)
+        .bind(&data.client_id)
+        .bind(&data.scopes)
+        .bind(data.created_at)
+        .bind(data.updated_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "OAuthConsentAdapter::find_by_id",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<OAuthConsent>, Error> {
+        let result = sqlx::query_as::<_, OAuthConsent>(
+            "SELECT id, user_id, client_id, scopes, created_at, updated_at, deleted_at FROM oauth_consents WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "OAuthConsentAdapter::find_by_coll",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_coll(&self, coll: &str, value: &str) -> Result<Option<OAuthConsent>, Error> {
+        let query = match coll {
+            "user_id" => {
+                "SELECT id, user_id, client_id, scopes, created_at, updated_at, deleted_at FROM oauth_consents WHERE user_id = $1::uuid AND deleted_at IS NULL"
+            }
+            "client_id" => {
+                "SELECT id, user_id, client_id, scopes, created_at, updated_at, deleted_at FROM oauth_consents WHERE client_id = $1 AND deleted_at IS NULL"
+            }
+            _ => return Err(Error::RowNotFound),
+        };
+
+        let result = sqlx::query_as::<_, OAuthConsent>(query)
+            .bind(value)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "OAuthConsentAdapter::update",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn update(&self, id: Uuid, data: &OAuthConsent) -> Result<(), Error> {
+        sqlx::query(
+            "UPDATE oauth_consents SET scopes = $1, updated_at = $2 WHERE id = $3 AND deleted_at IS NULL",
+        )
+        .bind(&data.scopes)
+        .bind(data.updated_at)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "OAuthConsentAdapter::delete",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn delete(&self, id: Uuid) -> Result<(), Error> {
+        let query = sqlx::query("DELETE FROM oauth_consents WHERE id = $1").bind(id);
+
+        query.execute(&self.pool).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl OAuthConsentPort for OAuthConsentAdapter {
+    #[instrument(
+        name = "OAuthConsentAdapter::find_by_user_client",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_user_client(
+        &self,
+        user_id: Uuid,
+        client_id: &str,
+    ) -> Result<Option<OAuthConsent>, Error> {
+        let result = sqlx::query_as::<_, OAuthConsent>(
+            "SELECT id, user_id, client_id, scopes, created_at, updated_at, deleted_at FROM oauth_consents WHERE user_id = $1 AND client_id = $2 AND deleted_at IS NULL",
+        )
+        .bind(user_id)
+        .bind(client_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "OAuthConsentAdapter::grant",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn grant(&self, data: &OAuthConsent) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO oauth_consents (id, user_id, client_id, scopes, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6)
+             ON CONFLICT (user_id, client_id)
+             DO UPDATE SET scopes = EXCLUDED.scopes, updated_at = $7, deleted_at = NULL",
+        )
+        .bind(data.id)
+        .bind(data.user_id)
+        .bind(&data.client_id)
+        .bind(&data.scopes)
+        .bind(data.created_at)
+        .bind(data.updated_at)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
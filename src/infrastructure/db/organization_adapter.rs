@@ -0,0 +1,184 @@
+use crate::domain::entity::membership::{Membership, MembershipRole};
+use crate::domain::entity::organization::{Organization, UserOrganization};
+use crate::domain::port::db::organization_port::OrganizationPort;
+use crate::domain::port::db_port::DbPort;
+use async_trait::async_trait;
+use sqlx::Error;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+pub struct OrganizationAdapter {
+    pub pool: sqlx::PgPool,
+}
+
+impl OrganizationAdapter {
+    pub fn new(pool: sqlx::PgPool) -> Self {
+        OrganizationAdapter { pool }
+    }
+}
+
+#[async_trait]
+impl DbPort<Organization> for OrganizationAdapter {
+    #[instrument(
+        name = "OrganizationAdapter::save",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn save(&self, data: &Organization) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO organizations (id, name, slug, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5)",
+        )
+        .bind(data.id)
+        .bind(&data.name)
+        .bind(&data.slug)
+        .bind(data.created_at)
+        .bind(data.updated_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "OrganizationAdapter::find_by_id",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Organization>, Error> {
+        let result = sqlx::query_as::<_, Organization>(
+            "SELECT id, name, slug, created_at, updated_at, deleted_at FROM organizations WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "OrganizationAdapter::find_by_coll",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_coll(&self, coll: &str, value: &str) -> Result<Option<Organization>, Error> {
+        let query = match coll {
+            "slug" => {
+                "SELECT id, name, slug, created_at, updated_at, deleted_at FROM organizations WHERE slug = $1 AND deleted_at IS NULL"
+            }
+            "id" => {
+                "SELECT id, name, slug, created_at, updated_at, deleted_at FROM organizations WHERE id = $1::uuid AND deleted_at IS NULL"
+            }
+            _ => return Err(Error::RowNotFound),
+        };
+
+        let result = sqlx::query_as::<_, Organization>(query)
+            .bind(value)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "OrganizationAdapter::update",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn update(&self, id: Uuid, data: &Organization) -> Result<(), Error> {
+        sqlx::query(
+            "UPDATE organizations
+             SET name = $1, slug = $2, updated_at = $3
+             WHERE id = $4 AND deleted_at IS NULL",
+        )
+        .bind(&data.name)
+        .bind(&data.slug)
+        .bind(data.updated_at)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "OrganizationAdapter::delete",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn delete(&self, id: Uuid) -> Result<(), Error> {
+        let query = sqlx::query("DELETE FROM organizations WHERE id = $1").bind(id);
+
+        query.execute(&self.pool).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl OrganizationPort for OrganizationAdapter {
+    #[instrument(
+        name = "OrganizationAdapter::create_with_owner",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn create_with_owner(&self, data: &Organization, owner_id: Uuid) -> Result<(), Error> {
+        let mut transaction = self.pool.begin().await?;
+
+        sqlx::query(
+            "INSERT INTO organizations (id, name, slug, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5)",
+        )
+        .bind(data.id)
+        .bind(&data.name)
+        .bind(&data.slug)
+        .bind(data.created_at)
+        .bind(data.updated_at)
+        .execute(&mut *transaction)
+        .await?;
+
+        let owner = Membership::new(data.id, owner_id, MembershipRole::Owner);
+        sqlx::query(
+            "INSERT INTO memberships (id, org_id, user_id, role, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6)",
+        )
+        .bind(owner.id)
+        .bind(owner.org_id)
+        .bind(owner.user_id)
+        .bind(owner.role)
+        .bind(owner.created_at)
+        .bind(owner.updated_at)
+        .execute(&mut *transaction)
+        .await?;
+
+        transaction.commit().await?;
+
+        info!("Organization {} created by user: {}", data.slug, owner_id);
+
+        Ok(())
+    }
+
+    #[instrument(
+        name = "OrganizationAdapter::list_by_user",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<UserOrganization>, Error> {
+        let result = sqlx::query_as::<_, UserOrganization>(
+            "SELECT o.id, o.name, o.slug, m.role, m.created_at AS joined_at
+             FROM memberships m
+             JOIN organizations o ON o.id = m.org_id
+             WHERE m.user_id = $1 AND m.deleted_at IS NULL AND o.deleted_at IS NULL
+             ORDER BY o.name",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+}
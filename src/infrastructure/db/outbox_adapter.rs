@@ -0,0 +1,212 @@
+use crate::domain::entity::outbox_message::{OutboxMessage, OutboxStatus};
+use crate::domain::port::db::outbox_port::OutboxPort;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Error, PgExecutor, Postgres, QueryBuilder};
+use std::time::Duration;
+use tracing::instrument;
+use uuid::Uuid;
+
+const OUTBOX_COLUMNS: &str = "id, kind, payload, status, attempts, last_error, next_attempt_at, delivered_at, created_at, updated_at";
+
+pub struct OutboxAdapter {
+    pub pool: sqlx::PgPool,
+}
+
+impl OutboxAdapter {
+    pub fn new(pool: sqlx::PgPool) -> Self {
+        OutboxAdapter { pool }
+    }
+}
+
+/// Inserts an outbox message on any executor, so other adapters can enqueue it inside the
+/// transaction that performs the state change.
+pub(crate) async fn insert_outbox_message<'e>(
+    executor: impl PgExecutor<'e>,
+    message: &OutboxMessage,
+) -> Result<(), Error> {
+    sqlx::query(
+        "INSERT INTO outbox (id, kind, payload, status, attempts, next_attempt_at, created_at, updated_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+    )
+    .bind(message.id)
+    .bind(&message.kind)
+    .bind(&message.payload)
+    .bind(&message.status)
+    .bind(message.attempts)
+    .bind(message.next_attempt_at)
+    .bind(message.created_at)
+    .bind(message.updated_at)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+#[async_trait]
+impl OutboxPort for OutboxAdapter {
+    #[instrument(
+        name = "OutboxAdapter::enqueue",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn enqueue(&self, message: &OutboxMessage) -> Result<(), Error> {
+        insert_outbox_message(&self.pool, message).await
+    }
+
+    #[instrument(
+        name = "OutboxAdapter::claim_due",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn claim_due(&self, limit: i64, lease: Duration) -> Result<Vec<OutboxMessage>, Error> {
+        let query = format!(
+            "UPDATE outbox
+             SET attempts = attempts + 1,
+                 next_attempt_at = NOW() + $2 * INTERVAL '1 second',
+                 updated_at = NOW()
+             WHERE id IN (
+                 SELECT id FROM outbox
+                 WHERE status = 'pending' AND next_attempt_at <= NOW()
+                 ORDER BY next_attempt_at
+                 LIMIT $1
+                 FOR UPDATE SKIP LOCKED
+             )
+             RETURNING {}",
+            OUTBOX_COLUMNS
+        );
+
+        let result = sqlx::query_as::<_, OutboxMessage>(&query)
+            .bind(limit)
+            .bind(lease.as_secs_f64())
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "OutboxAdapter::mark_delivered",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn mark_delivered(&self, id: Uuid) -> Result<(), Error> {
+        sqlx::query(
+            "UPDATE outbox
+             SET status = 'delivered', delivered_at = NOW(), last_error = NULL, updated_at = NOW()
+             WHERE id = $1",
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "OutboxAdapter::mark_failed",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), Error> {
+        let status = match retry_at {
+            Some(_) => OutboxStatus::Pending,
+            None => OutboxStatus::Dead,
+        };
+
+        sqlx::query(
+            "UPDATE outbox
+             SET status = $1, last_error = $2, next_attempt_at = COALESCE($3, next_attempt_at), updated_at = NOW()
+             WHERE id = $4",
+        )
+        .bind(status.as_str())
+        .bind(error)
+        .bind(retry_at)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "OutboxAdapter::list",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn list(
+        &self,
+        status: Option<OutboxStatus>,
+        before: Option<(DateTime<Utc>, Uuid)>,
+        limit: i64,
+    ) -> Result<Vec<OutboxMessage>, Error> {
+        let mut query: QueryBuilder<Postgres> =
+            QueryBuilder::new(format!("SELECT {} FROM outbox WHERE TRUE", OUTBOX_COLUMNS));
+
+        if let Some(status) = status {
+            query.push(" AND status = ").push_bind(status.as_str());
+        }
+        if let Some((created_at, id)) = before {
+            query
+                .push(" AND (created_at, id) < (")
+                .push_bind(created_at)
+                .push(", ")
+                .push_bind(id)
+                .push(")");
+        }
+
+        query
+            .push(" ORDER BY created_at DESC, id DESC LIMIT ")
+            .push_bind(limit);
+
+        let result = query
+            .build_query_as::<OutboxMessage>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "OutboxAdapter::replay",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn replay(&self, id: Uuid) -> Result<bool, Error> {
+        let result = sqlx::query(
+            "UPDATE outbox
+             SET status = 'pending', attempts = 0, next_attempt_at = NOW(), updated_at = NOW()
+             WHERE id = $1 AND status = 'dead'",
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(
+        name = "OutboxAdapter::purge_finished",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn purge_finished(&self, before: DateTime<Utc>) -> Result<u64, Error> {
+        let result = sqlx::query(
+            "DELETE FROM outbox WHERE status IN ('delivered', 'dead') AND updated_at < $1",
+        )
+        .bind(before)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
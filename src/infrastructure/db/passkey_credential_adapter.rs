@@ -0,0 +1,180 @@
+use crate::domain::entity::passkey_credential::PasskeyCredential;
+use crate::domain::port::db::passkey_credential_port::PasskeyCredentialPort;
+use crate::domain::port::db_port::DbPort;
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::Error;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct PasskeyCredentialAdapter {
+    pub pool: sqlx::PgPool,
+}
+
+impl PasskeyCredentialAdapter {
+    pub fn new(pool: sqlx::PgPool) -> Self {
+        PasskeyCredentialAdapter { pool }
+    }
+}
+
+#[async_trait]
+impl DbPort<PasskeyCredential> for PasskeyCredentialAdapter {
+    #[instrument(
+        name = "PasskeyCredentialAdapter::save",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn save(&self, data: &PasskeyCredential) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO passkey_credentials (id, user_id, name, credential_id, credential, sign_count, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+        )
+        .bind(data.id)
+        .bind(data.user_id)
+        .bind(&data.name)
+        .bind(&data.credential_id)
+        .bind(&data.credential)
+        .bind(data.sign_count)
+        .bind(data.created_at)
+        .bind(data.updated_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "PasskeyCredentialAdapter::find_by_id",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PasskeyCredential>, Error> {
+        let result = sqlx::query_as::<_, PasskeyCredential>(
+            "SELECT id, user_id, name, credential_id, credential, sign_count, last_used_at, created_at, updated_at, deleted_at FROM passkey_credentials WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "PasskeyCredentialAdapter::find_by_coll",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_coll(
+        &self,
+        coll: &str,
+        value: &str,
+    ) -> Result<Option<PasskeyCredential>, Error> {
+        let query = match coll {
+            "credential_id" => {
+                "SELECT id, user_id, name, credential_id, credential, sign_count, last_used_at, created_at, updated_at, deleted_at FROM passkey_credentials WHERE credential_id = $1 AND deleted_at IS NULL"
+            }
+            "id" => {
+                "SELECT id, user_id, name, credential_id, credential, sign_count, last_used_at, created_at, updated_at, deleted_at FROM passkey_credentials WHERE id = $1::uuid AND deleted_at IS NULL"
+            }
+            _ => return Err(Error::RowNotFound),
+        };
+
+        let result = sqlx::query_as::<_, PasskeyCredential>(query)
+            .bind(value)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "PasskeyCredentialAdapter::update",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn update(&self, id: Uuid, data: &PasskeyCredential) -> Result<(), Error> {
+        sqlx::query(
+            "UPDATE passkey_credentials
+             SET name = $1, credential = $2, sign_count = $3, updated_at = $4
+             WHERE id = $5 AND deleted_at IS NULL",
+        )
+        .bind(&data.name)
+        .bind(&data.credential)
+        .bind(data.sign_count)
+        .bind(data.updated_at)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "PasskeyCredentialAdapter::delete",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn delete(&self, id: Uuid) -> Result<(), Error> {
+        let query = sqlx::query("DELETE FROM passkey_credentials WHERE id = $1").bind(id);
+
+        query.execute(&self.pool).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl PasskeyCredentialPort for PasskeyCredentialAdapter {
+    #[instrument(
+        name = "PasskeyCredentialAdapter::list_by_user",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<PasskeyCredential>, Error> {
+        let result = sqlx::query_as::<_, PasskeyCredential>(
+            "SELECT id, user_id, name, credential_id, credential, sign_count, last_used_at, created_at, updated_at, deleted_at FROM passkey_credentials WHERE user_id = $1 AND deleted_at IS NULL",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "PasskeyCredentialAdapter::find_by_credential_id",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_credential_id(
+        &self,
+        credential_id: &str,
+    ) -> Result<Option<PasskeyCredential>, Error> {
+        self.find_by_coll("credential_id", credential_id).await
+    }
+
+    #[instrument(
+        name = "PasskeyCredentialAdapter::record_use",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn record_use(&self, id: Uuid, credential: &str, sign_count: i64) -> Result<(), Error> {
+        sqlx::query(
+            "UPDATE passkey_credentials
+             SET credential = $1, sign_count = $2, last_used_at = $3, updated_at = $3
+             WHERE id = $4 AND deleted_at IS NULL",
+        )
+        .bind(credential)
+        .bind(sign_count)
+        .bind(Utc::now())
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+use crate::domain::entity::service_client::ServiceClient;
+use crate::domain::port::db::service_client_port::ServiceClientPort;
+use crate::domain::port::db_port::DbPort;
+use async_trait::async_trait;
+use sqlx::Error;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct ServiceClientAdapter {
+    pub pool: sqlx::PgPool,
+}
+
+impl ServiceClientAdapter {
+    pub fn new(pool: sqlx::PgPool) -> Self {
+        ServiceClientAdapter { pool }
+    }
+}
+
+#[async_trait]
+impl DbPort<ServiceClient> for ServiceClientAdapter {
+    #[instrument(
+        name = "ServiceClientAdapter::save",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn save(&self, data: &ServiceClient) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO service_clients (id, client_id, client_secret_hash, name, allowed_scopes, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)",
+        )
+        .bind(data.id)
+        .bind(&data.client_id)
+        .bind(&data.client_secret_hash)
+        .bind(&data.name)
+        .bind(&data.allowed_scopes)
+        .bind(data.created_at)
+        .bind(data.updated_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "ServiceClientAdapter::find_by_id",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ServiceClient>, Error> {
+        let result = sqlx::query_as::<_, ServiceClient>(
+            "SELECT id, client_id, client_secret_hash, name, allowed_scopes, created_at, updated_at, deleted_at FROM service_clients WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "ServiceClientAdapter::find_by_coll",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_coll(&self, coll: &str, value: &str) -> Result<Option<ServiceClient>, Error> {
+        let query = match coll {
+            "client_id" => {
+                "SELECT id, client_id, client_secret_hash, name, allowed_scopes, created_at, updated_at, deleted_at FROM service_clients WHERE client_id = $1 AND deleted_at IS NULL"
+            }
+            "name" => {
+                "SELECT id, client_id, client_secret_hash, name, allowed_scopes, created_at, updated_at, deleted_at FROM service_clients WHERE name = $1 AND deleted_at IS NULL"
+            }
+            _ => return Err(Error::RowNotFound),
+        };
+
+        let result = sqlx::query_as::<_, ServiceClient>(query)
+            .bind(value)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "ServiceClientAdapter::update",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn update(&self, id: Uuid, data: &ServiceClient) -> Result<(), Error> {
+        sqlx::query(
+            "UPDATE service_clients
+             SET client_secret_hash = $1, name = $2, allowed_scopes = $3, updated_at = $4
+             WHERE id = $5 AND deleted_at IS NULL",
+        )
+        .bind(&data.client_secret_hash)
+        .bind(&data.name)
+        .bind(&data.allowed_scopes)
+        .bind(data.updated_at)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "ServiceClientAdapter::delete",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn delete(&self, id: Uuid) -> Result<(), Error> {
+        let query = sqlx::query("DELETE FROM service_clients WHERE id = $1").bind(id);
+
+        query.execute(&self.pool).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ServiceClientPort for ServiceClientAdapter {
+    #[instrument(
+        name = "ServiceClientAdapter::find_by_client_id",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_client_id(&self, client_id: &str) -> Result<Option<ServiceClient>, Error> {
+        self.find_by_coll("client_id", client_id).await
+    }
+}
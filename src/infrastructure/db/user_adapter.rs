@@ -1,10 +1,14 @@
+use crate::domain::entity::membership::Membership;
+use crate::domain::entity::outbox_message::OutboxMessage;
 use crate::domain::entity::user::{User, UserStatus};
 use crate::domain::port::db::user_port::UserPort;
 use crate::domain::port::db_port::DbPort;
+use crate::infrastructure::db::membership_adapter::insert_membership;
+use crate::infrastructure::db::outbox_adapter::insert_outbox_message;
 use async_trait::async_trait;
 use chrono::Utc;
-use sqlx::Error;
-use tracing::info;
+use sqlx::{Error, PgExecutor};
+use tracing::{info, instrument};
 use uuid::Uuid;
 
 pub struct UserAdapter {
@@ -17,27 +21,45 @@ impl UserAdapter {
     }
 }
 
+async fn insert_user<'e>(executor: impl PgExecutor<'e>, data: &User) -> Result<(), Error> {
+    sqlx::query(
+        "INSERT INTO users (id, name, email, password, org_id, locale, created_at, updated_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+    )
+    .bind(data.id)
+    .bind(&data.name)
+    .bind(&data.email)
+    .bind(&data.password)
+    .bind(data.org_id)
+    .bind(&data.locale)
+    .bind(data.created_at)
+    .bind(data.updated_at)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
 #[async_trait]
 impl DbPort<User> for UserAdapter {
+    #[instrument(
+        name = "UserAdapter::save",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
     async fn save(&self, data: &User) -> Result<(), Error> {
-        sqlx::query(
-            "INSERT INTO users (id, name, email, password, created_at, updated_at)
-             VALUES ($1, $2, $3, $4, $5, $6)",
-        )
-        .bind(data.id)
-        .bind(&data.name)
-        .bind(&data.email)
-        .bind(&data.password)
-        .bind(data.created_at)
-        .bind(data.updated_at)
-        .execute(&self.pool)
-        .await?;
-        Ok(())
+        insert_user(&self.pool, data).await
     }
 
+    #[instrument(
+        name = "UserAdapter::find_by_id",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
     async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, Error> {
         let result = sqlx::query_as::<_, User>(
-            "SELECT id, name, email, password, status,created_at, updated_at, deleted_at FROM users WHERE id = $1 AND deleted_at IS NULL",
+            "SELECT id, name, email, password, status, org_id, phone_number, phone_verified_at, locale, created_at, updated_at, deleted_at FROM users WHERE id = $1 AND deleted_at IS NULL",
         )
         .bind(id)
         .fetch_optional(&self.pool)
@@ -46,13 +68,17 @@ impl DbPort<User> for UserAdapter {
         Ok(result)
     }
 
+    #[instrument(
+        name = "UserAdapter::find_by_coll",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
     async fn find_by_coll(&self, coll: &str, value: &str) -> Result<Option<User>, Error> {
         let query = match coll {
-            "email" => {
-                "SELECT id, name, email, password, status, created_at, updated_at, deleted_at FROM users WHERE email = $1 AND deleted_at IS NULL"
-            }
+            "email" => return self.find_by_email(value, None).await,
             "id" => {
-                "SELECT id, name, email, password, status, created_at, updated_at, deleted_at FROM users WHERE id = $1 AND deleted_at IS NULL"
+                "SELECT id, name, email, password, status, org_id, phone_number, phone_verified_at, locale, created_at, updated_at, deleted_at FROM users WHERE id = $1::uuid AND deleted_at IS NULL"
             }
             _ => return Err(Error::RowNotFound),
         };
@@ -69,6 +95,12 @@ impl DbPort<User> for UserAdapter {
         Ok(result)
     }
 
+    #[instrument(
+        name = "UserAdapter::update",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
     async fn update(&self, id: Uuid, data: &User) -> Result<(), Error> {
         sqlx::query(
             "UPDATE users
@@ -85,6 +117,12 @@ impl DbPort<User> for UserAdapter {
         Ok(())
     }
 
+    #[instrument(
+        name = "UserAdapter::delete",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
     async fn delete(&self, id: Uuid) -> Result<(), Error> {
         let query = sqlx::query("DELETE FROM users WHERE id = $1").bind(id);
 
@@ -95,7 +133,65 @@ impl DbPort<User> for UserAdapter {
 
 #[async_trait]
 impl UserPort for UserAdapter {
-    async fn verify_email(&self, id: Uuid) -> Result<(), Error> {
+    #[instrument(
+        name = "UserAdapter::register",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn register(
+        &self,
+        data: &User,
+        membership: Option<&Membership>,
+        messages: &[OutboxMessage],
+    ) -> Result<(), Error> {
+        let mut transaction = self.pool.begin().await?;
+
+        insert_user(&mut *transaction, data).await?;
+        if let Some(membership) = membership {
+            insert_membership(&mut *transaction, membership).await?;
+        }
+        for message in messages {
+            insert_outbox_message(&mut *transaction, message).await?;
+        }
+
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "UserAdapter::find_by_email",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_email(
+        &self,
+        email: &str,
+        org_id: Option<Uuid>,
+    ) -> Result<Option<User>, Error> {
+        let result = sqlx::query_as::<_, User>(
+            "SELECT id, name, email, password, status, org_id, phone_number, phone_verified_at, locale, created_at, updated_at, deleted_at FROM users WHERE email = $1 AND org_id IS NOT DISTINCT FROM $2 AND deleted_at IS NULL",
+        )
+        .bind(email)
+        .bind(org_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            info!("Error finding user by email: {}", e);
+            e
+        })?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "UserAdapter::verify_email",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn verify_email(&self, id: Uuid, messages: &[OutboxMessage]) -> Result<(), Error> {
         let mut transaction = self.pool.begin().await?;
 
         sqlx::query(
@@ -125,6 +221,10 @@ impl UserPort for UserAdapter {
         .execute(&mut *transaction)
         .await?;
 
+        for message in messages {
+            insert_outbox_message(&mut *transaction, message).await?;
+        }
+
         transaction.commit().await?;
 
         info!("Email verified for user: {}", id.to_string());
@@ -132,6 +232,50 @@ impl UserPort for UserAdapter {
         Ok(())
     }
 
+    #[instrument(
+        name = "UserAdapter::set_phone_number",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn set_phone_number(&self, id: Uuid, phone_number: &str) -> Result<(), Error> {
+        sqlx::query(
+            "UPDATE users SET phone_number = $1, phone_verified_at = NULL, updated_at = $2 WHERE id = $3 AND deleted_at IS NULL",
+        )
+        .bind(phone_number)
+        .bind(Utc::now())
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "UserAdapter::verify_phone_number",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn verify_phone_number(&self, id: Uuid) -> Result<(), Error> {
+        sqlx::query(
+            "UPDATE users SET phone_verified_at = $1, updated_at = $1 WHERE id = $2 AND deleted_at IS NULL",
+        )
+        .bind(Utc::now())
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        info!("Phone number verified for user: {}", id);
+
+        Ok(())
+    }
+
+    #[instrument(
+        name = "UserAdapter::update_password",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
     async fn update_password(&self, id: Uuid, data: &User) -> Result<(), Error> {
         let mut transaction = self.pool.begin().await?;
 
@@ -0,0 +1,146 @@
+use crate::domain::entity::user_identity::UserIdentity;
+use crate::domain::port::db::user_identity_port::UserIdentityPort;
+use crate::domain::port::db_port::DbPort;
+use async_trait::async_trait;
+use sqlx::Error;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct UserIdentityAdapter {
+    pub pool: sqlx::PgPool,
+}
+
+impl UserIdentityAdapter {
+    pub fn new(pool: sqlx::PgPool) -> Self {
+        UserIdentityAdapter { pool }
+    }
+}
+
+#[async_trait]
+impl DbPort<UserIdentity> for UserIdentityAdapter {
+    #[instrument(
+        name = "UserIdentityAdapter::save",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn save(&self, data: &UserIdentity) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO user_identities (id, user_id, provider, subject, email, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)",
+        )
+        .bind(data.id)
+        .bind(data.user_id)
+        .bind(&data.provider)
+        .bind(&data.subject)
+        .bind(&data.email)
+        .bind(data.created_at)
+        .bind(data.updated_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "UserIdentityAdapter::find_by_id",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<UserIdentity>, Error> {
+        let result = sqlx::query_as::<_, UserIdentity>(
+            "SELECT id, user_id, provider, subject, email, created_at, updated_at, deleted_at FROM user_identities WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "UserIdentityAdapter::find_by_coll",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_coll(&self, coll: &str, value: &str) -> Result<Option<UserIdentity>, Error> {
+        let query = match coll {
+            "user_id" => {
+                "SELECT id, user_id, provider, subject, email, created_at, updated_at, deleted_at FROM user_identities WHERE user_id = $1::uuid AND deleted_at IS NULL"
+            }
+            "email" => {
+                "SELECT id, user_id, provider, subject, email, created_at, updated_at, deleted_at FROM user_identities WHERE email = $1 AND deleted_at IS NULL"
+            }
+            "id" => {
+                "SELECT id, user_id, provider, subject, email, created_at, updated_at, deleted_at FROM user_identities WHERE id = $1::uuid AND deleted_at IS NULL"
+            }
+            _ => return Err(Error::RowNotFound),
+        };
+
+        let result = sqlx::query_as::<_, UserIdentity>(query)
+            .bind(value)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "UserIdentityAdapter::update",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn update(&self, id: Uuid, data: &UserIdentity) -> Result<(), Error> {
+        sqlx::query(
+            "UPDATE user_identities
+             SET email = $1, updated_at = $2
+             WHERE id = $3 AND deleted_at IS NULL",
+        )
+        .bind(&data.email)
+        .bind(data.updated_at)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "UserIdentityAdapter::delete",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn delete(&self, id: Uuid) -> Result<(), Error> {
+        let query = sqlx::query("DELETE FROM user_identities WHERE id = $1").bind(id);
+
+        query.execute(&self.pool).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UserIdentityPort for UserIdentityAdapter {
+    #[instrument(
+        name = "UserIdentityAdapter::find_by_provider_subject",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_provider_subject(
+        &self,
+        provider: &str,
+        subject: &str,
+    ) -> Result<Option<UserIdentity>, Error> {
+        let result = sqlx::query_as::<_, UserIdentity>(
+            "SELECT id, user_id, provider, subject, email, created_at, updated_at, deleted_at FROM user_identities WHERE provider = $1 AND subject = $2 AND deleted_at IS NULL",
+        )
+        .bind(provider)
+        .bind(subject)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+}
@@ -1,33 +1,53 @@
 use crate::domain::entity::user_info::UserInfo;
+use crate::domain::port::db::user_info_port::UserInfoPort;
 use crate::domain::port::db_port::DbPort;
 use async_trait::async_trait;
 use sqlx::Error;
+use tracing::instrument;
 use uuid::Uuid;
 
 pub struct UserInfoAdapter {
     pub pool: sqlx::PgPool,
 }
 
+impl UserInfoAdapter {
+    pub fn new(pool: sqlx::PgPool) -> Self {
+        UserInfoAdapter { pool }
+    }
+}
+
 #[async_trait]
 impl DbPort<UserInfo> for UserInfoAdapter {
+    #[instrument(
+        name = "UserInfoAdapter::save",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
     async fn save(&self, data: &UserInfo) -> Result<(), Error> {
         sqlx::query(
             "INSERT INTO user_info (id, user_id, first_name, last_name, gender, birth_date, created_at, updated_at) 
-            VALUES ($1, $2, $3, $4, $5, $6, $7)",
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
         )
-        .bind(&data.id)
-        .bind(&data.user_id)
+        .bind(data.id)
+        .bind(data.user_id)
         .bind(&data.first_name)
         .bind(&data.last_name)
         .bind(&data.gender)
-        .bind(&data.birth_date)
-        .bind(&data.created_at)
-        .bind(&data.updated_at)
+        .bind(data.birth_date)
+        .bind(data.created_at)
+        .bind(data.updated_at)
         .execute(&self.pool)
         .await?;
         Ok(())
     }
 
+    #[instrument(
+        name = "UserInfoAdapter::find_by_id",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
     async fn find_by_id(&self, id: Uuid) -> Result<Option<UserInfo>, Error> {
         let result = sqlx::query_as::<_, UserInfo>(
             "SELECT * FROM user_info WHERE id = $1 AND deleted_at IS NULL",
@@ -39,13 +59,19 @@ impl DbPort<UserInfo> for UserInfoAdapter {
         Ok(result)
     }
 
+    #[instrument(
+        name = "UserInfoAdapter::find_by_coll",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
     async fn find_by_coll(&self, coll: &str, value: &str) -> Result<Option<UserInfo>, Error> {
         let query = match coll {
-            "user_id" => "SELECT * FROM user_info WHERE user_id = $1 AND deleted_at IS NULL",
+            "user_id" => "SELECT * FROM user_info WHERE user_id = $1::uuid AND deleted_at IS NULL",
             "first_name" => "SELECT * FROM user_info WHERE first_name = $1 AND deleted_at IS NULL",
             "last_name" => "SELECT * FROM user_info WHERE last_name = $1 AND deleted_at IS NULL",
             "gender" => "SELECT * FROM user_info WHERE gender = $1 AND deleted_at IS NULL",
-            "id" => "SELECT * FROM user_info WHERE id = $1 AND deleted_at IS NULL",
+            "id" => "SELECT * FROM user_info WHERE id = $1::uuid AND deleted_at IS NULL",
             &_ => return Err(Error::RowNotFound),
         };
 
@@ -57,6 +83,12 @@ impl DbPort<UserInfo> for UserInfoAdapter {
         Ok(result)
     }
 
+    #[instrument(
+        name = "UserInfoAdapter::update",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
     async fn update(&self, id: Uuid, data: &UserInfo) -> Result<(), Error> {
         sqlx::query(
             "UPDATE user_info
@@ -71,6 +103,12 @@ impl DbPort<UserInfo> for UserInfoAdapter {
         Ok(())
     }
 
+    #[instrument(
+        name = "UserInfoAdapter::delete",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
     async fn delete(&self, id: Uuid) -> Result<(), Error> {
         let query = sqlx::query("DELETE FROM user_info WHERE id = $1").bind(id);
 
@@ -78,3 +116,6 @@ impl DbPort<UserInfo> for UserInfoAdapter {
         Ok(())
     }
 }
+
+#[async_trait]
+impl UserInfoPort for UserInfoAdapter {}
@@ -2,6 +2,7 @@ use crate::domain::entity::user_security::UserSecurity;
 use crate::domain::port::db_port::DbPort;
 use async_trait::async_trait;
 use sqlx::{Error, Pool, Postgres};
+use tracing::instrument;
 use uuid::Uuid;
 
 pub struct UserSecurityAdapter {
@@ -9,13 +10,19 @@ pub struct UserSecurityAdapter {
 }
 
 impl UserSecurityAdapter {
-    pub(crate) fn new(p: Pool<Postgres>) -> Self {
+    pub fn new(p: Pool<Postgres>) -> Self {
         UserSecurityAdapter { pool: p }
     }
 }
 
 #[async_trait]
 impl DbPort<UserSecurity> for UserSecurityAdapter {
+    #[instrument(
+        name = "UserSecurityAdapter::save",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
     async fn save(&self, data: &UserSecurity) -> Result<(), Error> {
         sqlx::query(
             "INSERT INTO user_security (id, user_id, mfa_secret_key, email_verified_at, last_password_change, account_locked_until, created_at, updated_at)\
@@ -34,6 +41,12 @@ impl DbPort<UserSecurity> for UserSecurityAdapter {
         Ok(())
     }
 
+    #[instrument(
+        name = "UserSecurityAdapter::find_by_id",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
     async fn find_by_id(&self, id: Uuid) -> Result<Option<UserSecurity>, Error> {
         let result = sqlx::query_as::<_, UserSecurity>(
             "SELECT id, user_id, mfa_secret_key, email_verified_at, last_password_change, created_at, updated_at FROM user_security WHERE id = $1 AND deleted_at IS NULL",
@@ -45,6 +58,12 @@ impl DbPort<UserSecurity> for UserSecurityAdapter {
         Ok(result)
     }
 
+    #[instrument(
+        name = "UserSecurityAdapter::find_by_coll",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
     async fn find_by_coll(&self, coll: &str, value: &str) -> Result<Option<UserSecurity>, Error> {
         let query = match coll {
             "user_id" => {
@@ -63,16 +82,22 @@ impl DbPort<UserSecurity> for UserSecurityAdapter {
         Ok(result)
     }
 
+    #[instrument(
+        name = "UserSecurityAdapter::update",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
     async fn update(&self, id: Uuid, data: &UserSecurity) -> Result<(), Error> {
         sqlx::query(
             "UPDATE user_security
             SET email_verified_at = $1, last_password_change = $2, account_locked_until = $3, updated_at = $4, mfa_secret_key = $5 \
             WHERE id = $6 AND deleted_at IS NULL",
         )
-        .bind(&data.email_verified_at)
-        .bind(&data.last_password_change)
+        .bind(data.email_verified_at)
+        .bind(data.last_password_change)
         .bind(data.account_locked_until)
-        .bind(&data.updated_at)
+        .bind(data.updated_at)
         .bind(&data.mfa_secret_key)
         .bind(id)
         .execute(&self.pool)
@@ -80,6 +105,12 @@ impl DbPort<UserSecurity> for UserSecurityAdapter {
         Ok(())
     }
 
+    #[instrument(
+        name = "UserSecurityAdapter::delete",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
     async fn delete(&self, id: Uuid) -> Result<(), Error> {
         let query = sqlx::query("DELETE FROM user_security WHERE id = $1").bind(id);
 
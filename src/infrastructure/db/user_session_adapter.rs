@@ -2,6 +2,7 @@ use crate::domain::entity::user_sessions::UserSessions;
 use crate::domain::port::db_port::DbPort;
 use async_trait::async_trait;
 use sqlx::Error;
+use tracing::instrument;
 use uuid::Uuid;
 
 pub struct UserSessionAdapter {
@@ -16,6 +17,12 @@ impl UserSessionAdapter {
 
 #[async_trait]
 impl DbPort<UserSessions> for UserSessionAdapter {
+    #[instrument(
+        name = "UserSessionAdapter::save",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
     async fn save(&self, data: &UserSessions) -> Result<(), Error> {
         sqlx::query(
             "INSERT INTO user_sessions (id, user_id, login_ip, login_device, login_location, created_at, updated_at)
@@ -33,6 +40,12 @@ impl DbPort<UserSessions> for UserSessionAdapter {
         Ok(())
     }
 
+    #[instrument(
+        name = "UserSessionAdapter::find_by_id",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
     async fn find_by_id(&self, id: Uuid) -> Result<Option<UserSessions>, Error> {
         let result = sqlx::query_as::<_, UserSessions>(
             "SELECT id, user_id, login_ip, login_device, login_location, created_at, updated_at FROM user_sessions WHERE id = $1 AND deleted_at IS NULL",
@@ -44,6 +57,12 @@ impl DbPort<UserSessions> for UserSessionAdapter {
         Ok(result)
     }
 
+    #[instrument(
+        name = "UserSessionAdapter::find_by_coll",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
     async fn find_by_coll(&self, coll: &str, value: &str) -> Result<Option<UserSessions>, Error> {
         let query = match coll {
             "login_device" => {
@@ -68,6 +87,12 @@ impl DbPort<UserSessions> for UserSessionAdapter {
         Ok(result)
     }
 
+    #[instrument(
+        name = "UserSessionAdapter::update",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
     async fn update(&self, id: Uuid, data: &UserSessions) -> Result<(), Error> {
         sqlx::query(
             "UPDATE user_sessions
@@ -84,6 +109,12 @@ impl DbPort<UserSessions> for UserSessionAdapter {
         Ok(())
     }
 
+    #[instrument(
+        name = "UserSessionAdapter::delete",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
     async fn delete(&self, id: Uuid) -> Result<(), Error> {
         let query = sqlx::query("DELETE FROM user_sessions WHERE id = $1").bind(id);
 
@@ -0,0 +1,83 @@
+use crate::domain::entity::webhook::WebhookDelivery;
+use crate::domain::port::db::webhook_delivery_port::WebhookDeliveryPort;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Error, Postgres, QueryBuilder};
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct WebhookDeliveryAdapter {
+    pub pool: sqlx::PgPool,
+}
+
+impl WebhookDeliveryAdapter {
+    pub fn new(pool: sqlx::PgPool) -> Self {
+        WebhookDeliveryAdapter { pool }
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryPort for WebhookDeliveryAdapter {
+    #[instrument(
+        name = "WebhookDeliveryAdapter::record",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn record(&self, delivery: &WebhookDelivery) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO webhook_deliveries (id, subscription_id, event_id, event, attempt, status_code, error, duration_ms, created_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+        )
+        .bind(delivery.id)
+        .bind(delivery.subscription_id)
+        .bind(delivery.event_id)
+        .bind(&delivery.event)
+        .bind(delivery.attempt)
+        .bind(delivery.status_code)
+        .bind(&delivery.error)
+        .bind(delivery.duration_ms)
+        .bind(delivery.created_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "WebhookDeliveryAdapter::list_by_subscription",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn list_by_subscription(
+        &self,
+        subscription_id: Uuid,
+        before: Option<(DateTime<Utc>, Uuid)>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, Error> {
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "SELECT id, subscription_id, event_id, event, attempt, status_code, error, duration_ms, created_at FROM webhook_deliveries WHERE subscription_id = ",
+        );
+        query.push_bind(subscription_id);
+
+        if let Some((created_at, id)) = before {
+            query
+                .push(" AND (created_at, id) < (")
+                .push_bind(created_at)
+                .push(", ")
+                .push_bind(id)
+                .push(")");
+        }
+
+        query
+            .push(" ORDER BY created_at DESC, id DESC LIMIT ")
+            .push_bind(limit);
+
+        let result = query
+            .build_query_as::<WebhookDelivery>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+}
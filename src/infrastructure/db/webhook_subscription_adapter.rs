@@ -0,0 +1,175 @@
+use crate::domain::entity::webhook::WebhookSubscription;
+use crate::domain::port::db::webhook_subscription_port::WebhookSubscriptionPort;
+use crate::domain::port::db_port::DbPort;
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::Error;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct WebhookSubscriptionAdapter {
+    pub pool: sqlx::PgPool,
+}
+
+impl WebhookSubscriptionAdapter {
+    pub fn new(pool: sqlx::PgPool) -> Self {
+        WebhookSubscriptionAdapter { pool }
+    }
+}
+
+#[async_trait]
+impl DbPort<WebhookSubscription> for WebhookSubscriptionAdapter {
+    #[instrument(
+        name = "WebhookSubscriptionAdapter::save",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn save(&self, data: &WebhookSubscription) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO webhook_subscriptions (id, url, secret, events, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6)",
+        )
+        .bind(data.id)
+        .bind(&data.url)
+        .bind(&data.secret)
+        .bind(&data.events)
+        .bind(data.created_at)
+        .bind(data.updated_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "WebhookSubscriptionAdapter::find_by_id",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<WebhookSubscription>, Error> {
+        let result = sqlx::query_as::<_, WebhookSubscription>(
+            "SELECT id, url, secret, events, created_at, updated_at, deleted_at FROM webhook_subscriptions WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "WebhookSubscriptionAdapter::find_by_coll",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn find_by_coll(
+        &self,
+        coll: &str,
+        value: &str,
+    ) -> Result<Option<WebhookSubscription>, Error> {
+        let query = match coll {
+            "id" => {
+                "SELECT id, url, secret, events, created_at, updated_at, deleted_at FROM webhook_subscriptions WHERE id = $1::uuid AND deleted_at IS NULL"
+            }
+            _ => return Err(Error::RowNotFound),
+        };
+
+        let result = sqlx::query_as::<_, WebhookSubscription>(query)
+            .bind(value)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "WebhookSubscriptionAdapter::update",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn update(&self, id: Uuid, data: &WebhookSubscription) -> Result<(), Error> {
+        sqlx::query(
+            "UPDATE webhook_subscriptions
+             SET url = $1, events = $2, updated_at = $3
+             WHERE id = $4 AND deleted_at IS NULL",
+        )
+        .bind(&data.url)
+        .bind(&data.events)
+        .bind(data.updated_at)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "WebhookSubscriptionAdapter::delete",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn delete(&self, id: Uuid) -> Result<(), Error> {
+        let query = sqlx::query("DELETE FROM webhook_subscriptions WHERE id = $1").bind(id);
+
+        query.execute(&self.pool).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl WebhookSubscriptionPort for WebhookSubscriptionAdapter {
+    #[instrument(
+        name = "WebhookSubscriptionAdapter::list_active",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn list_active(&self) -> Result<Vec<WebhookSubscription>, Error> {
+        let result = sqlx::query_as::<_, WebhookSubscription>(
+            "SELECT id, url, secret, events, created_at, updated_at, deleted_at FROM webhook_subscriptions WHERE deleted_at IS NULL ORDER BY created_at DESC",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "WebhookSubscriptionAdapter::list_for_event",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn list_for_event(&self, event: &str) -> Result<Vec<WebhookSubscription>, Error> {
+        let result = sqlx::query_as::<_, WebhookSubscription>(
+            "SELECT id, url, secret, events, created_at, updated_at, deleted_at FROM webhook_subscriptions WHERE $1 = ANY(events) AND deleted_at IS NULL",
+        )
+        .bind(event)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(
+        name = "WebhookSubscriptionAdapter::remove",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "postgresql")
+    )]
+    async fn remove(&self, id: Uuid) -> Result<bool, Error> {
+        let result = sqlx::query(
+            "UPDATE webhook_subscriptions SET deleted_at = $1, updated_at = $1
+             WHERE id = $2 AND deleted_at IS NULL",
+        )
+        .bind(Utc::now())
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
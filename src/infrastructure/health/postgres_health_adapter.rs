@@ -0,0 +1,25 @@
+use crate::domain::port::health_check_port::HealthCheckPort;
+use async_trait::async_trait;
+use std::error::Error;
+
+pub struct PostgresHealthAdapter {
+    pub pool: sqlx::PgPool,
+}
+
+impl PostgresHealthAdapter {
+    pub fn new(pool: sqlx::PgPool) -> Self {
+        PostgresHealthAdapter { pool }
+    }
+}
+
+#[async_trait]
+impl HealthCheckPort for PostgresHealthAdapter {
+    fn name(&self) -> &'static str {
+        "postgres"
+    }
+
+    async fn check(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        sqlx::query("SELECT 1").execute(&self.pool).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use crate::config::redis::RedisClient;
+use crate::domain::port::health_check_port::HealthCheckPort;
+use async_trait::async_trait;
+use std::error::Error;
+
+pub struct RedisHealthAdapter {
+    pub redis: RedisClient,
+}
+
+impl RedisHealthAdapter {
+    pub fn new(redis: RedisClient) -> Self {
+        RedisHealthAdapter { redis }
+    }
+}
+
+#[async_trait]
+impl HealthCheckPort for RedisHealthAdapter {
+    fn name(&self) -> &'static str {
+        "redis"
+    }
+
+    async fn check(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let mut conn = self.redis.client.get_multiplexed_tokio_connection().await?;
+        let _: String = redis::cmd("PING").query_async(&mut conn).await?;
+        Ok(())
+    }
+}
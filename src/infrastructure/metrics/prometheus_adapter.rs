@@ -0,0 +1,206 @@
+use crate::domain::port::health_check_port::HealthCheckPort;
+use crate::domain::port::metrics_port::MetricsPort;
+use prometheus::core::Collector;
+use prometheus::{
+    Encoder, Gauge, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge, IntGaugeVec,
+    Opts, Registry, TextEncoder,
+};
+use sqlx::PgPool;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::error;
+
+/// Request, business and dependency metrics in a registry of their own, rendered in the
+/// Prometheus text format for `/metrics`.
+pub struct PrometheusAdapter {
+    registry: Registry,
+    rpc_requests: IntCounterVec,
+    rpc_duration: HistogramVec,
+    registrations: IntCounter,
+    logins_succeeded: IntCounterVec,
+    logins_failed: IntCounterVec,
+    otps_sent: IntCounterVec,
+    lockouts: IntCounter,
+    db_connections: IntGaugeVec,
+    db_max_connections: IntGauge,
+    redis_up: IntGauge,
+    redis_ping: Gauge,
+    pool: PgPool,
+    redis: Arc<dyn HealthCheckPort>,
+    redis_timeout: Duration,
+}
+
+fn register<C: Collector + Clone + 'static>(
+    registry: &Registry,
+    collector: C,
+) -> Result<C, prometheus::Error> {
+    registry.register(Box::new(collector.clone()))?;
+    Ok(collector)
+}
+
+impl PrometheusAdapter {
+    /// `redis` is pinged with `redis_timeout` on every scrape to fill the Redis gauges.
+    pub fn new(
+        pool: PgPool,
+        redis: Arc<dyn HealthCheckPort>,
+        redis_timeout: Duration,
+    ) -> Result<Self, prometheus::Error> {
+        let registry = Registry::new();
+
+        let adapter = PrometheusAdapter {
+            rpc_requests: register(
+                &registry,
+                IntCounterVec::new(
+                    Opts::new(
+                        "grpc_server_handled_total",
+                        "gRPC calls completed, by method and status code",
+                    ),
+                    &["grpc_method", "grpc_code"],
+                )?,
+            )?,
+            rpc_duration: register(
+                &registry,
+                HistogramVec::new(
+                    HistogramOpts::new(
+                        "grpc_server_handling_seconds",
+                        "Time until a gRPC call is answered, by method",
+                    ),
+                    &["grpc_method"],
+                )?,
+            )?,
+            registrations: register(
+                &registry,
+                IntCounter::new("auth_registrations_total", "Accounts registered")?,
+            )?,
+            logins_succeeded: register(
+                &registry,
+                IntCounterVec::new(
+                    Opts::new(
+                        "auth_logins_succeeded_total",
+                        "Successful logins, by method",
+                    ),
+                    &["method"],
+                )?,
+            )?,
+            logins_failed: register(
+                &registry,
+                IntCounterVec::new(
+                    Opts::new("auth_logins_failed_total", "Refused logins, by reason"),
+                    &["reason"],
+                )?,
+            )?,
+            otps_sent: register(
+                &registry,
+                IntCounterVec::new(
+                    Opts::new("auth_otps_sent_total", "One-time codes sent, by channel"),
+                    &["channel"],
+                )?,
+            )?,
+            lockouts: register(
+                &registry,
+                IntCounter::new(
+                    "auth_lockouts_total",
+                    "Sign-ins refused because the account is suspended or banned",
+                )?,
+            )?,
+            db_connections: register(
+                &registry,
+                IntGaugeVec::new(
+                    Opts::new("db_pool_connections", "Postgres pool connections, by state"),
+                    &["state"],
+                )?,
+            )?,
+            db_max_connections: register(
+                &registry,
+                IntGauge::new(
+                    "db_pool_max_connections",
+                    "Most connections the Postgres pool may open",
+                )?,
+            )?,
+            redis_up: register(
+                &registry,
+                IntGauge::new("redis_up", "Whether Redis answered the last ping")?,
+            )?,
+            redis_ping: register(
+                &registry,
+                Gauge::new("redis_ping_seconds", "Round trip of the last Redis ping")?,
+            )?,
+            registry,
+            pool,
+            redis,
+            redis_timeout,
+        };
+
+        // Export every known series from the start so rates work before the first event.
+        for method in ["password", "passkey", "external"] {
+            adapter.logins_succeeded.with_label_values(&[method]);
+        }
+        for reason in ["invalid_credentials", "inactive", "locked"] {
+            adapter.logins_failed.with_label_values(&[reason]);
+        }
+        for channel in ["email", "sms"] {
+            adapter.otps_sent.with_label_values(&[channel]);
+        }
+
+        Ok(adapter)
+    }
+
+    pub fn record_rpc(&self, method: &str, code: &str, elapsed: Duration) {
+        self.rpc_requests.with_label_values(&[method, code]).inc();
+        self.rpc_duration
+            .with_label_values(&[method])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    async fn refresh_gauges(&self) {
+        let size = self.pool.size() as i64;
+        let idle = self.pool.num_idle() as i64;
+        self.db_connections.with_label_values(&["idle"]).set(idle);
+        self.db_connections
+            .with_label_values(&["in_use"])
+            .set(size - idle);
+        self.db_max_connections
+            .set(self.pool.options().get_max_connections() as i64);
+
+        let start = Instant::now();
+        let up = matches!(
+            tokio::time::timeout(self.redis_timeout, self.redis.check()).await,
+            Ok(Ok(()))
+        );
+        self.redis_up.set(up as i64);
+        self.redis_ping.set(start.elapsed().as_secs_f64());
+    }
+
+    /// Refreshes the pool and Redis gauges and renders every metric.
+    pub async fn render(&self) -> String {
+        self.refresh_gauges().await;
+
+        let mut buffer = Vec::new();
+        if let Err(e) = TextEncoder::new().encode(&self.registry.gather(), &mut buffer) {
+            error!("Failed to encode metrics: {}", e);
+        }
+        String::from_utf8(buffer).unwrap_or_default()
+    }
+}
+
+impl MetricsPort for PrometheusAdapter {
+    fn user_registered(&self) {
+        self.registrations.inc();
+    }
+
+    fn login_succeeded(&self, method: &str) {
+        self.logins_succeeded.with_label_values(&[method]).inc();
+    }
+
+    fn login_failed(&self, reason: &str) {
+        self.logins_failed.with_label_values(&[reason]).inc();
+    }
+
+    fn otp_sent(&self, channel: &str) {
+        self.otps_sent.with_label_values(&[channel]).inc();
+    }
+
+    fn account_locked(&self) {
+        self.lockouts.inc();
+    }
+}
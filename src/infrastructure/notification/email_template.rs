@@ -0,0 +1,157 @@
+use crate::config::env::EnvConfig;
+use crate::domain::port::notification_port::Notification;
+use lettre::Message;
+use lettre::message::MultiPart;
+use std::error::Error;
+use tera::{Context, Tera};
+use tracing::{error, info};
+
+/// Every email has a `.subject`, `.html` and `.txt` template per locale, named
+/// `<locale>/<email>.<part>`.
+macro_rules! embedded_templates {
+    ($($name:literal),* $(,)?) => {
+        &[$(($name, include_str!(concat!("../../util/template/email/", $name)))),*]
+    };
+}
+
+const EMBEDDED_TEMPLATES: &[(&str, &str)] = embedded_templates!(
+    "en/otp.subject",
+    "en/otp.html",
+    "en/otp.txt",
+    "en/invitation.subject",
+    "en/invitation.html",
+    "en/invitation.txt",
+    "id/otp.subject",
+    "id/otp.html",
+    "id/otp.txt",
+    "id/invitation.subject",
+    "id/invitation.html",
+    "id/invitation.txt",
+);
+
+/// An email ready to be handed to a transport.
+#[derive(Debug, Clone)]
+pub struct RenderedEmail {
+    pub recipient: String,
+    pub subject: String,
+    pub html_body: String,
+    pub text_body: String,
+}
+
+/// Renders notifications into emails. Shared by every email transport.
+pub struct EmailRenderer {
+    templates: Tera,
+    default_locale: String,
+    app_name: String,
+    from: String,
+}
+
+/// Embedded templates, overridden file by file by `EMAIL_TEMPLATE_DIR` when it is set. The
+/// override directory may also add locales that are not embedded.
+fn load_templates(template_dir: Option<&str>) -> Tera {
+    let mut embedded = Tera::default();
+    embedded
+        .add_raw_templates(EMBEDDED_TEMPLATES.iter().copied())
+        .expect("Embedded email templates must be valid");
+
+    let Some(dir) = template_dir else {
+        return embedded;
+    };
+
+    let mut templates = match Tera::new(&format!("{}/**/*", dir.trim_end_matches('/'))) {
+        Ok(templates) => templates,
+        Err(e) => {
+            error!("Failed to load email templates from {}: {}", dir, e);
+            panic!("Template loading failed");
+        }
+    };
+    // Templates already loaded from the override directory win over the embedded ones.
+    templates
+        .extend(&embedded)
+        .expect("Embedded email templates must be valid");
+
+    info!("Email templates loaded with overrides from {}", dir);
+    templates
+}
+
+impl EmailRenderer {
+    pub fn new(config: &EnvConfig) -> Self {
+        EmailRenderer {
+            templates: load_templates(config.email_template_dir.as_deref()),
+            default_locale: config.email_default_locale.clone(),
+            app_name: config.app_name.clone(),
+            from: config.smtp_from.clone(),
+        }
+    }
+
+    /// The requested locale when a template exists for it, otherwise `EMAIL_DEFAULT_LOCALE`.
+    fn resolve_locale<'a>(&'a self, locale: Option<&'a str>, email: &str) -> &'a str {
+        locale
+            .filter(|locale| {
+                self.templates
+                    .get_template(&format!("{}/{}.html", locale, email))
+                    .is_ok()
+            })
+            .unwrap_or(self.default_locale.as_str())
+    }
+
+    pub fn render(&self, notification: &Notification) -> Result<RenderedEmail, tera::Error> {
+        let mut context = Context::new();
+
+        let (email, locale) = match notification {
+            Notification::OtpEmail {
+                otp_code, locale, ..
+            } => {
+                context.insert("otp_code", otp_code);
+                ("otp", locale)
+            }
+            Notification::InvitationEmail {
+                organization,
+                inviter,
+                invitation_link,
+                locale,
+                ..
+            } => {
+                context.insert("organization", organization);
+                context.insert("inviter", inviter);
+                context.insert("invitation_link", invitation_link);
+                ("invitation", locale)
+            }
+        };
+        context.insert("app_name", &self.app_name);
+
+        let locale = self.resolve_locale(locale.as_deref(), email);
+        let render = |part: &str| {
+            let template = format!("{}/{}.{}", locale, email, part);
+            self.templates.render(&template, &context).map_err(|e| {
+                error!("Failed to render {} template: {}", template, e);
+                e
+            })
+        };
+
+        Ok(RenderedEmail {
+            recipient: notification.recipient().to_string(),
+            subject: render("subject")?.trim().to_string(),
+            html_body: render("html")?,
+            text_body: render("txt")?,
+        })
+    }
+
+    /// Builds the multipart HTML and plain-text message sent by SMTP and written by the
+    /// file-drop transport.
+    pub fn build_message(
+        &self,
+        email: RenderedEmail,
+    ) -> Result<Message, Box<dyn Error + Send + Sync>> {
+        let message = Message::builder()
+            .from(self.from.parse()?)
+            .to(email.recipient.parse()?)
+            .subject(email.subject)
+            .multipart(MultiPart::alternative_plain_html(
+                email.text_body,
+                email.html_body,
+            ))?;
+
+        Ok(message)
+    }
+}
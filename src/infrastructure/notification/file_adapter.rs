@@ -0,0 +1,49 @@
+use crate::domain::port::notification_port::{Notification, NotificationPort};
+use crate::infrastructure::notification::email_template::EmailRenderer;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::error::Error;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+/// Writes every email as an `.eml` file instead of sending it, for local development.
+pub struct FileAdapter {
+    dir: PathBuf,
+    renderer: Arc<EmailRenderer>,
+}
+
+impl FileAdapter {
+    pub fn new(dir: impl Into<PathBuf>, renderer: Arc<EmailRenderer>) -> Self {
+        FileAdapter {
+            dir: dir.into(),
+            renderer,
+        }
+    }
+}
+
+#[async_trait]
+impl NotificationPort for FileAdapter {
+    async fn send(&self, notification: &Notification) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let message = self
+            .renderer
+            .build_message(self.renderer.render(notification)?)?;
+
+        tokio::fs::create_dir_all(&self.dir).await?;
+
+        let path = self.dir.join(format!(
+            "{}-{}.eml",
+            Utc::now().format("%Y%m%dT%H%M%S"),
+            Uuid::new_v4()
+        ));
+        tokio::fs::write(&path, message.formatted()).await?;
+
+        info!(
+            "Email to {} written to {}",
+            notification.recipient(),
+            path.display()
+        );
+        Ok(())
+    }
+}
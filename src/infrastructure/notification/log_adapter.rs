@@ -0,0 +1,32 @@
+use crate::domain::port::notification_port::{Notification, NotificationPort};
+use crate::infrastructure::notification::email_template::EmailRenderer;
+use async_trait::async_trait;
+use std::error::Error;
+use std::sync::Arc;
+use tracing::{debug, info};
+
+/// Logs every rendered email instead of sending it. Bodies carry codes and invitation links,
+/// so they only appear at debug level.
+pub struct LogAdapter {
+    renderer: Arc<EmailRenderer>,
+}
+
+impl LogAdapter {
+    pub fn new(renderer: Arc<EmailRenderer>) -> Self {
+        LogAdapter { renderer }
+    }
+}
+
+#[async_trait]
+impl NotificationPort for LogAdapter {
+    async fn send(&self, notification: &Notification) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let email = self.renderer.render(notification)?;
+
+        info!(
+            "Email to {} with subject {:?}",
+            email.recipient, email.subject
+        );
+        debug!("Email body for {}:\n{}", email.recipient, email.html_body);
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+use crate::domain::port::sms_port::SmsPort;
+use async_trait::async_trait;
+use std::error::Error;
+use tracing::{debug, info};
+
+/// Logs every SMS instead of sending it. The body carries the code, so it is only logged at
+/// debug level.
+pub struct LogSmsAdapter;
+
+#[async_trait]
+impl SmsPort for LogSmsAdapter {
+    async fn send_sms(
+        &self,
+        phone_number: &str,
+        body: &str,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        info!("SMS to {}", phone_number);
+        debug!("SMS body for {}: {}", phone_number, body);
+        Ok(())
+    }
+}
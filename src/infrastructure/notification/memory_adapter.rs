@@ -0,0 +1,35 @@
+use crate::domain::port::notification_port::{Notification, NotificationPort};
+use async_trait::async_trait;
+use std::error::Error;
+use std::sync::Mutex;
+
+/// Keeps sent notifications in memory so tests can inspect them without a mail server.
+#[derive(Default)]
+pub struct MemoryAdapter {
+    sent: Mutex<Vec<Notification>>,
+}
+
+impl MemoryAdapter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn sent(&self) -> Vec<Notification> {
+        self.sent.lock().unwrap().clone()
+    }
+
+    pub fn sent_to(&self, recipient: &str) -> Vec<Notification> {
+        self.sent()
+            .into_iter()
+            .filter(|notification| notification.recipient() == recipient)
+            .collect()
+    }
+}
+
+#[async_trait]
+impl NotificationPort for MemoryAdapter {
+    async fn send(&self, notification: &Notification) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.sent.lock().unwrap().push(notification.clone());
+        Ok(())
+    }
+}
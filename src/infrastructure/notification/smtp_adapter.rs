@@ -0,0 +1,78 @@
+use crate::config::env::EnvConfig;
+use crate::domain::port::health_check_port::HealthCheckPort;
+use crate::domain::port::notification_port::{Notification, NotificationPort};
+use crate::infrastructure::notification::email_template::EmailRenderer;
+use async_trait::async_trait;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::transport::smtp::client::{Tls, TlsParameters};
+use lettre::{AsyncSmtpTransport, AsyncTransport, Tokio1Executor};
+use std::error::Error;
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+pub struct SmtpAdapter {
+    mailer: AsyncSmtpTransport<Tokio1Executor>,
+    renderer: Arc<EmailRenderer>,
+}
+
+impl SmtpAdapter {
+    pub fn new(config: &EnvConfig, renderer: Arc<EmailRenderer>) -> Self {
+        if config.email_host.is_empty() {
+            panic!("EMAIL_HOST must be set when NOTIFICATION_TRANSPORT is smtp");
+        }
+
+        let creds = Credentials::new(config.email_user.clone(), config.email_password.clone());
+
+        let tls_parameters = TlsParameters::builder(config.email_host.clone())
+            .build()
+            .expect("Failed to build TLS parameters");
+
+        let mailer = AsyncSmtpTransport::<Tokio1Executor>::relay(&config.email_host)
+            .expect("Failed to create SMTP transport")
+            .port(config.email_port)
+            .credentials(creds)
+            .tls(Tls::Required(tls_parameters))
+            .build();
+
+        info!(
+            "SMTP transport initialized with host: {}",
+            config.email_host
+        );
+
+        SmtpAdapter { mailer, renderer }
+    }
+}
+
+#[async_trait]
+impl NotificationPort for SmtpAdapter {
+    #[instrument(
+        name = "SmtpAdapter::send",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client")
+    )]
+    async fn send(&self, notification: &Notification) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let message = self
+            .renderer
+            .build_message(self.renderer.render(notification)?)?;
+
+        info!("Sending email to: {}", notification.recipient());
+
+        self.mailer.send(message).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl HealthCheckPort for SmtpAdapter {
+    fn name(&self) -> &'static str {
+        "smtp"
+    }
+
+    async fn check(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        if !self.mailer.test_connection().await? {
+            return Err("SMTP server rejected the connection".into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use crate::domain::port::sms_port::SmsPort;
+use async_trait::async_trait;
+use serde::Serialize;
+use std::error::Error;
+use tracing::info;
+
+#[derive(Serialize)]
+struct SmsWebhookRequest<'a> {
+    to: &'a str,
+    body: &'a str,
+}
+
+/// Posts `{"to": ..., "body": ...}` to an SMS gateway webhook, optionally with a bearer token.
+pub struct WebhookSmsAdapter {
+    client: reqwest::Client,
+    url: String,
+    token: Option<String>,
+}
+
+impl WebhookSmsAdapter {
+    pub fn new(url: String, token: Option<String>) -> Self {
+        WebhookSmsAdapter {
+            client: reqwest::Client::new(),
+            url,
+            token,
+        }
+    }
+}
+
+#[async_trait]
+impl SmsPort for WebhookSmsAdapter {
+    async fn send_sms(
+        &self,
+        phone_number: &str,
+        body: &str,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let mut request = self.client.post(&self.url).json(&SmsWebhookRequest {
+            to: phone_number,
+            body,
+        });
+        if let Some(token) = &self.token {
+            request = request.bearer_auth(token);
+        }
+
+        request.send().await?.error_for_status()?;
+
+        info!("SMS sent to: {}", phone_number);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::SmsGateway;
+    use axum::http::StatusCode;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn posts_the_message_with_the_bearer_token() {
+        let gateway = SmsGateway::start(StatusCode::OK).await;
+        let adapter =
+            WebhookSmsAdapter::new(gateway.url.clone(), Some("gateway-token".to_string()));
+
+        adapter
+            .send_sms("+6281234567890", "Your code is 482913")
+            .await
+            .unwrap();
+
+        let received = gateway.received();
+        assert_eq!(received.len(), 1);
+        assert_eq!(
+            received[0].body,
+            json!({"to": "+6281234567890", "body": "Your code is 482913"})
+        );
+        assert_eq!(
+            received[0].authorization.as_deref(),
+            Some("Bearer gateway-token")
+        );
+    }
+
+    #[tokio::test]
+    async fn non_success_replies_are_errors() {
+        let gateway = SmsGateway::start(StatusCode::SERVICE_UNAVAILABLE).await;
+        let adapter = WebhookSmsAdapter::new(gateway.url.clone(), None);
+
+        let error = adapter
+            .send_sms("+6281234567890", "Your code is 482913")
+            .await
+            .unwrap_err();
+
+        assert!(error.to_string().contains("503"), "{}", error);
+        assert_eq!(gateway.received()[0].authorization, None);
+    }
+}
@@ -0,0 +1,334 @@
+use crate::config::env::{EnvConfig, OidcProviderConfig};
+use crate::domain::port::external_identity_port::{ExternalIdentity, ExternalIdentityPort};
+use async_trait::async_trait;
+use jsonwebtoken::jwk::{Jwk, JwkSet};
+use jsonwebtoken::{DecodingKey, Validation, decode, decode_header};
+use reqwest::Url;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::error::Error;
+use tokio::sync::{OnceCell, RwLock};
+use tracing::info;
+
+#[derive(Debug, Deserialize)]
+struct Discovery {
+    authorization_endpoint: String,
+    token_endpoint: String,
+    userinfo_endpoint: String,
+    jwks_uri: String,
+}
+
+#[derive(Debug, Clone)]
+struct Endpoints {
+    authorization: String,
+    token: String,
+    userinfo: String,
+    /// Only discovered providers publish keys; plain OAuth2 providers issue no ID tokens.
+    jwks: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    id_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IdTokenClaims {
+    sub: String,
+    nonce: Option<String>,
+}
+
+struct OidcProvider {
+    config: OidcProviderConfig,
+    endpoints: OnceCell<Endpoints>,
+    jwks: RwLock<Option<JwkSet>>,
+}
+
+impl OidcProvider {
+    /// Whether the provider speaks OpenID Connect and so must return a verifiable ID token.
+    fn issues_id_tokens(&self, endpoints: &Endpoints) -> bool {
+        endpoints.jwks.is_some() && self.config.scopes.split(' ').any(|scope| scope == "openid")
+    }
+}
+
+pub struct OidcAdapter {
+    http: reqwest::Client,
+    redirect_url: String,
+    user_agent: String,
+    providers: HashMap<String, OidcProvider>,
+}
+
+impl OidcAdapter {
+    pub fn new(config: &EnvConfig) -> Self {
+        let providers = config
+            .oidc_providers
+            .iter()
+            .map(|provider| {
+                info!("OIDC provider registered: {}", provider.name);
+                (
+                    provider.name.clone(),
+                    OidcProvider {
+                        config: provider.clone(),
+                        endpoints: OnceCell::new(),
+                        jwks: RwLock::new(None),
+                    },
+                )
+            })
+            .collect();
+
+        OidcAdapter {
+            http: reqwest::Client::new(),
+            redirect_url: config.oidc_redirect_url.clone(),
+            user_agent: config.app_name.clone(),
+            providers,
+        }
+    }
+
+    fn provider(&self, name: &str) -> Result<&OidcProvider, Box<dyn Error + Send + Sync>> {
+        self.providers
+            .get(name)
+            .ok_or_else(|| format!("Unknown identity provider: {}", name).into())
+    }
+
+    async fn endpoints<'a>(
+        &self,
+        provider: &'a OidcProvider,
+    ) -> Result<&'a Endpoints, Box<dyn Error + Send + Sync>> {
+        provider
+            .endpoints
+            .get_or_try_init(|| async {
+                let config = &provider.config;
+                let Some(issuer) = config.issuer.as_deref() else {
+                    // Validation requires every endpoint when there is no issuer.
+                    return Ok(Endpoints {
+                        authorization: config.auth_url.clone().unwrap_or_default(),
+                        token: config.token_url.clone().unwrap_or_default(),
+                        userinfo: config.userinfo_url.clone().unwrap_or_default(),
+                        jwks: None,
+                    });
+                };
+                let discovery_url = format!(
+                    "{}/.well-known/openid-configuration",
+                    issuer.trim_end_matches('/')
+                );
+
+                // Discovery runs even when every endpoint is overridden, for the JWKS location.
+                let discovered: Discovery = self
+                    .http
+                    .get(&discovery_url)
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?;
+
+                info!("OIDC discovery completed for provider: {}", config.name);
+
+                Ok(Endpoints {
+                    authorization: config
+                        .auth_url
+                        .clone()
+                        .unwrap_or(discovered.authorization_endpoint),
+                    token: config
+                        .token_url
+                        .clone()
+                        .unwrap_or(discovered.token_endpoint),
+                    userinfo: config
+                        .userinfo_url
+                        .clone()
+                        .unwrap_or(discovered.userinfo_endpoint),
+                    jwks: Some(discovered.jwks_uri),
+                })
+            })
+            .await
+    }
+
+    /// Finds the key an ID token names in the cached JWKS, refetching it once when the key is
+    /// unknown so issuer key rotation is picked up without a restart.
+    async fn signing_key(
+        &self,
+        provider: &OidcProvider,
+        jwks_uri: &str,
+        kid: Option<&str>,
+    ) -> Result<Jwk, Box<dyn Error + Send + Sync>> {
+        let find = |jwks: &JwkSet| match kid {
+            Some(kid) => jwks.find(kid).cloned(),
+            None => jwks.keys.first().cloned(),
+        };
+
+        if let Some(jwk) = provider.jwks.read().await.as_ref().and_then(find) {
+            return Ok(jwk);
+        }
+
+        let jwks: JwkSet = self
+            .http
+            .get(jwks_uri)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        info!("JWKS refreshed for provider: {}", provider.config.name);
+
+        let jwk = find(&jwks);
+        *provider.jwks.write().await = Some(jwks);
+        jwk.ok_or_else(|| "ID token is signed with an unknown key".into())
+    }
+
+    /// Checks an ID token's signature against the issuer's JWKS, its issuer and audience, and
+    /// that it carries the nonce sent with the authorization request, returning its subject.
+    async fn verify_id_token(
+        &self,
+        provider: &OidcProvider,
+        jwks_uri: &str,
+        id_token: &str,
+        nonce: &str,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let header = decode_header(id_token)?;
+        let jwk = self
+            .signing_key(provider, jwks_uri, header.kid.as_deref())
+            .await?;
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_audience(&[&provider.config.client_id]);
+        if let Some(issuer) = &provider.config.issuer {
+            validation.set_issuer(&[issuer.trim_end_matches('/')]);
+        }
+
+        let claims = decode::<IdTokenClaims>(id_token, &DecodingKey::from_jwk(&jwk)?, &validation)?;
+        if claims.claims.nonce.as_deref() != Some(nonce) {
+            return Err("ID token was not issued for this login".into());
+        }
+        Ok(claims.claims.sub)
+    }
+}
+
+#[async_trait]
+impl ExternalIdentityPort for OidcAdapter {
+    fn has_provider(&self, provider: &str) -> bool {
+        self.providers.contains_key(provider)
+    }
+
+    async fn authorization_url(
+        &self,
+        provider: &str,
+        state: &str,
+        nonce: &str,
+        code_challenge: &str,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let provider = self.provider(provider)?;
+        let endpoints = self.endpoints(provider).await?;
+
+        let url = Url::parse_with_params(
+            &endpoints.authorization,
+            &[
+                ("response_type", "code"),
+                ("client_id", provider.config.client_id.as_str()),
+                ("redirect_uri", self.redirect_url.as_str()),
+                ("scope", provider.config.scopes.as_str()),
+                ("state", state),
+                ("nonce", nonce),
+                ("code_challenge", code_challenge),
+                ("code_challenge_method", "S256"),
+            ],
+        )?;
+
+        Ok(url.to_string())
+    }
+
+    async fn exchange_code(
+        &self,
+        provider: &str,
+        code: &str,
+        code_verifier: &str,
+        nonce: &str,
+    ) -> Result<ExternalIdentity, Box<dyn Error + Send + Sync>> {
+        let provider = self.provider(provider)?;
+        let endpoints = self.endpoints(provider).await?;
+
+        let token: TokenResponse = self
+            .http
+            .post(&endpoints.token)
+            .header(reqwest::header::ACCEPT, "application/json")
+            .form(&[
+                ("grant_type", "authorization_code"),
+                ("code", code),
+                ("redirect_uri", self.redirect_url.as_str()),
+                ("client_id", provider.config.client_id.as_str()),
+                ("client_secret", provider.config.client_secret.as_str()),
+                ("code_verifier", code_verifier),
+            ])
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        let claims: Value = self
+            .http
+            .get(&endpoints.userinfo)
+            .header(reqwest::header::ACCEPT, "application/json")
+            .header(reqwest::header::USER_AGENT, self.user_agent.as_str())
+            .bearer_auth(&token.access_token)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        // OIDC providers return `sub`; plain OAuth2 providers such as GitHub return a numeric `id`.
+        let subject = match claims.get("sub").or_else(|| claims.get("id")) {
+            Some(Value::String(s)) => s.clone(),
+            Some(Value::Number(n)) => n.to_string(),
+            _ => return Err("Identity provider did not return a subject".into()),
+        };
+
+        // OpenID Connect providers also return an ID token; it must be genuine, answer this
+        // login's nonce and name the same subject as userinfo (OIDC Core 3.1.3.7, 5.3.2).
+        match (&token.id_token, &endpoints.jwks) {
+            (Some(id_token), Some(jwks_uri)) => {
+                let id_subject = self
+                    .verify_id_token(provider, jwks_uri, id_token, nonce)
+                    .await?;
+                if id_subject != subject {
+                    return Err("ID token and userinfo name different subjects".into());
+                }
+            }
+            (Some(_), None) => {
+                return Err("Identity provider returned an ID token but publishes no JWKS".into());
+            }
+            (None, _) if provider.issues_id_tokens(endpoints) => {
+                return Err("Identity provider did not return an ID token".into());
+            }
+            (None, _) => {}
+        }
+
+        let email = claims
+            .get("email")
+            .and_then(Value::as_str)
+            .map(str::to_lowercase);
+
+        let email_verified = provider.config.trust_email
+            || match claims.get("email_verified") {
+                Some(Value::Bool(b)) => *b,
+                Some(Value::String(s)) => s == "true",
+                _ => false,
+            };
+
+        let name = claims
+            .get("name")
+            .or_else(|| claims.get("login"))
+            .and_then(Value::as_str)
+            .map(String::from);
+
+        Ok(ExternalIdentity {
+            provider: provider.config.name.clone(),
+            subject,
+            email,
+            email_verified,
+            name,
+        })
+    }
+}
@@ -0,0 +1,110 @@
+use crate::config::redis::RedisClient;
+use crate::domain::entity::account_event::AccountEvent;
+use crate::domain::port::account_event_port::{AccountEventPort, AccountEventStream};
+use async_trait::async_trait;
+use futures::StreamExt;
+use redis::AsyncCommands;
+use redis::aio::ConnectionManager;
+use std::error::Error;
+use tokio::sync::OnceCell;
+use tracing::{instrument, warn};
+use uuid::Uuid;
+
+fn channel(user_id: Uuid) -> String {
+    format!("account_events:{}", user_id)
+}
+
+fn decode(payload: &str) -> Option<AccountEvent> {
+    serde_json::from_str(payload)
+        .map_err(|e| warn!("Dropping malformed account event: {}", e))
+        .ok()
+}
+
+/// Redis pub/sub, one channel per user. Events are not persisted; only live watchers see them.
+pub struct RedisAccountEventAdapter {
+    pub redis: RedisClient,
+    /// Shared by every publish and opened on the first one; it reconnects on its own.
+    publisher: OnceCell<ConnectionManager>,
+}
+
+impl RedisAccountEventAdapter {
+    pub fn new(redis: RedisClient) -> Self {
+        RedisAccountEventAdapter {
+            redis,
+            publisher: OnceCell::new(),
+        }
+    }
+
+    async fn publisher(&self) -> redis::RedisResult<ConnectionManager> {
+        self.publisher
+            .get_or_try_init(|| ConnectionManager::new(self.redis.client.clone()))
+            .await
+            .cloned()
+    }
+}
+
+#[async_trait]
+impl AccountEventPort for RedisAccountEventAdapter {
+    #[instrument(
+        name = "RedisAccountEventAdapter::publish",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "redis")
+    )]
+    async fn publish(&self, event: &AccountEvent) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let payload = serde_json::to_string(event)?;
+        let mut conn = self.publisher().await?;
+        let _: i64 = conn.publish(channel(event.user_id), payload).await?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "RedisAccountEventAdapter::subscribe",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "redis")
+    )]
+    async fn subscribe(
+        &self,
+        user_id: Uuid,
+    ) -> Result<AccountEventStream, Box<dyn Error + Send + Sync>> {
+        let mut pubsub = self.redis.client.get_async_pubsub().await?;
+        pubsub.subscribe(channel(user_id)).await?;
+
+        let events = pubsub.into_on_message().filter_map(|msg| async move {
+            let payload: String = msg.get_payload().ok()?;
+            decode(&payload)
+        });
+
+        Ok(Box::pin(events))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entity::account_event::AccountEventKind;
+
+    #[test]
+    fn every_user_has_a_channel_of_their_own() {
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+
+        assert_eq!(channel(alice), format!("account_events:{}", alice));
+        assert_ne!(channel(alice), channel(bob));
+    }
+
+    #[test]
+    fn published_payloads_decode_and_malformed_ones_are_dropped() {
+        let event = AccountEvent::new(Uuid::new_v4(), AccountEventKind::Login)
+            .with_detail("password")
+            .from_client("203.0.113.7", "curl/8.0");
+
+        let decoded = decode(&serde_json::to_string(&event).unwrap()).unwrap();
+
+        assert_eq!(decoded.id, event.id);
+        assert_eq!(decoded.user_id, event.user_id);
+        assert_eq!(decoded.kind, "login");
+        assert_eq!(decoded.ip, "203.0.113.7");
+        assert!(decode(r#"{"kind":"login"}"#).is_none());
+    }
+}
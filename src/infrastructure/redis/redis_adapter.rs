@@ -2,6 +2,7 @@ use crate::config::redis::RedisClient;
 use crate::domain::port::redis_port::RedisPort;
 use redis::{AsyncCommands, RedisResult};
 use tonic::Status;
+use tracing::instrument;
 use tracing::log::error;
 
 pub struct RedisAdapter {
@@ -9,45 +10,97 @@ pub struct RedisAdapter {
 }
 
 impl RedisAdapter {
-    pub fn new() -> Self {
-        let redis = RedisClient::new().expect("Failed to create Redis client");
+    pub fn new(redis: RedisClient) -> Self {
         RedisAdapter { redis }
     }
 }
 
 #[async_trait::async_trait]
 impl RedisPort for RedisAdapter {
+    #[instrument(
+        name = "RedisAdapter::set_value",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "redis")
+    )]
     async fn set_value(&self, key: &str, value: &str) -> RedisResult<()> {
         let mut conn = self.redis.client.get_multiplexed_tokio_connection().await?;
         conn.set(key, value).await
     }
 
+    #[instrument(
+        name = "RedisAdapter::set_value_with_ttl",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "redis")
+    )]
+    async fn set_value_with_ttl(&self, key: &str, value: &str, ttl_secs: u64) -> RedisResult<()> {
+        let mut conn = self.redis.client.get_multiplexed_tokio_connection().await?;
+        conn.set_ex(key, value, ttl_secs).await
+    }
+
+    #[instrument(
+        name = "RedisAdapter::get_value",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "redis")
+    )]
     async fn get_value(&self, key: &str) -> RedisResult<Option<String>> {
         let mut conn = self.redis.client.get_multiplexed_tokio_connection().await?;
         conn.get(key).await
     }
 
+    #[instrument(
+        name = "RedisAdapter::delete_value",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "redis")
+    )]
     async fn delete_value(&self, key: &str) -> RedisResult<()> {
         let mut conn = self.redis.client.get_multiplexed_tokio_connection().await?;
         conn.del(key).await
     }
 
+    #[instrument(
+        name = "RedisAdapter::exists",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "redis")
+    )]
     async fn exists(&self, key: &str) -> RedisResult<Option<bool>> {
         let mut conn = self.redis.client.get_multiplexed_tokio_connection().await?;
         let result: i32 = conn.exists(key).await?;
         Ok(Some(result > 0))
     }
 
+    #[instrument(
+        name = "RedisAdapter::pull_value",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "redis")
+    )]
     async fn pull_value(&self, key: &str) -> RedisResult<Option<String>> {
         let mut conn = self.redis.client.get_multiplexed_tokio_connection().await?;
         conn.get_del(key).await
     }
 
+    #[instrument(
+        name = "RedisAdapter::blacklist_token",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "redis")
+    )]
     async fn blacklist_token(&self, token: &str) -> RedisResult<()> {
         let mut conn = self.redis.client.get_multiplexed_tokio_connection().await?;
         conn.set(token, "BLACKLISTED").await
     }
 
+    #[instrument(
+        name = "RedisAdapter::ensure_not_blacklisted",
+        level = "debug",
+        skip_all,
+        fields(otel.kind = "client", db.system = "redis")
+    )]
     async fn ensure_not_blacklisted(&self, token: &str) -> Result<(), Status> {
         let mut conn = self
             .redis
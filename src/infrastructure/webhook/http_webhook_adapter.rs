@@ -0,0 +1,89 @@
+use crate::domain::port::webhook_sender_port::WebhookSenderPort;
+use async_trait::async_trait;
+use reqwest::header::CONTENT_TYPE;
+use std::error::Error;
+use std::time::Duration;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub struct HttpWebhookAdapter {
+    client: reqwest::Client,
+}
+
+impl HttpWebhookAdapter {
+    pub fn new() -> Self {
+        HttpWebhookAdapter {
+            client: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .expect("Failed to build webhook HTTP client"),
+        }
+    }
+}
+
+impl Default for HttpWebhookAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl WebhookSenderPort for HttpWebhookAdapter {
+    async fn post(
+        &self,
+        url: &str,
+        headers: &[(&str, String)],
+        body: &str,
+    ) -> Result<u16, Box<dyn Error + Send + Sync>> {
+        let mut request = self
+            .client
+            .post(url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.to_string());
+        for (name, value) in headers {
+            request = request.header(*name, value);
+        }
+
+        let response = request.send().await?;
+        Ok(response.status().as_u16())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::WebhookReceiver;
+    use axum::http::StatusCode;
+
+    #[tokio::test]
+    async fn posts_the_body_as_json_with_the_given_headers() {
+        let receiver = WebhookReceiver::start(StatusCode::NO_CONTENT).await;
+
+        let status = HttpWebhookAdapter::new()
+            .post(
+                &receiver.url,
+                &[("x-webhook-event", "user.registered".to_string())],
+                r#"{"type":"user.registered"}"#,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(status, 204);
+        let received = &receiver.received()[0];
+        assert_eq!(received.body, r#"{"type":"user.registered"}"#);
+        assert_eq!(received.headers[CONTENT_TYPE], "application/json");
+        assert_eq!(received.headers["x-webhook-event"], "user.registered");
+    }
+
+    #[tokio::test]
+    async fn error_statuses_are_returned_not_raised() {
+        let receiver = WebhookReceiver::start(StatusCode::INTERNAL_SERVER_ERROR).await;
+
+        let status = HttpWebhookAdapter::new()
+            .post(&receiver.url, &[], "{}")
+            .await
+            .unwrap();
+
+        assert_eq!(status, 500);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::util::util::primary_language_tag;
 use serde::{Deserialize, Serialize};
 use std::net::SocketAddr;
 use tonic::Request;
@@ -24,14 +25,22 @@ pub fn get_device_info<T>(request: &Request<T>) -> Option<String> {
         .and_then(|ua| ua.to_str().ok().map(String::from))
 }
 
+/// The caller's language from `x-locale`, falling back to `accept-language`.
+pub fn get_locale<T>(request: &Request<T>) -> Option<String> {
+    ["x-locale", "accept-language"].iter().find_map(|key| {
+        request
+            .metadata()
+            .get(*key)
+            .and_then(|value| value.to_str().ok())
+            .and_then(primary_language_tag)
+    })
+}
+
 pub async fn get_location(ip: &str) -> Option<GeoLocation> {
     let url = format!("https://ipapi.co/{}/json/", ip);
 
     match reqwest::get(&url).await {
-        Ok(response) => match response.json::<GeoLocation>().await {
-            Ok(geo_info) => Some(geo_info),
-            Err(_) => None,
-        },
+        Ok(response) => response.json::<GeoLocation>().await.ok(),
         Err(_) => None,
     }
 }
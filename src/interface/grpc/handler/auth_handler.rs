@@ -1,44 +1,264 @@
+use crate::application::account_event_use_case::{AccountEventFeed, AccountEventUseCase};
+use crate::application::api_key_use_case::ApiKeyUseCase;
+use crate::application::audit_use_case::{AuditContext, AuditUseCase};
 use crate::application::auth_use_case::AuthUseCase;
+use crate::application::client_credentials_use_case::ClientCredentialsUseCase;
+use crate::application::invitation_use_case::InvitationUseCase;
+use crate::application::organization_use_case::OrganizationUseCase;
+use crate::application::outbox_use_case::OutboxUseCase;
+use crate::application::webhook_use_case::WebhookUseCase;
+use crate::config::env::EnvConfig;
 use crate::domain::dto::auth_dto::{
-    ForgotPasswordDto, LoginDto, LogoutDto, RegisterDto, SendOtpDto, VerifyEmailDto,
+    AcceptInvitationDto, BeginExternalLoginDto, BeginPasskeyLoginDto, ClientCredentialsTokenDto,
+    CompleteExternalLoginDto, CreateApiKeyDto, CreateOrganizationDto, CreateWebhookSubscriptionDto,
+    DeleteWebhookSubscriptionDto, FinishPasskeyLoginDto, FinishPasskeyRegistrationDto,
+    ForgotPasswordDto, InviteMemberDto, ListAuditEventsDto, ListInvitationsDto,
+    ListOutboxMessagesDto, ListWebhookDeliveriesDto, LoginDto, LogoutDto, RegisterDto,
+    ReplayOutboxMessageDto, RevokeApiKeyDto, RevokeInvitationDto, SendOtpDto,
+    SwitchOrganizationDto, UpdatePhoneNumberDto, VerifyEmailDto, VerifyPhoneNumberDto,
 };
-use crate::domain::entity::user_sessions::UserSessions;
-use crate::domain::port::db::user_port::UserPort;
-use crate::domain::port::db_port::DbPort;
+use crate::domain::entity::audit_event::AuditAction;
+use crate::domain::port::db::api_key_port::ApiKeyPort;
 use crate::domain::port::redis_port::RedisPort;
 use crate::domain::validator::ValidateFromRequest;
 use crate::interface::common::client_info::{
-    GeoLocation, get_client_ip, get_device_info, get_location,
+    GeoLocation, get_client_ip, get_device_info, get_locale, get_location,
 };
 use crate::interface::grpc::interceptor::auth_interceptor::{
-    extract_token_from_metadata, validate_access_token,
+    API_KEY_METADATA_KEY, Principal, authenticate, authorize_client_scope,
+    extract_token_from_metadata, peer_identity, validate_access_token,
 };
 use crate::pb::auth::auth_handler_server::AuthHandler as Handler;
 use crate::pb::auth::{
-    ForgotPasswordRequest, ForgotPasswordResponse, LoginRequest, LoginResponse, LogoutRequest,
-    LogoutResponse, RegisterRequest, RegisterResponse, SendOtpRequest,
+    AcceptInvitationRequest, AcceptInvitationResponse, InviteMemberRequest, InviteMemberResponse,
+    ListInvitationsRequest, ListInvitationsResponse, RevokeInvitationRequest,
+    RevokeInvitationResponse,
+};
+use crate::pb::auth::{
+    BeginExternalLoginRequest, BeginExternalLoginResponse, ClientCredentialsTokenRequest,
+    ClientCredentialsTokenResponse, CompleteExternalLoginRequest, CompleteExternalLoginResponse,
+    CreateApiKeyRequest, CreateApiKeyResponse, ForgotPasswordRequest, ForgotPasswordResponse,
+    ListApiKeysRequest, ListApiKeysResponse, LoginRequest, LoginResponse, LogoutRequest,
+    LogoutResponse, RegisterRequest, RegisterResponse, RevokeApiKeyRequest, RevokeApiKeyResponse,
+    SendOtpRequest,
+};
+use crate::pb::auth::{
+    BeginPasskeyLoginRequest, BeginPasskeyLoginResponse, BeginPasskeyRegistrationRequest,
+    BeginPasskeyRegistrationResponse, FinishPasskeyLoginRequest, FinishPasskeyLoginResponse,
+    FinishPasskeyRegistrationRequest, FinishPasskeyRegistrationResponse,
+};
+use crate::pb::auth::{
+    CreateOrganizationRequest, CreateOrganizationResponse, ListOrganizationsRequest,
+    ListOrganizationsResponse, SwitchOrganizationRequest, SwitchOrganizationResponse,
+};
+use crate::pb::auth::{
+    CreateWebhookSubscriptionRequest, CreateWebhookSubscriptionResponse,
+    DeleteWebhookSubscriptionRequest, DeleteWebhookSubscriptionResponse,
+    ListWebhookDeliveriesRequest, ListWebhookDeliveriesResponse, ListWebhookSubscriptionsRequest,
+    ListWebhookSubscriptionsResponse, WatchAccountEventsRequest,
+};
+use crate::pb::auth::{
+    ListAuditEventsRequest, ListAuditEventsResponse, ListOutboxMessagesRequest,
+    ListOutboxMessagesResponse, ReplayOutboxMessageRequest, ReplayOutboxMessageResponse,
 };
 use crate::pb::auth::{SendOtpResponse, VerifyEmailRequest, VerifyEmailResponse};
+use crate::pb::auth::{
+    UpdatePhoneNumberRequest, UpdatePhoneNumberResponse, VerifyPhoneNumberRequest,
+    VerifyPhoneNumberResponse,
+};
 use std::sync::Arc;
 use tonic::{Request, Response, Status};
 use tracing::error;
+use uuid::Uuid;
+
+/// Scope a service client needs to inspect and replay the outbox.
+const OUTBOX_ADMIN_SCOPE: &str = "outbox:admin";
+/// Scope a service client needs to manage webhook subscriptions.
+const WEBHOOKS_ADMIN_SCOPE: &str = "webhooks:admin";
+
+/// Scopes an API key needs for each RPC it may call. API keys without scopes may call them all;
+/// RPCs that manage credentials or issue tokens only accept access tokens.
+const API_KEYS_READ_SCOPE: &str = "api_keys:read";
+const ORGANIZATIONS_READ_SCOPE: &str = "organizations:read";
+const ORGANIZATIONS_WRITE_SCOPE: &str = "organizations:write";
+const INVITATIONS_READ_SCOPE: &str = "invitations:read";
+const INVITATIONS_WRITE_SCOPE: &str = "invitations:write";
+const AUDIT_READ_SCOPE: &str = "audit:read";
+const ACCOUNT_EVENTS_READ_SCOPE: &str = "account_events:read";
 
 pub struct AuthHandler {
     auth_service: AuthUseCase,
+    client_credentials_service: ClientCredentialsUseCase,
+    api_key_service: ApiKeyUseCase,
+    organization_service: OrganizationUseCase,
+    invitation_service: InvitationUseCase,
+    audit_service: AuditUseCase,
+    outbox_service: OutboxUseCase,
+    webhook_service: WebhookUseCase,
+    account_event_service: AccountEventUseCase,
     redis_port: Arc<dyn RedisPort + Send + Sync>,
+    api_key_port: Arc<dyn ApiKeyPort + Send + Sync>,
+    config: Arc<EnvConfig>,
 }
 
 impl AuthHandler {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
-        port: Arc<dyn UserPort + Send + Sync>,
-        session: Arc<dyn DbPort<UserSessions> + Send + Sync>,
+        auth_service: AuthUseCase,
+        client_credentials_service: ClientCredentialsUseCase,
+        api_key_service: ApiKeyUseCase,
+        organization_service: OrganizationUseCase,
+        invitation_service: InvitationUseCase,
+        audit_service: AuditUseCase,
+        outbox_service: OutboxUseCase,
+        webhook_service: WebhookUseCase,
+        account_event_service: AccountEventUseCase,
         redis_port: Arc<dyn RedisPort + Send + Sync>,
+        api_key_port: Arc<dyn ApiKeyPort + Send + Sync>,
+        config: Arc<EnvConfig>,
     ) -> Self {
         AuthHandler {
-            auth_service: AuthUseCase::new(port, session, redis_port.clone()),
+            auth_service,
+            client_credentials_service,
+            api_key_service,
+            organization_service,
+            invitation_service,
+            audit_service,
+            outbox_service,
+            webhook_service,
+            account_event_service,
             redis_port,
+            api_key_port,
+            config,
+        }
+    }
+}
+
+impl AuthHandler {
+    /// Authenticates the user behind a `Bearer` access token or an `x-api-key` header. API keys
+    /// must have been granted `scope`.
+    async fn authenticated_user<T>(
+        &self,
+        request: &Request<T>,
+        scope: &str,
+    ) -> Result<Uuid, Status> {
+        let principal = authenticate(
+            request.metadata(),
+            &self.redis_port,
+            &self.api_key_port,
+            &self.config.access_secret,
+        )
+        .await?;
+
+        let user_id = match &principal {
+            Principal::User { user_id, .. } => user_id,
+            Principal::ApiKey { user_id, .. } if principal.has_scope(scope) => user_id,
+            Principal::ApiKey { .. } => {
+                return Err(Status::permission_denied(format!(
+                    "API key lacks the {} scope",
+                    scope
+                )));
+            }
+            Principal::Client { .. } => {
+                return Err(Status::permission_denied(
+                    "Client tokens cannot be used for this operation",
+                ));
+            }
+        };
+
+        parse_user_id(user_id)
+    }
+
+    /// Authenticates the user behind a `Bearer` access token only, for RPCs that manage
+    /// credentials or issue tokens.
+    async fn session_user<T>(&self, request: &Request<T>) -> Result<Uuid, Status> {
+        if request.metadata().contains_key(API_KEY_METADATA_KEY) {
+            return Err(Status::permission_denied(
+                "API keys cannot be used for this operation",
+            ));
         }
+
+        let user_id = validate_access_token(
+            request.metadata(),
+            &self.redis_port,
+            &self.config.access_secret,
+        )
+        .await?;
+
+        parse_user_id(&user_id)
     }
+
+    /// Authorizes a service client by its `Bearer` token, or, when none is sent, by a verified
+    /// mutual TLS client certificate.
+    async fn authorized_client<T>(
+        &self,
+        request: &Request<T>,
+        scope: &str,
+    ) -> Result<String, Status> {
+        if !request.metadata().contains_key("authorization")
+            && let Some(identity) = peer_identity(request)
+        {
+            return self
+                .client_credentials_service
+                .authorize_certificate(identity.common_name.as_deref(), scope)
+                .await;
+        }
+
+        authorize_client_scope(
+            request.metadata(),
+            &self.redis_port,
+            &self.config.access_secret,
+            scope,
+        )
+        .await
+    }
+}
+
+fn audit_context<T>(request: &Request<T>) -> AuditContext {
+    AuditContext {
+        ip: get_client_ip(request),
+        device: get_device_info(request),
+        ..AuditContext::default()
+    }
+}
+
+fn parse_uuid(value: &str) -> Option<Uuid> {
+    Uuid::parse_str(value).ok()
+}
+
+fn parse_user_id(user_id: &str) -> Result<Uuid, Status> {
+    Uuid::parse_str(user_id).map_err(|_| {
+        error!("Invalid user id in credentials: {}", user_id);
+        Status::unauthenticated("Invalid token")
+    })
+}
+
+async fn client_context<T>(request: &Request<T>) -> Result<(String, String, GeoLocation), Status> {
+    let ip = get_client_ip(request).ok_or_else(|| {
+        error!("Failed to get client IP");
+        Status::internal("Failed to get client IP")
+    })?;
+
+    let device = get_device_info(request).ok_or_else(|| {
+        error!("Failed to get device info");
+        Status::internal("Failed to get device info")
+    })?;
+
+    let location = match get_location(&ip).await {
+        Some(loc) => loc,
+        None => {
+            error!("Failed to get geolocation for IP: {}", ip);
+            GeoLocation {
+                city: String::new(),
+                country: String::new(),
+                region: String::new(),
+                latitude: 0.0,
+                longitude: 0.0,
+            }
+        }
+    };
+
+    Ok((ip, device, location))
 }
 
 #[tonic::async_trait]
@@ -47,40 +267,45 @@ impl Handler for AuthHandler {
         &self,
         request: Request<RegisterRequest>,
     ) -> Result<Response<RegisterResponse>, Status> {
+        let (email, org_id) = (
+            request.get_ref().email.clone(),
+            request.get_ref().org_id.clone(),
+        );
+        let audit = AuditContext {
+            target: Some(email.clone()),
+            ..audit_context(&request)
+        };
+
+        let locale = get_locale(&request);
+
         let dto = RegisterDto::validate_from_request(request)?;
-        self.auth_service.register(dto).await
+        let result = self.auth_service.register(dto, locale).await;
+        self.audit_service
+            .record_anonymous(AuditAction::Register, audit, &email, &org_id, &result)
+            .await;
+        result
     }
 
     async fn login(
         &self,
         request: Request<LoginRequest>,
     ) -> Result<Response<LoginResponse>, Status> {
-        let ip = get_client_ip(&request).ok_or_else(|| {
-            error!("Failed to get client IP");
-            Status::internal("Failed to get client IP")
-        })?;
-
-        let device = get_device_info(&request).ok_or_else(|| {
-            error!("Failed to get device info");
-            Status::internal("Failed to get device info")
-        })?;
-
-        let location = match get_location(&ip).await {
-            Some(loc) => loc,
-            None => {
-                error!("Failed to get geolocation for IP: {}", ip);
-                GeoLocation {
-                    city: String::new(),
-                    country: String::new(),
-                    region: String::new(),
-                    latitude: 0.0,
-                    longitude: 0.0,
-                }
-            }
+        let (ip, device, location) = client_context(&request).await?;
+        let (email, org_id) = (
+            request.get_ref().email.clone(),
+            request.get_ref().org_id.clone(),
+        );
+        let audit = AuditContext {
+            target: Some(email.clone()),
+            ..audit_context(&request)
         };
 
         let dto = LoginDto::validate_from_request(request)?;
-        self.auth_service.login(dto, ip, device, location).await
+        let result = self.auth_service.login(dto, ip, device, location).await;
+        self.audit_service
+            .record_anonymous(AuditAction::Login, audit, &email, &org_id, &result)
+            .await;
+        result
     }
 
     async fn logout(
@@ -88,7 +313,12 @@ impl Handler for AuthHandler {
         request: Request<LogoutRequest>,
     ) -> Result<Response<LogoutResponse>, Status> {
         let metadata = request.metadata().clone();
-        validate_access_token(&metadata, &self.redis_port).await?;
+        let user_id =
+            validate_access_token(&metadata, &self.redis_port, &self.config.access_secret).await?;
+        let audit = AuditContext {
+            actor_id: parse_uuid(&user_id),
+            ..audit_context(&request)
+        };
 
         let access_token = extract_token_from_metadata(&metadata)
             .map_err(|e| {
@@ -98,30 +328,624 @@ impl Handler for AuthHandler {
             .to_string();
 
         let req = LogoutDto::validate_from_request(request)?;
-        self.auth_service.logout(req, access_token).await
+        let result = self.auth_service.logout(req, access_token).await;
+        self.audit_service
+            .record(AuditAction::Logout, audit, &result)
+            .await;
+        result
     }
 
     async fn send_otp(
         &self,
         request: Request<SendOtpRequest>,
     ) -> Result<Response<SendOtpResponse>, Status> {
+        let (email, org_id) = (
+            request.get_ref().email.clone(),
+            request.get_ref().org_id.clone(),
+        );
+        let audit = AuditContext {
+            target: Some(email.clone()),
+            ..audit_context(&request)
+        };
+
+        let locale = get_locale(&request);
+
         let dto = SendOtpDto::validate_from_request(request)?;
-        self.auth_service.send_otp(dto).await
+        let result = self.auth_service.send_otp(dto, locale).await;
+        self.audit_service
+            .record_anonymous(AuditAction::OtpSend, audit, &email, &org_id, &result)
+            .await;
+        result
     }
 
     async fn verify_email(
         &self,
         request: Request<VerifyEmailRequest>,
     ) -> Result<Response<VerifyEmailResponse>, Status> {
+        let (email, org_id) = (
+            request.get_ref().email.clone(),
+            request.get_ref().org_id.clone(),
+        );
+        let audit = AuditContext {
+            target: Some(email.clone()),
+            ..audit_context(&request)
+        };
+
         let dto = VerifyEmailDto::validate_from_request(request)?;
-        self.auth_service.verify_email(dto).await
+        let result = self.auth_service.verify_email(dto).await;
+        self.audit_service
+            .record_anonymous(AuditAction::OtpVerify, audit, &email, &org_id, &result)
+            .await;
+        result
     }
 
     async fn forgot_password(
         &self,
         request: Request<ForgotPasswordRequest>,
     ) -> Result<Response<ForgotPasswordResponse>, Status> {
+        let (email, org_id) = (
+            request.get_ref().email.clone(),
+            request.get_ref().org_id.clone(),
+        );
+        let audit = AuditContext {
+            target: Some(email.clone()),
+            ..audit_context(&request)
+        };
+
         let dto = ForgotPasswordDto::validate_from_request(request)?;
-        self.auth_service.forgot_password(dto).await
+        let result = self.auth_service.forgot_password(dto).await;
+        self.audit_service
+            .record_anonymous(AuditAction::PasswordReset, audit, &email, &org_id, &result)
+            .await;
+        result
+    }
+
+    async fn begin_external_login(
+        &self,
+        request: Request<BeginExternalLoginRequest>,
+    ) -> Result<Response<BeginExternalLoginResponse>, Status> {
+        let dto = BeginExternalLoginDto::validate_from_request(request)?;
+        self.auth_service.begin_external_login(dto).await
+    }
+
+    async fn complete_external_login(
+        &self,
+        request: Request<CompleteExternalLoginRequest>,
+    ) -> Result<Response<CompleteExternalLoginResponse>, Status> {
+        let (ip, device, location) = client_context(&request).await?;
+        let audit = audit_context(&request);
+
+        let dto = CompleteExternalLoginDto::validate_from_request(request)?;
+        let result = self
+            .auth_service
+            .complete_external_login(dto, ip, device, location)
+            .await;
+        self.audit_service
+            .record(AuditAction::ExternalLogin, audit, &result)
+            .await;
+        result
+    }
+
+    async fn client_credentials_token(
+        &self,
+        request: Request<ClientCredentialsTokenRequest>,
+    ) -> Result<Response<ClientCredentialsTokenResponse>, Status> {
+        let audit = AuditContext {
+            target: Some(request.get_ref().client_id.clone()),
+            ..audit_context(&request)
+        };
+
+        let dto = ClientCredentialsTokenDto::validate_from_request(request)?;
+        let result = self.client_credentials_service.issue_token(dto).await;
+        self.audit_service
+            .record(AuditAction::ClientToken, audit, &result)
+            .await;
+        result
+    }
+
+    async fn create_api_key(
+        &self,
+        request: Request<CreateApiKeyRequest>,
+    ) -> Result<Response<CreateApiKeyResponse>, Status> {
+        let user_id = self.session_user(&request).await?;
+        let audit = AuditContext {
+            actor_id: Some(user_id),
+            target: Some(request.get_ref().name.clone()),
+            ..audit_context(&request)
+        };
+
+        let dto = CreateApiKeyDto::validate_from_request(request)?;
+        let result = self.api_key_service.create_api_key(user_id, dto).await;
+        self.audit_service
+            .record(AuditAction::ApiKeyCreate, audit, &result)
+            .await;
+        result
+    }
+
+    async fn list_api_keys(
+        &self,
+        request: Request<ListApiKeysRequest>,
+    ) -> Result<Response<ListApiKeysResponse>, Status> {
+        let user_id = self
+            .authenticated_user(&request, API_KEYS_READ_SCOPE)
+            .await?;
+
+        self.api_key_service.list_api_keys(user_id).await
+    }
+
+    async fn revoke_api_key(
+        &self,
+        request: Request<RevokeApiKeyRequest>,
+    ) -> Result<Response<RevokeApiKeyResponse>, Status> {
+        let user_id = self.session_user(&request).await?;
+        let audit = AuditContext {
+            actor_id: Some(user_id),
+            target: Some(request.get_ref().id.clone()),
+            ..audit_context(&request)
+        };
+
+        let dto = RevokeApiKeyDto::validate_from_request(request)?;
+        let result = self.api_key_service.revoke_api_key(user_id, dto).await;
+        self.audit_service
+            .record(AuditAction::ApiKeyRevoke, audit, &result)
+            .await;
+        result
+    }
+
+    async fn begin_passkey_registration(
+        &self,
+        request: Request<BeginPasskeyRegistrationRequest>,
+    ) -> Result<Response<BeginPasskeyRegistrationResponse>, Status> {
+        let user_id = self.session_user(&request).await?;
+
+        self.auth_service.begin_passkey_registration(user_id).await
+    }
+
+    async fn finish_passkey_registration(
+        &self,
+        request: Request<FinishPasskeyRegistrationRequest>,
+    ) -> Result<Response<FinishPasskeyRegistrationResponse>, Status> {
+        let user_id = self.session_user(&request).await?;
+        let audit = AuditContext {
+            actor_id: Some(user_id),
+            target: Some(request.get_ref().name.clone()),
+            ..audit_context(&request)
+        };
+
+        let dto = FinishPasskeyRegistrationDto::validate_from_request(request)?;
+        let result = self
+            .auth_service
+            .finish_passkey_registration(user_id, dto)
+            .await;
+        self.audit_service
+            .record(AuditAction::PasskeyRegister, audit, &result)
+            .await;
+        result
+    }
+
+    async fn begin_passkey_login(
+        &self,
+        request: Request<BeginPasskeyLoginRequest>,
+    ) -> Result<Response<BeginPasskeyLoginResponse>, Status> {
+        let dto = BeginPasskeyLoginDto::validate_from_request(request)?;
+        self.auth_service.begin_passkey_login(dto).await
+    }
+
+    async fn finish_passkey_login(
+        &self,
+        request: Request<FinishPasskeyLoginRequest>,
+    ) -> Result<Response<FinishPasskeyLoginResponse>, Status> {
+        let (ip, device, location) = client_context(&request).await?;
+        let audit = audit_context(&request);
+
+        let dto = FinishPasskeyLoginDto::validate_from_request(request)?;
+        let result = self
+            .auth_service
+            .finish_passkey_login(dto, ip, device, location)
+            .await;
+        self.audit_service
+            .record(AuditAction::PasskeyLogin, audit, &result)
+            .await;
+        result
+    }
+
+    async fn create_organization(
+        &self,
+        request: Request<CreateOrganizationRequest>,
+    ) -> Result<Response<CreateOrganizationResponse>, Status> {
+        let user_id = self
+            .authenticated_user(&request, ORGANIZATIONS_WRITE_SCOPE)
+            .await?;
+        let audit = AuditContext {
+            actor_id: Some(user_id),
+            target: Some(request.get_ref().slug.clone()),
+            ..audit_context(&request)
+        };
+
+        let dto = CreateOrganizationDto::validate_from_request(request)?;
+        let result = self
+            .organization_service
+            .create_organization(user_id, dto)
+            .await;
+        self.audit_service
+            .record(AuditAction::OrganizationCreate, audit, &result)
+            .await;
+        result
+    }
+
+    async fn list_organizations(
+        &self,
+        request: Request<ListOrganizationsRequest>,
+    ) -> Result<Response<ListOrganizationsResponse>, Status> {
+        let user_id = self
+            .authenticated_user(&request, ORGANIZATIONS_READ_SCOPE)
+            .await?;
+
+        self.organization_service.list_organizations(user_id).await
+    }
+
+    async fn switch_organization(
+        &self,
+        request: Request<SwitchOrganizationRequest>,
+    ) -> Result<Response<SwitchOrganizationResponse>, Status> {
+        let user_id = self.session_user(&request).await?;
+        let (ip, device, location) = client_context(&request).await?;
+        let audit = AuditContext {
+            actor_id: Some(user_id),
+            org_id: parse_uuid(&request.get_ref().org_id),
+            ..audit_context(&request)
+        };
+
+        let dto = SwitchOrganizationDto::validate_from_request(request)?;
+        let result = self
+            .auth_service
+            .switch_organization(user_id, dto, ip, device, location)
+            .await;
+        self.audit_service
+            .record(AuditAction::OrganizationSwitch, audit, &result)
+            .await;
+        result
+    }
+
+    async fn invite_member(
+        &self,
+        request: Request<InviteMemberRequest>,
+    ) -> Result<Response<InviteMemberResponse>, Status> {
+        let user_id = self
+            .authenticated_user(&request, INVITATIONS_WRITE_SCOPE)
+            .await?;
+        let audit = AuditContext {
+            actor_id: Some(user_id),
+            target: Some(request.get_ref().email.clone()),
+            org_id: parse_uuid(&request.get_ref().org_id),
+            ..audit_context(&request)
+        };
+
+        let locale = get_locale(&request);
+
+        let dto = InviteMemberDto::validate_from_request(request)?;
+        let result = self
+            .invitation_service
+            .invite_member(user_id, dto, locale)
+            .await;
+        self.audit_service
+            .record(AuditAction::InvitationCreate, audit, &result)
+            .await;
+        result
+    }
+
+    async fn list_invitations(
+        &self,
+        request: Request<ListInvitationsRequest>,
+    ) -> Result<Response<ListInvitationsResponse>, Status> {
+        let user_id = self
+            .authenticated_user(&request, INVITATIONS_READ_SCOPE)
+            .await?;
+
+        let dto = ListInvitationsDto::validate_from_request(request)?;
+        self.invitation_service.list_invitations(user_id, dto).await
+    }
+
+    async fn revoke_invitation(
+        &self,
+        request: Request<RevokeInvitationRequest>,
+    ) -> Result<Response<RevokeInvitationResponse>, Status> {
+        let user_id = self
+            .authenticated_user(&request, INVITATIONS_WRITE_SCOPE)
+            .await?;
+        let audit = AuditContext {
+            actor_id: Some(user_id),
+            target: Some(request.get_ref().id.clone()),
+            ..audit_context(&request)
+        };
+
+        let dto = RevokeInvitationDto::validate_from_request(request)?;
+        let result = self
+            .invitation_service
+            .revoke_invitation(user_id, dto)
+            .await;
+        self.audit_service
+            .record(AuditAction::InvitationRevoke, audit, &result)
+            .await;
+        result
+    }
+
+    async fn accept_invitation(
+        &self,
+        request: Request<AcceptInvitationRequest>,
+    ) -> Result<Response<AcceptInvitationResponse>, Status> {
+        let audit = audit_context(&request);
+
+        let dto = AcceptInvitationDto::validate_from_request(request)?;
+        let result = self.invitation_service.accept_invitation(dto).await;
+        let audit = match &result {
+            Ok(response) => AuditContext {
+                target: response
+                    .get_ref()
+                    .data
+                    .as_ref()
+                    .and_then(|data| data.user.as_ref())
+                    .map(|user| user.email.clone()),
+                org_id: response
+                    .get_ref()
+                    .data
+                    .as_ref()
+                    .and_then(|data| parse_uuid(&data.org_id)),
+                ..audit
+            },
+            Err(_) => audit,
+        };
+        self.audit_service
+            .record(AuditAction::InvitationAccept, audit, &result)
+            .await;
+        result
+    }
+
+    async fn list_audit_events(
+        &self,
+        request: Request<ListAuditEventsRequest>,
+    ) -> Result<Response<ListAuditEventsResponse>, Status> {
+        let user_id = self.authenticated_user(&request, AUDIT_READ_SCOPE).await?;
+
+        let dto = ListAuditEventsDto::validate_from_request(request)?;
+        self.audit_service.list_audit_events(user_id, dto).await
+    }
+
+    async fn list_outbox_messages(
+        &self,
+        request: Request<ListOutboxMessagesRequest>,
+    ) -> Result<Response<ListOutboxMessagesResponse>, Status> {
+        self.authorized_client(&request, OUTBOX_ADMIN_SCOPE).await?;
+
+        let dto = ListOutboxMessagesDto::validate_from_request(request)?;
+        self.outbox_service.list_outbox_messages(dto).await
+    }
+
+    async fn replay_outbox_message(
+        &self,
+        request: Request<ReplayOutboxMessageRequest>,
+    ) -> Result<Response<ReplayOutboxMessageResponse>, Status> {
+        self.authorized_client(&request, OUTBOX_ADMIN_SCOPE).await?;
+
+        let dto = ReplayOutboxMessageDto::validate_from_request(request)?;
+        self.outbox_service.replay_outbox_message(dto).await
+    }
+
+    async fn update_phone_number(
+        &self,
+        request: Request<UpdatePhoneNumberRequest>,
+    ) -> Result<Response<UpdatePhoneNumberResponse>, Status> {
+        let user_id = self.session_user(&request).await?;
+        let audit = AuditContext {
+            actor_id: Some(user_id),
+            target: Some(request.get_ref().phone_number.clone()),
+            ..audit_context(&request)
+        };
+
+        let dto = UpdatePhoneNumberDto::validate_from_request(request)?;
+        let result = self.auth_service.update_phone_number(user_id, dto).await;
+        self.audit_service
+            .record(AuditAction::PhoneUpdate, audit, &result)
+            .await;
+        result
+    }
+
+    async fn verify_phone_number(
+        &self,
+        request: Request<VerifyPhoneNumberRequest>,
+    ) -> Result<Response<VerifyPhoneNumberResponse>, Status> {
+        let user_id = self.session_user(&request).await?;
+        let audit = AuditContext {
+            actor_id: Some(user_id),
+            ..audit_context(&request)
+        };
+
+        let dto = VerifyPhoneNumberDto::validate_from_request(request)?;
+        let result = self.auth_service.verify_phone_number(user_id, dto).await;
+        self.audit_service
+            .record(AuditAction::PhoneVerify, audit, &result)
+            .await;
+        result
+    }
+
+    async fn create_webhook_subscription(
+        &self,
+        request: Request<CreateWebhookSubscriptionRequest>,
+    ) -> Result<Response<CreateWebhookSubscriptionResponse>, Status> {
+        self.authorized_client(&request, WEBHOOKS_ADMIN_SCOPE)
+            .await?;
+
+        let dto = CreateWebhookSubscriptionDto::validate_from_request(request)?;
+        self.webhook_service.create_webhook_subscription(dto).await
+    }
+
+    async fn list_webhook_subscriptions(
+        &self,
+        request: Request<ListWebhookSubscriptionsRequest>,
+    ) -> Result<Response<ListWebhookSubscriptionsResponse>, Status> {
+        self.authorized_client(&request, WEBHOOKS_ADMIN_SCOPE)
+            .await?;
+
+        self.webhook_service.list_webhook_subscriptions().await
+    }
+
+    async fn delete_webhook_subscription(
+        &self,
+        request: Request<DeleteWebhookSubscriptionRequest>,
+    ) -> Result<Response<DeleteWebhookSubscriptionResponse>, Status> {
+        self.authorized_client(&request, WEBHOOKS_ADMIN_SCOPE)
+            .await?;
+
+        let dto = DeleteWebhookSubscriptionDto::validate_from_request(request)?;
+        self.webhook_service.delete_webhook_subscription(dto).await
+    }
+
+    async fn list_webhook_deliveries(
+        &self,
+        request: Request<ListWebhookDeliveriesRequest>,
+    ) -> Result<Response<ListWebhookDeliveriesResponse>, Status> {
+        self.authorized_client(&request, WEBHOOKS_ADMIN_SCOPE)
+            .await?;
+
+        let dto = ListWebhookDeliveriesDto::validate_from_request(request)?;
+        self.webhook_service.list_webhook_deliveries(dto).await
+    }
+
+    type WatchAccountEventsStream = AccountEventFeed;
+
+    async fn watch_account_events(
+        &self,
+        request: Request<WatchAccountEventsRequest>,
+    ) -> Result<Response<Self::WatchAccountEventsStream>, Status> {
+        let user_id = self
+            .authenticated_user(&request, ACCOUNT_EVENTS_READ_SCOPE)
+            .await?;
+        self.account_event_service
+            .watch_account_events(user_id)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entity::api_key::ApiKey;
+    use crate::domain::entity::user::{User, UserStatus};
+    use crate::domain::service::jwt_service::Token;
+    use crate::test_support::{Fakes, test_config};
+
+    /// A handler with one active user holding an API key limited to `scopes`.
+    fn handler_with_api_key(scopes: &[&str]) -> (AuthHandler, Fakes, User, String) {
+        let config = Arc::new(test_config(""));
+        let fakes = Fakes::default();
+        let user = User::new(
+            "Owner".to_string(),
+            "owner@example.com".to_string(),
+            "unused".to_string(),
+            UserStatus::Active,
+        );
+        fakes.users.with_row(user.clone());
+
+        let (key, prefix, key_hash) = ApiKey::generate_key();
+        fakes.api_keys.with_row(ApiKey::new(
+            user.id,
+            "ci".to_string(),
+            prefix,
+            key_hash,
+            scopes.iter().map(|scope| scope.to_string()).collect(),
+            None,
+        ));
+
+        (fakes.auth_handler(&config), fakes, user, key)
+    }
+
+    fn with_api_key<T>(message: T, key: &str) -> Request<T> {
+        let mut request = Request::new(message);
+        request
+            .metadata_mut()
+            .insert(API_KEY_METADATA_KEY, key.parse().unwrap());
+        request
+    }
+
+    #[tokio::test]
+    async fn api_key_calls_rpcs_within_its_scopes() {
+        let (handler, fakes, user, key) = handler_with_api_key(&[ORGANIZATIONS_READ_SCOPE]);
+
+        handler
+            .list_organizations(with_api_key(ListOrganizationsRequest {}, &key))
+            .await
+            .unwrap();
+
+        let api_key = fakes.api_keys.all().remove(0);
+        assert_eq!(api_key.user_id, user.id);
+        assert!(api_key.last_used_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn api_key_is_refused_outside_its_scopes() {
+        let (handler, _, _, key) = handler_with_api_key(&[ORGANIZATIONS_READ_SCOPE]);
+
+        let status = handler
+            .create_organization(with_api_key(
+                CreateOrganizationRequest {
+                    name: "Acme".to_string(),
+                    slug: "acme".to_string(),
+                },
+                &key,
+            ))
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+    }
+
+    #[tokio::test]
+    async fn api_key_cannot_manage_credentials() {
+        let (handler, fakes, _, key) = handler_with_api_key(&[]);
+
+        let status = handler
+            .create_api_key(with_api_key(
+                CreateApiKeyRequest {
+                    name: "escalated".to_string(),
+                    scopes: Vec::new(),
+                    expires_in_days: 0,
+                },
+                &key,
+            ))
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+        assert_eq!(fakes.api_keys.all().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn revoked_api_key_is_rejected() {
+        let (handler, fakes, user, key) = handler_with_api_key(&[]);
+        let api_key = fakes.api_keys.all().remove(0);
+        fakes.api_keys.revoke(api_key.id, user.id).await.unwrap();
+
+        let status = handler
+            .list_organizations(with_api_key(ListOrganizationsRequest {}, &key))
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    }
+
+    #[tokio::test]
+    async fn access_tokens_are_not_limited_by_scopes() {
+        let (handler, _, user, _) = handler_with_api_key(&[]);
+        let config = test_config("");
+        let (token, _) = Token::create_tokens(&config, user.id.to_string(), None)
+            .await
+            .unwrap();
+        let mut request = Request::new(ListAuditEventsRequest::default());
+        request.metadata_mut().insert(
+            "authorization",
+            format!("Bearer {}", token).parse().unwrap(),
+        );
+
+        handler.list_audit_events(request).await.unwrap();
     }
 }
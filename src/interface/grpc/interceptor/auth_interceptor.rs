@@ -1,8 +1,87 @@
-use crate::cfg;
+use crate::domain::entity::api_key::ApiKey;
+use crate::domain::port::db::api_key_port::ApiKeyPort;
 use crate::domain::port::redis_port::RedisPort;
-use crate::domain::service::jwt_service::Token;
+use crate::domain::service::jwt_service::{SubjectType, Token};
+use crate::util::util::sha256_hex;
 use std::sync::Arc;
 use tonic::{Request, Status};
+use tracing::error;
+use x509_parser::extensions::GeneralName;
+use x509_parser::prelude::{FromDer, X509Certificate};
+
+pub const API_KEY_METADATA_KEY: &str = "x-api-key";
+
+/// The authenticated caller behind an access token.
+#[derive(Debug, Clone)]
+pub enum Principal {
+    User {
+        user_id: String,
+        org_id: Option<String>,
+    },
+    Client {
+        client_id: String,
+        scopes: Vec<String>,
+    },
+    ApiKey {
+        user_id: String,
+        key_id: String,
+        scopes: Vec<String>,
+    },
+}
+
+/// A caller that presented a client certificate verified against `TLS_CLIENT_CA_PATH`.
+#[derive(Debug, Clone)]
+pub struct PeerIdentity {
+    pub common_name: Option<String>,
+    pub dns_names: Vec<String>,
+}
+
+/// The verified client certificate identity, when the connection uses mutual TLS.
+pub fn peer_identity<T>(request: &Request<T>) -> Option<PeerIdentity> {
+    let certs = request.peer_certs()?;
+    let (_, cert) = X509Certificate::from_der(certs.first()?.as_ref()).ok()?;
+
+    let common_name = cert
+        .subject()
+        .iter_common_name()
+        .next()
+        .and_then(|cn| cn.as_str().ok())
+        .map(String::from);
+
+    let dns_names = cert
+        .subject_alternative_name()
+        .ok()
+        .flatten()
+        .map(|san| {
+            san.value
+                .general_names
+                .iter()
+                .filter_map(|name| match name {
+                    GeneralName::DNSName(dns) => Some(dns.to_string()),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(PeerIdentity {
+        common_name,
+        dns_names,
+    })
+}
+
+impl Principal {
+    /// API keys without scopes are unrestricted; client tokens only carry their granted scopes.
+    pub fn has_scope(&self, scope: &str) -> bool {
+        match self {
+            Principal::User { .. } => true,
+            Principal::Client { scopes, .. } => scopes.iter().any(|s| s == scope),
+            Principal::ApiKey { scopes, .. } => {
+                scopes.is_empty() || scopes.iter().any(|s| s == scope)
+            }
+        }
+    }
+}
 
 pub fn extract_token_from_metadata(
     metadata: &tonic::metadata::MetadataMap,
@@ -15,39 +94,254 @@ pub fn extract_token_from_metadata(
         .to_str()
         .map_err(|_| Status::unauthenticated("Invalid token format"))?;
 
-    if token_str.starts_with("Bearer ") {
-        Ok(&token_str["Bearer ".len()..])
-    } else {
-        Err(Status::unauthenticated("Invalid token scheme"))
+    token_str
+        .strip_prefix("Bearer ")
+        .ok_or_else(|| Status::unauthenticated("Invalid token scheme"))
+}
+
+async fn authenticate_api_key(
+    metadata: &tonic::metadata::MetadataMap,
+    api_keys: &Arc<dyn ApiKeyPort + Send + Sync>,
+) -> Result<Principal, Status> {
+    let key = metadata
+        .get(API_KEY_METADATA_KEY)
+        .and_then(|value| value.to_str().ok())
+        .ok_or_else(|| Status::unauthenticated("Invalid API key format"))?;
+
+    let prefix =
+        ApiKey::prefix_of(key).ok_or_else(|| Status::unauthenticated("Invalid API key"))?;
+
+    let api_key = api_keys
+        .find_by_prefix(prefix)
+        .await
+        .map_err(|e| {
+            error!("Failed to query API key: {}", e);
+            Status::internal("Failed to validate API key")
+        })?
+        .filter(|api_key| api_key.key_hash == sha256_hex(key))
+        .ok_or_else(|| Status::unauthenticated("Invalid API key"))?;
+
+    if !api_key.is_usable() {
+        return Err(Status::unauthenticated("API key expired or revoked"));
     }
+
+    if let Err(e) = api_keys.touch_last_used(api_key.id).await {
+        error!("Failed to update API key last use: {}", e);
+    }
+
+    Ok(Principal::ApiKey {
+        user_id: api_key.user_id.to_string(),
+        key_id: api_key.id.to_string(),
+        scopes: api_key.scopes,
+    })
 }
 
-pub async fn authenticate_interceptor(
-    req: Request<()>,
+/// Authenticates the caller from either an `x-api-key` header or a `Bearer` access token.
+pub async fn authenticate(
+    metadata: &tonic::metadata::MetadataMap,
     redis_port: &Arc<dyn RedisPort + Send + Sync>,
-) -> Result<Request<()>, Status> {
-    let token = extract_token_from_metadata(req.metadata())?;
+    api_keys: &Arc<dyn ApiKeyPort + Send + Sync>,
+    access_secret: &str,
+) -> Result<Principal, Status> {
+    if metadata.contains_key(API_KEY_METADATA_KEY) {
+        return authenticate_api_key(metadata, api_keys).await;
+    }
+
+    authenticate_bearer(metadata, redis_port, access_secret).await
+}
+
+async fn authenticate_bearer(
+    metadata: &tonic::metadata::MetadataMap,
+    redis_port: &Arc<dyn RedisPort + Send + Sync>,
+    access_secret: &str,
+) -> Result<Principal, Status> {
+    let token = extract_token_from_metadata(metadata)?;
 
     redis_port.ensure_not_blacklisted(token).await?;
 
-    let config = cfg();
-    match Token::validate_token(token, &config.access_secret) {
-        Ok(_) => Ok(req),
-        Err(status) => Err(status),
-    }
+    let claims = Token::validate_token(token, access_secret)?;
+
+    Ok(match claims.subject_type() {
+        SubjectType::User => Principal::User {
+            user_id: claims.subject().to_string(),
+            org_id: claims.org_id().map(String::from),
+        },
+        SubjectType::Client => Principal::Client {
+            client_id: claims.subject().to_string(),
+            scopes: claims
+                .scope()
+                .unwrap_or_default()
+                .split_whitespace()
+                .map(String::from)
+                .collect(),
+        },
+        SubjectType::Invitation | SubjectType::OidcUser => {
+            return Err(Status::unauthenticated("Invalid token"));
+        }
+    })
 }
 
+/// Validates a user `Bearer` access token and returns the user id. Client tokens are rejected.
 pub async fn validate_access_token(
     metadata: &tonic::metadata::MetadataMap,
     redis_port: &Arc<dyn RedisPort + Send + Sync>,
-) -> Result<(), Status> {
-    let token = extract_token_from_metadata(metadata)?;
+    access_secret: &str,
+) -> Result<String, Status> {
+    match authenticate_bearer(metadata, redis_port, access_secret).await? {
+        Principal::User { user_id, .. } => Ok(user_id),
+        _ => Err(Status::permission_denied(
+            "Client tokens cannot be used for this operation",
+        )),
+    }
+}
 
-    redis_port.ensure_not_blacklisted(token).await?;
+/// Validates a client `Bearer` access token that was granted `scope` and returns the client id.
+pub async fn authorize_client_scope(
+    metadata: &tonic::metadata::MetadataMap,
+    redis_port: &Arc<dyn RedisPort + Send + Sync>,
+    access_secret: &str,
+    scope: &str,
+) -> Result<String, Status> {
+    let principal = authenticate_bearer(metadata, redis_port, access_secret).await?;
+
+    match principal {
+        Principal::Client { client_id, .. } if principal.has_scope(scope) => Ok(client_id),
+        _ => Err(Status::permission_denied(format!(
+            "A client token with the {} scope is required",
+            scope
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{MemoryRedis, test_config};
+    use tonic::metadata::MetadataMap;
+
+    fn bearer(token: &str) -> MetadataMap {
+        let mut metadata = MetadataMap::new();
+        metadata.insert(
+            "authorization",
+            format!("Bearer {}", token).parse().unwrap(),
+        );
+        metadata
+    }
+
+    fn redis() -> Arc<dyn RedisPort + Send + Sync> {
+        Arc::new(MemoryRedis::default())
+    }
+
+    #[tokio::test]
+    async fn user_tokens_authenticate_users() {
+        let config = test_config("");
+        let (token, _) = Token::create_tokens(&config, "user-1".to_string(), None)
+            .await
+            .unwrap();
+
+        let user_id = validate_access_token(&bearer(&token), &redis(), &config.access_secret)
+            .await
+            .unwrap();
+
+        assert_eq!(user_id, "user-1");
+    }
+
+    #[tokio::test]
+    async fn oidc_access_tokens_are_rejected() {
+        let config = test_config("");
+        let token = Token::create_oidc_access_token(
+            &config,
+            "user-1".to_string(),
+            "client-1".to_string(),
+            "openid".to_string(),
+        )
+        .await
+        .unwrap();
+
+        let status = validate_access_token(&bearer(&token), &redis(), &config.access_secret)
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    }
+
+    #[tokio::test]
+    async fn blacklisted_tokens_are_rejected() {
+        let config = test_config("");
+        let (token, _) = Token::create_tokens(&config, "user-1".to_string(), None)
+            .await
+            .unwrap();
+        let redis = redis();
+        redis.blacklist_token(&token).await.unwrap();
+
+        let status = validate_access_token(&bearer(&token), &redis, &config.access_secret)
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    }
+
+    #[tokio::test]
+    async fn client_tokens_need_the_scope_and_cannot_act_as_users() {
+        let config = test_config("");
+        let token = Token::create_client_token(&config, "client-1".to_string(), "read".to_string())
+            .await
+            .unwrap();
+        let metadata = bearer(&token);
+
+        let client_id = authorize_client_scope(&metadata, &redis(), &config.access_secret, "read")
+            .await
+            .unwrap();
+        assert_eq!(client_id, "client-1");
+
+        let missing = authorize_client_scope(&metadata, &redis(), &config.access_secret, "write")
+            .await
+            .unwrap_err();
+        assert_eq!(missing.code(), tonic::Code::PermissionDenied);
+
+        let as_user = validate_access_token(&metadata, &redis(), &config.access_secret)
+            .await
+            .unwrap_err();
+        assert_eq!(as_user.code(), tonic::Code::PermissionDenied);
+    }
+
+    #[test]
+    fn scopes_limit_clients_and_api_keys_but_not_users() {
+        let user = Principal::User {
+            user_id: "user-1".to_string(),
+            org_id: None,
+        };
+        let client = Principal::Client {
+            client_id: "client-1".to_string(),
+            scopes: vec!["read".to_string()],
+        };
+        let scoped_key = Principal::ApiKey {
+            user_id: "user-1".to_string(),
+            key_id: "key-1".to_string(),
+            scopes: vec!["read".to_string()],
+        };
+        let unscoped_key = Principal::ApiKey {
+            user_id: "user-1".to_string(),
+            key_id: "key-2".to_string(),
+            scopes: Vec::new(),
+        };
+
+        assert!(user.has_scope("write"));
+        assert!(client.has_scope("read"));
+        assert!(!client.has_scope("write"));
+        assert!(scoped_key.has_scope("read"));
+        assert!(!scoped_key.has_scope("write"));
+        assert!(unscoped_key.has_scope("write"));
+    }
+
+    #[test]
+    fn bearer_scheme_is_required() {
+        let mut metadata = MetadataMap::new();
+        assert!(extract_token_from_metadata(&metadata).is_err());
+
+        metadata.insert("authorization", "Basic abc".parse().unwrap());
+        assert!(extract_token_from_metadata(&metadata).is_err());
 
-    let config = cfg();
-    match Token::validate_token(token, &config.access_secret) {
-        Ok(_) => Ok(()),
-        Err(status) => Err(status),
+        assert_eq!(extract_token_from_metadata(&bearer("abc")).unwrap(), "abc");
     }
 }
@@ -0,0 +1,98 @@
+use crate::infrastructure::metrics::prometheus_adapter::PrometheusAdapter;
+use futures::FutureExt;
+use futures::future::BoxFuture;
+use http::{Request, Response};
+use http_body::Body;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Instant;
+use tonic::Code;
+use tower::{Layer, Service};
+
+/// Counts gRPC calls by method and status code and times them until they are answered.
+///
+/// The code is the one a call is answered with: failures are sent as trailers-only responses
+/// with `grpc-status` in the headers, and anything else counts as `Ok`, so a stream that fails
+/// after it started is still counted as `Ok`.
+#[derive(Clone)]
+pub struct MetricsLayer {
+    metrics: Arc<PrometheusAdapter>,
+}
+
+impl MetricsLayer {
+    pub fn new(metrics: Arc<PrometheusAdapter>) -> Self {
+        MetricsLayer { metrics }
+    }
+}
+
+impl<S> Layer<S> for MetricsLayer {
+    type Service = MetricsMiddleware<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        MetricsMiddleware {
+            inner,
+            metrics: self.metrics.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct MetricsMiddleware<S> {
+    inner: S,
+    metrics: Arc<PrometheusAdapter>,
+}
+
+fn status_code<B>(response: &Response<B>) -> Code {
+    response
+        .headers()
+        .get("grpc-status")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<i32>().ok())
+        .map(Code::from_i32)
+        .unwrap_or(Code::Ok)
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for MetricsMiddleware<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    ReqBody: Send + 'static,
+    ResBody: Body + Send + 'static,
+    S::Error: Send + 'static,
+{
+    type Response = Response<ResBody>;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        let path = req.uri().path().to_string();
+        let metrics = self.metrics.clone();
+        let start = Instant::now();
+
+        let fut = self.inner.call(req);
+
+        async move {
+            let res = fut.await;
+            let code = match &res {
+                Ok(response) => status_code(response),
+                // Only the timeout layer below fails calls instead of answering them.
+                Err(_) => Code::DeadlineExceeded,
+            };
+
+            // Unknown paths are answered `Unimplemented`; keep them out of the method label.
+            let method = if code == Code::Unimplemented {
+                "unknown"
+            } else {
+                path.as_str()
+            };
+            metrics.record_rpc(method, &format!("{:?}", code), start.elapsed());
+
+            res
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,220 @@
+use futures::FutureExt;
+use futures::future::BoxFuture;
+use http::{HeaderMap, Request, Response};
+use opentelemetry::global;
+use opentelemetry::propagation::Extractor;
+use opentelemetry::trace::{TraceContextExt, TraceId};
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+use tracing::Instrument;
+use tracing::field::{Empty, display};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// Reads W3C `traceparent`/`tracestate` from request headers, which are the gRPC metadata.
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|key| key.as_str()).collect()
+    }
+}
+
+/// Runs each request in a `request` span that continues the caller's trace when it sends a
+/// `traceparent` header. The span carries the trace id, so every log line of the request
+/// shows it, and the `grpc-status` of failed calls, which are answered trailers-only.
+#[derive(Clone)]
+pub struct TraceLayer;
+
+impl<S> Layer<S> for TraceLayer {
+    type Service = TraceMiddleware<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TraceMiddleware { inner }
+    }
+}
+
+#[derive(Clone)]
+pub struct TraceMiddleware<S> {
+    inner: S,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for TraceMiddleware<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    ReqBody: Send + 'static,
+    ResBody: Send + 'static,
+    S::Error: Send + 'static,
+{
+    type Response = Response<ResBody>;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        let path = req.uri().path();
+        let span = tracing::info_span!(
+            "request",
+            otel.name = path,
+            otel.kind = "server",
+            trace_id = Empty,
+            rpc.grpc.status_code = Empty,
+            otel.status_code = Empty,
+        );
+
+        let parent = global::get_text_map_propagator(|propagator| {
+            propagator.extract(&HeaderExtractor(req.headers()))
+        });
+        span.set_parent(parent.clone());
+
+        // Without an exporter the span has no OpenTelemetry context; log the caller's id then.
+        let trace_id = [span.context(), parent]
+            .iter()
+            .map(|cx| cx.span().span_context().trace_id())
+            .find(|id| *id != TraceId::INVALID);
+        if let Some(trace_id) = trace_id {
+            span.record("trace_id", display(trace_id));
+        }
+
+        let fut = span.in_scope(|| self.inner.call(req));
+        let record_span = span.clone();
+
+        async move {
+            let res = fut.await;
+            if let Ok(response) = &res
+                && let Some(code) = response.headers().get("grpc-status")
+                && let Ok(code) = code.to_str()
+            {
+                record_span.record("rpc.grpc.status_code", code);
+                if code != "0" {
+                    record_span.record("otel.status_code", "ERROR");
+                }
+            }
+            res
+        }
+        .instrument(span)
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::telemetry::otel_layer;
+    use opentelemetry::trace::{SpanId, SpanKind, Status as SpanStatus};
+    use opentelemetry::{KeyValue, Value};
+    use opentelemetry_sdk::propagation::TraceContextPropagator;
+    use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider, SpanData};
+    use std::convert::Infallible;
+    use tower::{ServiceExt, service_fn};
+    use tracing_subscriber::layer::SubscriberExt;
+
+    const TRACE_ID: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+    const PARENT_SPAN_ID: &str = "00f067aa0ba902b7";
+
+    /// Sends each request through `TraceLayer` to a service answering with `grpc_status`, and
+    /// returns the spans exported meanwhile.
+    async fn export(requests: Vec<Request<()>>, grpc_status: &'static str) -> Vec<SpanData> {
+        global::set_text_map_propagator(TraceContextPropagator::new());
+        let exporter = InMemorySpanExporter::default();
+        let provider = SdkTracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+        let subscriber = tracing_subscriber::registry().with(otel_layer(&provider));
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let service = TraceLayer.layer(service_fn(move |_request: Request<()>| async move {
+            Ok::<_, Infallible>(
+                Response::builder()
+                    .header("grpc-status", grpc_status)
+                    .body(())
+                    .unwrap(),
+            )
+        }));
+        for request in requests {
+            service.clone().oneshot(request).await.unwrap();
+        }
+
+        provider.force_flush().unwrap();
+        exporter.get_finished_spans().unwrap()
+    }
+
+    fn rpc(path: &str) -> Request<()> {
+        Request::post(path).body(()).unwrap()
+    }
+
+    fn attribute<'a>(span: &'a SpanData, key: &str) -> Option<&'a Value> {
+        span.attributes
+            .iter()
+            .find(|KeyValue { key: k, .. }| k.as_str() == key)
+            .map(|attribute| &attribute.value)
+    }
+
+    #[tokio::test]
+    async fn every_rpc_gets_its_own_server_span() {
+        let spans = export(
+            vec![
+                rpc("/auth.AuthHandler/Login"),
+                rpc("/auth.AuthHandler/Register"),
+            ],
+            "0",
+        )
+        .await;
+
+        let names: Vec<_> = spans.iter().map(|span| span.name.as_ref()).collect();
+        assert_eq!(
+            names,
+            vec!["/auth.AuthHandler/Login", "/auth.AuthHandler/Register"]
+        );
+        assert!(spans.iter().all(|span| span.span_kind == SpanKind::Server));
+        assert!(
+            spans
+                .iter()
+                .all(|span| span.parent_span_id == SpanId::INVALID)
+        );
+        assert_ne!(
+            spans[0].span_context.trace_id(),
+            spans[1].span_context.trace_id()
+        );
+        assert_eq!(
+            attribute(&spans[0], "rpc.grpc.status_code"),
+            Some(&Value::from("0"))
+        );
+    }
+
+    #[tokio::test]
+    async fn incoming_traceparent_is_continued() {
+        let mut request = rpc("/auth.AuthHandler/Login");
+        request.headers_mut().insert(
+            "traceparent",
+            format!("00-{}-{}-01", TRACE_ID, PARENT_SPAN_ID)
+                .parse()
+                .unwrap(),
+        );
+
+        let spans = export(vec![request], "16").await;
+
+        assert_eq!(spans.len(), 1);
+        let span = &spans[0];
+        assert_eq!(
+            span.span_context.trace_id(),
+            TraceId::from_hex(TRACE_ID).unwrap()
+        );
+        assert_eq!(
+            span.parent_span_id,
+            SpanId::from_hex(PARENT_SPAN_ID).unwrap()
+        );
+        assert_eq!(
+            attribute(span, "rpc.grpc.status_code"),
+            Some(&Value::from("16"))
+        );
+        assert!(matches!(span.status, SpanStatus::Error { .. }));
+    }
+}
@@ -0,0 +1,22 @@
+use crate::infrastructure::metrics::prometheus_adapter::PrometheusAdapter;
+use axum::Router;
+use axum::extract::State;
+use axum::http::header;
+use axum::response::IntoResponse;
+use axum::routing::get;
+use std::sync::Arc;
+
+type MetricsState = Arc<PrometheusAdapter>;
+
+pub fn metrics_router(metrics: MetricsState) -> Router {
+    Router::new()
+        .route("/metrics", get(metrics_text))
+        .with_state(metrics)
+}
+
+async fn metrics_text(State(metrics): State<MetricsState>) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, prometheus::TEXT_FORMAT)],
+        metrics.render().await,
+    )
+}
@@ -0,0 +1,201 @@
+use crate::application::audit_use_case::AuditContext;
+use crate::application::oidc_provider_use_case::{
+    AuthorizeParams, OAuthError, OidcProviderUseCase, TokenParams,
+};
+use axum::extract::{ConnectInfo, Query, State};
+use axum::http::{HeaderMap, HeaderValue, StatusCode, header};
+use axum::response::{Html, IntoResponse, Redirect, Response};
+use axum::routing::get;
+use axum::{Extension, Form, Json, Router};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde::Deserialize;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tera::{Context, Tera};
+use tracing::error;
+
+const AUTHORIZE_TEMPLATE: &str = include_str!("../../util/template/authorize.html");
+
+type OidcState = Arc<OidcProviderUseCase>;
+
+#[derive(Deserialize)]
+struct AuthorizeForm {
+    #[serde(flatten)]
+    params: AuthorizeParams,
+    email: String,
+    password: String,
+    consent: Option<String>,
+}
+
+impl IntoResponse for OAuthError {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let mut response = (status, Json(&self)).into_response();
+
+        if status == StatusCode::UNAUTHORIZED {
+            let challenge = format!("Bearer error=\"{}\"", self.error);
+            if let Ok(value) = HeaderValue::from_str(&challenge) {
+                response
+                    .headers_mut()
+                    .insert(header::WWW_AUTHENTICATE, value);
+            }
+        }
+
+        response
+    }
+}
+
+pub fn oidc_router(use_case: OidcState) -> Router {
+    Router::new()
+        .route("/.well-known/openid-configuration", get(discovery))
+        .route("/oauth2/jwks", get(jwks))
+        .route("/oauth2/authorize", get(authorize_page).post(authorize))
+        .route("/oauth2/token", axum::routing::post(token))
+        .route("/oauth2/userinfo", get(userinfo).post(userinfo))
+        .with_state(use_case)
+}
+
+async fn discovery(State(use_case): State<OidcState>) -> Json<serde_json::Value> {
+    Json(use_case.discovery())
+}
+
+async fn jwks(State(use_case): State<OidcState>) -> Json<serde_json::Value> {
+    Json(use_case.jwks())
+}
+
+async fn authorize_page(
+    State(use_case): State<OidcState>,
+    Query(params): Query<AuthorizeParams>,
+) -> Response {
+    match use_case.prompt(&params).await {
+        Ok(prompt) => render_authorize(
+            &params,
+            &prompt.client_name,
+            &prompt.scopes,
+            "",
+            None,
+            false,
+        ),
+        Err(e) => e.into_response(),
+    }
+}
+
+async fn authorize(
+    State(use_case): State<OidcState>,
+    connect_info: Option<Extension<ConnectInfo<SocketAddr>>>,
+    headers: HeaderMap,
+    Form(form): Form<AuthorizeForm>,
+) -> Response {
+    let consent_given = form.consent.as_deref() == Some("approve");
+    let context = AuditContext {
+        ip: connect_info.map(|Extension(ConnectInfo(addr))| addr.ip().to_string()),
+        device: headers
+            .get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(String::from),
+        ..AuditContext::default()
+    };
+
+    match use_case
+        .authorize(
+            &form.params,
+            &form.email,
+            &form.password,
+            consent_given,
+            context,
+        )
+        .await
+    {
+        Ok(redirect_url) => Redirect::to(&redirect_url).into_response(),
+        Err(e) if e.error == "login_required" || e.error == "consent_required" => {
+            match use_case.prompt(&form.params).await {
+                Ok(prompt) => render_authorize(
+                    &form.params,
+                    &prompt.client_name,
+                    &prompt.scopes,
+                    &form.email,
+                    Some(&e.error_description),
+                    e.error == "consent_required",
+                ),
+                Err(e) => e.into_response(),
+            }
+        }
+        Err(e) => e.into_response(),
+    }
+}
+
+async fn token(
+    State(use_case): State<OidcState>,
+    headers: HeaderMap,
+    Form(params): Form<TokenParams>,
+) -> Response {
+    match use_case.token(params, basic_credentials(&headers)).await {
+        Ok(token) => {
+            let mut response = Json(token).into_response();
+            response
+                .headers_mut()
+                .insert(header::CACHE_CONTROL, HeaderValue::from_static("no-store"));
+            response
+        }
+        Err(e) => e.into_response(),
+    }
+}
+
+async fn userinfo(State(use_case): State<OidcState>, headers: HeaderMap) -> Response {
+    let token = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    let Some(token) = token else {
+        return OAuthError {
+            error: "invalid_token",
+            error_description: "Authorization token is missing".to_string(),
+            status: 401,
+        }
+        .into_response();
+    };
+
+    match use_case.userinfo(token).await {
+        Ok(claims) => Json(claims).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+fn basic_credentials(headers: &HeaderMap) -> Option<(String, String)> {
+    let encoded = headers
+        .get(header::AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Basic ")?;
+    let decoded = String::from_utf8(STANDARD.decode(encoded).ok()?).ok()?;
+    let (id, secret) = decoded.split_once(':')?;
+
+    Some((id.to_string(), secret.to_string()))
+}
+
+fn render_authorize(
+    params: &AuthorizeParams,
+    client_name: &str,
+    scopes: &[String],
+    email: &str,
+    error_message: Option<&str>,
+    consent_required: bool,
+) -> Response {
+    let mut context = Context::new();
+    context.insert("params", params);
+    context.insert("client_name", client_name);
+    context.insert("scopes", scopes);
+    context.insert("email", email);
+    context.insert("error", &error_message);
+    context.insert("consent_required", &consent_required);
+
+    match Tera::one_off(AUTHORIZE_TEMPLATE, &context, true) {
+        Ok(html) => Html(html).into_response(),
+        Err(e) => {
+            error!("Failed to render authorize template: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
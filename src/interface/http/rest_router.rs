@@ -0,0 +1,701 @@
+use crate::interface::grpc::handler::auth_handler::AuthHandler;
+use crate::interface::grpc::layer::trace_layer::TraceLayer;
+use crate::pb::auth::auth_handler_server::AuthHandler as Handler;
+use crate::pb::auth::{
+    AcceptInvitationRequest, BeginExternalLoginRequest, BeginPasskeyLoginRequest,
+    BeginPasskeyRegistrationRequest, ClientCredentialsTokenRequest, CompleteExternalLoginRequest,
+    CreateApiKeyRequest, CreateOrganizationRequest, CreateWebhookSubscriptionRequest,
+    DeleteWebhookSubscriptionRequest, FinishPasskeyLoginRequest, FinishPasskeyRegistrationRequest,
+    ForgotPasswordRequest, InviteMemberRequest, ListApiKeysRequest, ListAuditEventsRequest,
+    ListInvitationsRequest, ListOrganizationsRequest, ListOutboxMessagesRequest,
+    ListWebhookDeliveriesRequest, ListWebhookSubscriptionsRequest, LoginRequest, LogoutRequest,
+    RegisterRequest, ReplayOutboxMessageRequest, RevokeApiKeyRequest, RevokeInvitationRequest,
+    SendOtpRequest, SwitchOrganizationRequest, UpdatePhoneNumberRequest, VerifyEmailRequest,
+    VerifyPhoneNumberRequest, WatchAccountEventsRequest,
+};
+use axum::extract::{ConnectInfo, FromRequest, FromRequestParts, Path, Query, State};
+use axum::http::request::Parts;
+use axum::http::{HeaderMap, HeaderValue, StatusCode, header};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{delete, get, post};
+use axum::{Json, Router};
+use futures::{Stream, StreamExt};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tonic::metadata::MetadataMap;
+use tonic::transport::server::TcpConnectInfo;
+use tonic::{Code, Status};
+
+type RestState = Arc<AuthHandler>;
+
+/// An RFC 9457 `application/problem+json` error. Failed RPCs keep their gRPC code in
+/// `grpc_status` so clients can branch on the same values as gRPC callers.
+pub struct Problem {
+    status: StatusCode,
+    detail: String,
+    grpc_status: Option<Code>,
+}
+
+#[derive(Serialize)]
+struct ProblemBody<'a> {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    title: &'static str,
+    status: u16,
+    detail: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    grpc_status: Option<i32>,
+}
+
+impl Problem {
+    fn new(status: StatusCode, detail: impl Into<String>) -> Self {
+        Problem {
+            status,
+            detail: detail.into(),
+            grpc_status: None,
+        }
+    }
+
+    fn body(&self) -> ProblemBody<'_> {
+        ProblemBody {
+            kind: "about:blank",
+            title: self.status.canonical_reason().unwrap_or("Error"),
+            status: self.status.as_u16(),
+            detail: &self.detail,
+            grpc_status: self.grpc_status.map(|code| code as i32),
+        }
+    }
+}
+
+/// HTTP status for a gRPC code, following the mapping of `google.rpc.Code`.
+fn http_status(code: Code) -> StatusCode {
+    match code {
+        Code::Ok => StatusCode::OK,
+        Code::Cancelled => StatusCode::from_u16(499).unwrap_or(StatusCode::BAD_REQUEST),
+        Code::InvalidArgument | Code::FailedPrecondition | Code::OutOfRange => {
+            StatusCode::BAD_REQUEST
+        }
+        Code::DeadlineExceeded => StatusCode::GATEWAY_TIMEOUT,
+        Code::NotFound => StatusCode::NOT_FOUND,
+        Code::AlreadyExists | Code::Aborted => StatusCode::CONFLICT,
+        Code::PermissionDenied => StatusCode::FORBIDDEN,
+        Code::Unauthenticated => StatusCode::UNAUTHORIZED,
+        Code::ResourceExhausted => StatusCode::TOO_MANY_REQUESTS,
+        Code::Unimplemented => StatusCode::NOT_IMPLEMENTED,
+        Code::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+        Code::Unknown | Code::Internal | Code::DataLoss => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+impl From<Status> for Problem {
+    fn from(status: Status) -> Self {
+        Problem {
+            status: http_status(status.code()),
+            detail: status.message().to_string(),
+            grpc_status: Some(status.code()),
+        }
+    }
+}
+
+impl IntoResponse for Problem {
+    fn into_response(self) -> Response {
+        let mut response = (self.status, Json(self.body())).into_response();
+        let headers = response.headers_mut();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/problem+json"),
+        );
+        if self.status == StatusCode::UNAUTHORIZED {
+            headers.insert(header::WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+        }
+        response
+    }
+}
+
+/// What a route forwards to `AuthHandler` besides the message: the request headers, which
+/// become gRPC metadata (`authorization`, `x-api-key`, `user-agent`, `x-locale`, ...), and the
+/// peer address, so client IPs and audit records match the gRPC listener.
+struct RpcContext {
+    headers: HeaderMap,
+    remote_addr: Option<SocketAddr>,
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for RpcContext {
+    type Rejection = Problem;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(RpcContext {
+            headers: parts.headers.clone(),
+            remote_addr: parts
+                .extensions
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(addr)| *addr),
+        })
+    }
+}
+
+impl RpcContext {
+    fn request<T>(self, message: T) -> tonic::Request<T> {
+        let mut extensions = tonic::Extensions::new();
+        extensions.insert(TcpConnectInfo {
+            local_addr: None,
+            remote_addr: self.remote_addr,
+        });
+        tonic::Request::from_parts(MetadataMap::from_headers(self.headers), extensions, message)
+    }
+}
+
+/// `Json` that rejects malformed bodies with a problem document.
+struct JsonBody<T>(T);
+
+impl<S: Send + Sync, T: DeserializeOwned> FromRequest<S> for JsonBody<T> {
+    type Rejection = Problem;
+
+    async fn from_request(request: axum::extract::Request, state: &S) -> Result<Self, Problem> {
+        Json::<T>::from_request(request, state)
+            .await
+            .map(|Json(message)| JsonBody(message))
+            .map_err(|e| Problem::new(e.status(), e.body_text()))
+    }
+}
+
+/// `Query` that rejects malformed parameters with a problem document.
+struct QueryParams<T>(T);
+
+impl<S: Send + Sync, T: DeserializeOwned> FromRequestParts<S> for QueryParams<T> {
+    type Rejection = Problem;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Problem> {
+        Query::<T>::from_request_parts(parts, state)
+            .await
+            .map(|Query(message)| QueryParams(message))
+            .map_err(|e| Problem::new(e.status(), e.body_text()))
+    }
+}
+
+/// Returns the RPC's response message as JSON, with its metadata as response headers.
+fn reply<T: Serialize>(result: Result<tonic::Response<T>, Status>) -> Result<Response, Problem> {
+    let (metadata, message, _) = result?.into_parts();
+    let mut response = Json(message).into_response();
+    response.headers_mut().extend(metadata.into_headers());
+    Ok(response)
+}
+
+/// JSON/REST routes over the same `AuthHandler` the gRPC listener serves, so validation,
+/// authorization and auditing are identical on both. Only `TraceLayer` is shared: the gRPC
+/// logging, metrics, timeout and concurrency limit layers are not applied here.
+pub fn rest_router(handler: RestState) -> Router {
+    Router::new()
+        .route("/v1/auth/register", post(register))
+        .route("/v1/auth/login", post(login))
+        .route("/v1/auth/logout", post(logout))
+        .route("/v1/auth/otp", post(send_otp))
+        .route("/v1/auth/verify-email", post(verify_email))
+        .route("/v1/auth/forgot-password", post(forgot_password))
+        .route("/v1/auth/external/begin", post(begin_external_login))
+        .route("/v1/auth/external/complete", post(complete_external_login))
+        .route("/v1/auth/client-token", post(client_credentials_token))
+        .route(
+            "/v1/auth/passkeys/register/begin",
+            post(begin_passkey_registration),
+        )
+        .route(
+            "/v1/auth/passkeys/register/finish",
+            post(finish_passkey_registration),
+        )
+        .route("/v1/auth/passkeys/login/begin", post(begin_passkey_login))
+        .route("/v1/auth/passkeys/login/finish", post(finish_passkey_login))
+        .route("/v1/api-keys", get(list_api_keys).post(create_api_key))
+        .route("/v1/api-keys/{id}", delete(revoke_api_key))
+        .route(
+            "/v1/organizations",
+            get(list_organizations).post(create_organization),
+        )
+        .route(
+            "/v1/organizations/{org_id}/switch",
+            post(switch_organization),
+        )
+        .route(
+            "/v1/organizations/{org_id}/invitations",
+            get(list_invitations).post(invite_member),
+        )
+        .route("/v1/invitations/{id}", delete(revoke_invitation))
+        .route("/v1/invitations/accept", post(accept_invitation))
+        .route("/v1/audit-events", get(list_audit_events))
+        .route("/v1/outbox/messages", get(list_outbox_messages))
+        .route(
+            "/v1/outbox/messages/{id}/replay",
+            post(replay_outbox_message),
+        )
+        .route("/v1/account/phone-number", post(update_phone_number))
+        .route("/v1/account/phone-number/verify", post(verify_phone_number))
+        .route("/v1/account/events", get(watch_account_events))
+        .route(
+            "/v1/webhooks",
+            get(list_webhook_subscriptions).post(create_webhook_subscription),
+        )
+        .route("/v1/webhooks/{id}", delete(delete_webhook_subscription))
+        .route(
+            "/v1/webhooks/{subscription_id}/deliveries",
+            get(list_webhook_deliveries),
+        )
+        .layer(TraceLayer)
+        .with_state(handler)
+}
+
+async fn register(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    JsonBody(message): JsonBody<RegisterRequest>,
+) -> Result<Response, Problem> {
+    reply(handler.register(context.request(message)).await)
+}
+
+async fn login(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    JsonBody(message): JsonBody<LoginRequest>,
+) -> Result<Response, Problem> {
+    reply(handler.login(context.request(message)).await)
+}
+
+async fn logout(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    JsonBody(message): JsonBody<LogoutRequest>,
+) -> Result<Response, Problem> {
+    reply(handler.logout(context.request(message)).await)
+}
+
+async fn send_otp(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    JsonBody(message): JsonBody<SendOtpRequest>,
+) -> Result<Response, Problem> {
+    reply(handler.send_otp(context.request(message)).await)
+}
+
+async fn verify_email(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    JsonBody(message): JsonBody<VerifyEmailRequest>,
+) -> Result<Response, Problem> {
+    reply(handler.verify_email(context.request(message)).await)
+}
+
+async fn forgot_password(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    JsonBody(message): JsonBody<ForgotPasswordRequest>,
+) -> Result<Response, Problem> {
+    reply(handler.forgot_password(context.request(message)).await)
+}
+
+async fn begin_external_login(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    JsonBody(message): JsonBody<BeginExternalLoginRequest>,
+) -> Result<Response, Problem> {
+    reply(handler.begin_external_login(context.request(message)).await)
+}
+
+async fn complete_external_login(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    JsonBody(message): JsonBody<CompleteExternalLoginRequest>,
+) -> Result<Response, Problem> {
+    reply(
+        handler
+            .complete_external_login(context.request(message))
+            .await,
+    )
+}
+
+async fn client_credentials_token(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    JsonBody(message): JsonBody<ClientCredentialsTokenRequest>,
+) -> Result<Response, Problem> {
+    reply(
+        handler
+            .client_credentials_token(context.request(message))
+            .await,
+    )
+}
+
+async fn begin_passkey_registration(
+    State(handler): State<RestState>,
+    context: RpcContext,
+) -> Result<Response, Problem> {
+    let message = BeginPasskeyRegistrationRequest {};
+    reply(
+        handler
+            .begin_passkey_registration(context.request(message))
+            .await,
+    )
+}
+
+async fn finish_passkey_registration(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    JsonBody(message): JsonBody<FinishPasskeyRegistrationRequest>,
+) -> Result<Response, Problem> {
+    reply(
+        handler
+            .finish_passkey_registration(context.request(message))
+            .await,
+    )
+}
+
+async fn begin_passkey_login(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    JsonBody(message): JsonBody<BeginPasskeyLoginRequest>,
+) -> Result<Response, Problem> {
+    reply(handler.begin_passkey_login(context.request(message)).await)
+}
+
+async fn finish_passkey_login(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    JsonBody(message): JsonBody<FinishPasskeyLoginRequest>,
+) -> Result<Response, Problem> {
+    reply(handler.finish_passkey_login(context.request(message)).await)
+}
+
+async fn create_api_key(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    JsonBody(message): JsonBody<CreateApiKeyRequest>,
+) -> Result<Response, Problem> {
+    reply(handler.create_api_key(context.request(message)).await)
+}
+
+async fn list_api_keys(
+    State(handler): State<RestState>,
+    context: RpcContext,
+) -> Result<Response, Problem> {
+    let message = ListApiKeysRequest {};
+    reply(handler.list_api_keys(context.request(message)).await)
+}
+
+async fn revoke_api_key(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    Path(id): Path<String>,
+) -> Result<Response, Problem> {
+    let message = RevokeApiKeyRequest { id };
+    reply(handler.revoke_api_key(context.request(message)).await)
+}
+
+async fn create_organization(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    JsonBody(message): JsonBody<CreateOrganizationRequest>,
+) -> Result<Response, Problem> {
+    reply(handler.create_organization(context.request(message)).await)
+}
+
+async fn list_organizations(
+    State(handler): State<RestState>,
+    context: RpcContext,
+) -> Result<Response, Problem> {
+    let message = ListOrganizationsRequest {};
+    reply(handler.list_organizations(context.request(message)).await)
+}
+
+async fn switch_organization(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    Path(org_id): Path<String>,
+) -> Result<Response, Problem> {
+    let message = SwitchOrganizationRequest { org_id };
+    reply(handler.switch_organization(context.request(message)).await)
+}
+
+async fn invite_member(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    Path(org_id): Path<String>,
+    JsonBody(message): JsonBody<InviteMemberRequest>,
+) -> Result<Response, Problem> {
+    let message = InviteMemberRequest { org_id, ..message };
+    reply(handler.invite_member(context.request(message)).await)
+}
+
+async fn list_invitations(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    Path(org_id): Path<String>,
+) -> Result<Response, Problem> {
+    let message = ListInvitationsRequest { org_id };
+    reply(handler.list_invitations(context.request(message)).await)
+}
+
+async fn revoke_invitation(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    Path(id): Path<String>,
+) -> Result<Response, Problem> {
+    let message = RevokeInvitationRequest { id };
+    reply(handler.revoke_invitation(context.request(message)).await)
+}
+
+async fn accept_invitation(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    JsonBody(message): JsonBody<AcceptInvitationRequest>,
+) -> Result<Response, Problem> {
+    reply(handler.accept_invitation(context.request(message)).await)
+}
+
+async fn list_audit_events(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    QueryParams(message): QueryParams<ListAuditEventsRequest>,
+) -> Result<Response, Problem> {
+    reply(handler.list_audit_events(context.request(message)).await)
+}
+
+async fn list_outbox_messages(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    QueryParams(message): QueryParams<ListOutboxMessagesRequest>,
+) -> Result<Response, Problem> {
+    reply(handler.list_outbox_messages(context.request(message)).await)
+}
+
+async fn replay_outbox_message(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    Path(id): Path<String>,
+) -> Result<Response, Problem> {
+    let message = ReplayOutboxMessageRequest { id };
+    reply(
+        handler
+            .replay_outbox_message(context.request(message))
+            .await,
+    )
+}
+
+async fn update_phone_number(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    JsonBody(message): JsonBody<UpdatePhoneNumberRequest>,
+) -> Result<Response, Problem> {
+    reply(handler.update_phone_number(context.request(message)).await)
+}
+
+async fn verify_phone_number(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    JsonBody(message): JsonBody<VerifyPhoneNumberRequest>,
+) -> Result<Response, Problem> {
+    reply(handler.verify_phone_number(context.request(message)).await)
+}
+
+/// Streams the caller's account events as server-sent events named after their `kind`. A
+/// failure mid-stream is sent as a final `error` event carrying a problem document.
+async fn watch_account_events(
+    State(handler): State<RestState>,
+    context: RpcContext,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, Problem> {
+    let message = WatchAccountEventsRequest {};
+    let feed = handler
+        .watch_account_events(context.request(message))
+        .await?
+        .into_inner();
+
+    let events = feed.map(|event| match event {
+        Ok(event) => Event::default()
+            .event(event.kind.clone())
+            .id(event.id.clone())
+            .json_data(event),
+        Err(status) => Event::default()
+            .event("error")
+            .json_data(Problem::from(status).body()),
+    });
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+async fn create_webhook_subscription(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    JsonBody(message): JsonBody<CreateWebhookSubscriptionRequest>,
+) -> Result<Response, Problem> {
+    reply(
+        handler
+            .create_webhook_subscription(context.request(message))
+            .await,
+    )
+}
+
+async fn list_webhook_subscriptions(
+    State(handler): State<RestState>,
+    context: RpcContext,
+) -> Result<Response, Problem> {
+    let message = ListWebhookSubscriptionsRequest {};
+    reply(
+        handler
+            .list_webhook_subscriptions(context.request(message))
+            .await,
+    )
+}
+
+async fn delete_webhook_subscription(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    Path(id): Path<String>,
+) -> Result<Response, Problem> {
+    let message = DeleteWebhookSubscriptionRequest { id };
+    reply(
+        handler
+            .delete_webhook_subscription(context.request(message))
+            .await,
+    )
+}
+
+async fn list_webhook_deliveries(
+    State(handler): State<RestState>,
+    context: RpcContext,
+    Path(subscription_id): Path<String>,
+    QueryParams(message): QueryParams<ListWebhookDeliveriesRequest>,
+) -> Result<Response, Problem> {
+    let message = ListWebhookDeliveriesRequest {
+        subscription_id,
+        ..message
+    };
+    reply(
+        handler
+            .list_webhook_deliveries(context.request(message))
+            .await,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{Fakes, test_config};
+    use axum::body::Body;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    fn router(fakes: &Fakes) -> Router {
+        let config = Arc::new(test_config(""));
+        rest_router(Arc::new(fakes.auth_handler(&config)))
+    }
+
+    async fn read(response: Response) -> (StatusCode, HeaderMap, Value) {
+        let (parts, body) = response.into_parts();
+        let body = axum::body::to_bytes(body, usize::MAX).await.unwrap();
+        let body = serde_json::from_slice(&body).unwrap_or(Value::Null);
+        (parts.status, parts.headers, body)
+    }
+
+    async fn call(router: Router, request: http::Request<Body>) -> (StatusCode, HeaderMap, Value) {
+        read(router.oneshot(request).await.unwrap()).await
+    }
+
+    fn post_json(uri: &str, body: &str) -> http::Request<Body> {
+        http::Request::post(uri)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    #[test]
+    fn grpc_codes_map_to_http_statuses() {
+        for (code, status) in [
+            (Code::Ok, 200),
+            (Code::Cancelled, 499),
+            (Code::InvalidArgument, 400),
+            (Code::FailedPrecondition, 400),
+            (Code::OutOfRange, 400),
+            (Code::Unauthenticated, 401),
+            (Code::PermissionDenied, 403),
+            (Code::NotFound, 404),
+            (Code::AlreadyExists, 409),
+            (Code::Aborted, 409),
+            (Code::ResourceExhausted, 429),
+            (Code::Unknown, 500),
+            (Code::Internal, 500),
+            (Code::DataLoss, 500),
+            (Code::Unimplemented, 501),
+            (Code::Unavailable, 503),
+            (Code::DeadlineExceeded, 504),
+        ] {
+            assert_eq!(http_status(code).as_u16(), status, "{:?}", code);
+        }
+    }
+
+    #[tokio::test]
+    async fn failed_rpc_becomes_problem_document() {
+        let (status, headers, body) =
+            read(Problem::from(Status::already_exists("User already exists")).into_response())
+                .await;
+
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(headers[header::CONTENT_TYPE], "application/problem+json");
+        assert!(headers.get(header::WWW_AUTHENTICATE).is_none());
+        assert_eq!(
+            body,
+            json!({
+                "type": "about:blank",
+                "title": "Conflict",
+                "status": 409,
+                "detail": "User already exists",
+                "grpc_status": Code::AlreadyExists as i32,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn unauthenticated_route_challenges_for_a_bearer_token() {
+        let (status, headers, body) = call(
+            router(&Fakes::default()),
+            http::Request::get("/v1/api-keys")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(headers[header::WWW_AUTHENTICATE], "Bearer");
+        assert_eq!(headers[header::CONTENT_TYPE], "application/problem+json");
+        assert_eq!(body["grpc_status"], Code::Unauthenticated as i32);
+    }
+
+    #[tokio::test]
+    async fn malformed_body_is_rejected_without_a_grpc_status() {
+        let (status, headers, body) = call(
+            router(&Fakes::default()),
+            post_json("/v1/auth/register", "{"),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(headers[header::CONTENT_TYPE], "application/problem+json");
+        assert_eq!(body["status"], 400);
+        assert!(body.get("grpc_status").is_none());
+    }
+
+    #[tokio::test]
+    async fn successful_rpc_returns_its_message_as_json() {
+        let fakes = Fakes::default();
+        let (status, _, body) = call(
+            router(&fakes),
+            post_json(
+                "/v1/auth/register",
+                r#"{"name":"Ada","email":"ada@example.com","password":"Correct-horse-9"}"#,
+            ),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["user"]["email"], "ada@example.com");
+        assert_eq!(fakes.users.all().len(), 1);
+    }
+}
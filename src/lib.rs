@@ -1,14 +1,31 @@
+#![allow(clippy::result_large_err)]
+
 pub mod application {
+    pub mod account_event_use_case;
+    pub mod api_key_use_case;
+    pub mod audit_use_case;
     pub mod auth_use_case;
+    pub mod client_credentials_use_case;
+    pub mod health_use_case;
+    pub mod invitation_use_case;
+    pub mod metrics_use_case;
+    pub mod oidc_provider_use_case;
+    pub mod organization_use_case;
+    pub mod outbox_use_case;
+    pub mod webhook_use_case;
 }
 
 pub mod config {
     pub mod db;
     pub mod env;
     pub mod redis;
+    pub mod source;
+    pub mod telemetry;
+    pub mod tls;
 }
 
 pub mod core {
+    pub mod app_state;
     pub mod server;
 }
 
@@ -18,42 +35,113 @@ pub mod domain {
         pub mod auth_dto;
     }
     pub mod entity {
+        pub mod account_event;
+        pub mod api_key;
+        pub mod audit_event;
+        pub mod domain_event;
+        pub mod invitation;
+        pub mod membership;
+        pub mod oauth_client;
+        pub mod oauth_consent;
+        pub mod organization;
+        pub mod outbox_message;
+        pub mod passkey_credential;
+        pub mod service_client;
         pub mod user;
+        pub mod user_identity;
         pub mod user_info;
         pub mod user_security;
         pub mod user_sessions;
+        pub mod webhook;
     }
     pub mod port {
+        pub mod account_event_port;
         pub mod db {
+            pub mod api_key_port;
+            pub mod audit_port;
+            pub mod invitation_port;
+            pub mod membership_port;
+            pub mod oauth_client_port;
+            pub mod oauth_consent_port;
+            pub mod organization_port;
+            pub mod outbox_port;
+            pub mod passkey_credential_port;
+            pub mod service_client_port;
+            pub mod user_identity_port;
             pub mod user_info_port;
             pub mod user_port;
             pub mod user_security_port;
             pub mod user_session_port;
+            pub mod webhook_delivery_port;
+            pub mod webhook_subscription_port;
         }
         pub mod db_port;
+        pub mod event_subscriber_port;
+        pub mod external_identity_port;
+        pub mod health_check_port;
+        pub mod metrics_port;
+        pub mod notification_port;
         pub mod redis_port;
+        pub mod sms_port;
+        pub mod webhook_sender_port;
     }
     pub mod service {
+        pub mod event_bus_service;
+        pub mod id_token_service;
         pub mod jwt_service;
         pub mod otp_service;
         pub mod totp_service;
+        pub mod webauthn_service;
     }
     pub mod validator;
 }
 
 pub mod infrastructure {
     pub mod db {
+        pub mod api_key_adapter;
+        pub mod audit_adapter;
+        pub mod invitation_adapter;
+        pub mod membership_adapter;
+        pub mod oauth_client_adapter;
+        pub mod oauth_consent_adapter;
+        pub mod organization_adapter;
+        pub mod outbox_adapter;
+        pub mod passkey_credential_adapter;
+        pub mod service_client_adapter;
         pub mod user_adapter;
+        pub mod user_identity_adapter;
         pub mod user_info_adapter;
         pub mod user_security_adapter;
         pub mod user_session_adapter;
+        pub mod webhook_delivery_adapter;
+        pub mod webhook_subscription_adapter;
+    }
+    pub mod health {
+        pub mod postgres_health_adapter;
+        pub mod redis_health_adapter;
+    }
+    pub mod metrics {
+        pub mod prometheus_adapter;
     }
     pub mod notification {
-        pub mod email_adapter;
+        pub mod email_template;
+        pub mod file_adapter;
+        pub mod log_adapter;
+        pub mod log_sms_adapter;
+        pub mod memory_adapter;
+        pub mod smtp_adapter;
+        pub mod webhook_sms_adapter;
+    }
+    pub mod oidc {
+        pub mod oidc_adapter;
     }
     pub mod redis {
+        pub mod account_event_adapter;
         pub mod redis_adapter;
     }
+    pub mod webhook {
+        pub mod http_webhook_adapter;
+    }
 }
 
 pub mod interface {
@@ -66,8 +154,15 @@ pub mod interface {
         }
         pub mod layer {
             pub mod logging_layer;
+            pub mod metrics_layer;
+            pub mod trace_layer;
         }
     }
+    pub mod http {
+        pub mod metrics_router;
+        pub mod oidc_router;
+        pub mod rest_router;
+    }
     pub mod common {
         pub mod client_info;
     }
@@ -78,10 +173,9 @@ pub mod pb {
 }
 
 pub mod util {
+    #[allow(clippy::module_inception)]
     pub mod util;
 }
 
-pub use config::env::cfg;
-pub use domain::service::otp_service::email_otp;
-pub use domain::service::totp_service::totp;
-pub use infrastructure::notification::email_adapter::email;
+#[cfg(test)]
+pub(crate) mod test_support;
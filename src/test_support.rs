@@ -0,0 +1,981 @@
+//! In-memory ports and configuration for unit tests, so use cases run without Postgres or Redis.
+
+use crate::application::account_event_use_case::AccountEventUseCase;
+use crate::application::api_key_use_case::ApiKeyUseCase;
+use crate::application::audit_use_case::AuditUseCase;
+use crate::application::auth_use_case::AuthUseCase;
+use crate::application::client_credentials_use_case::ClientCredentialsUseCase;
+use crate::application::invitation_use_case::InvitationUseCase;
+use crate::application::oidc_provider_use_case::OidcProviderUseCase;
+use crate::application::organization_use_case::OrganizationUseCase;
+use crate::application::outbox_use_case::OutboxUseCase;
+use crate::application::webhook_use_case::WebhookUseCase;
+use crate::config::env::EnvConfig;
+use crate::domain::entity::account_event::AccountEvent;
+use crate::domain::entity::api_key::ApiKey;
+use crate::domain::entity::audit_event::{AuditEvent, AuditFilter};
+use crate::domain::entity::domain_event::DomainEvent;
+use crate::domain::entity::invitation::Invitation;
+use crate::domain::entity::membership::Membership;
+use crate::domain::entity::oauth_client::OAuthClient;
+use crate::domain::entity::oauth_consent::OAuthConsent;
+use crate::domain::entity::organization::{Organization, UserOrganization};
+use crate::domain::entity::outbox_message::{OutboxMessage, OutboxPayload, OutboxStatus};
+use crate::domain::entity::passkey_credential::PasskeyCredential;
+use crate::domain::entity::service_client::ServiceClient;
+use crate::domain::entity::user::{User, UserStatus};
+use crate::domain::entity::user_identity::UserIdentity;
+use crate::domain::entity::user_info::UserInfo;
+use crate::domain::entity::user_sessions::UserSessions;
+use crate::domain::entity::webhook::{WebhookDelivery, WebhookSubscription};
+use crate::domain::port::account_event_port::{AccountEventPort, AccountEventStream};
+use crate::domain::port::db::api_key_port::ApiKeyPort;
+use crate::domain::port::db::audit_port::AuditPort;
+use crate::domain::port::db::invitation_port::InvitationPort;
+use crate::domain::port::db::membership_port::MembershipPort;
+use crate::domain::port::db::oauth_client_port::OAuthClientPort;
+use crate::domain::port::db::oauth_consent_port::OAuthConsentPort;
+use crate::domain::port::db::organization_port::OrganizationPort;
+use crate::domain::port::db::outbox_port::OutboxPort;
+use crate::domain::port::db::passkey_credential_port::PasskeyCredentialPort;
+use crate::domain::port::db::service_client_port::ServiceClientPort;
+use crate::domain::port::db::user_identity_port::UserIdentityPort;
+use crate::domain::port::db::user_info_port::UserInfoPort;
+use crate::domain::port::db::user_port::UserPort;
+use crate::domain::port::db::webhook_delivery_port::WebhookDeliveryPort;
+use crate::domain::port::db::webhook_subscription_port::WebhookSubscriptionPort;
+use crate::domain::port::db_port::DbPort;
+use crate::domain::port::event_subscriber_port::EventSubscriberPort;
+use crate::domain::port::notification_port::NotificationPort;
+use crate::domain::port::redis_port::RedisPort;
+use crate::domain::port::sms_port::SmsPort;
+use crate::domain::port::webhook_sender_port::WebhookSenderPort;
+use crate::domain::service::event_bus_service::EventBus;
+use crate::domain::service::otp_service::OtpEmail;
+use crate::domain::service::webauthn_service::WebauthnService;
+use crate::infrastructure::notification::log_sms_adapter::LogSmsAdapter;
+use crate::infrastructure::notification::memory_adapter::MemoryAdapter;
+use crate::infrastructure::oidc::oidc_adapter::OidcAdapter;
+use crate::interface::grpc::handler::auth_handler::AuthHandler;
+use async_trait::async_trait;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode, header};
+use axum::routing::post;
+use axum::{Json, Router};
+use chrono::{DateTime, Utc};
+use futures::channel::mpsc::{UnboundedSender, unbounded};
+use redis::RedisResult;
+use serde_json::Value;
+use sqlx::Error;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
+use tonic::Status;
+use uuid::Uuid;
+
+pub(crate) const OIDC_SIGNING_KEY_PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/oidc_signing_key.pem"
+);
+
+/// A valid configuration with every required key set, plus the TOML lines in `extra`, which
+/// may only add keys.
+pub(crate) fn test_config(extra: &str) -> EnvConfig {
+    let toml = format!(
+        r#"
+database_url = "postgres://localhost/test"
+access_secret = "access-secret-access-secret-0123456789"
+refresh_secret = "refresh-secret-refresh-secret-0123456789"
+secret_key = "secret-key-secret-key-secret-key-0123456789"
+access_token_duration = 900
+refresh_token_duration = 86400
+redis_host = "localhost"
+redis_port = 6379
+notification_transport = "memory"
+oidc_signing_key_path = "{}"
+{}
+"#,
+        OIDC_SIGNING_KEY_PATH, extra
+    );
+    EnvConfig::from_toml(&toml).expect("test configuration must be valid")
+}
+
+/// Redis stand-in backed by a map. Keys expire on tokio's clock, so tests with paused time can
+/// skip past a TTL.
+#[derive(Default)]
+pub(crate) struct MemoryRedis {
+    values: Mutex<HashMap<String, (String, Option<Instant>)>>,
+}
+
+impl MemoryRedis {
+    pub(crate) fn get(&self, key: &str) -> Option<String> {
+        let mut values = self.values.lock().unwrap();
+        match values.get(key) {
+            Some((_, Some(expires_at))) if *expires_at <= Instant::now() => {
+                values.remove(key);
+                None
+            }
+            entry => entry.map(|(value, _)| value.clone()),
+        }
+    }
+
+    fn insert(&self, key: &str, value: &str, expires_at: Option<Instant>) {
+        self.values
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), (value.to_string(), expires_at));
+    }
+}
+
+#[async_trait]
+impl RedisPort for MemoryRedis {
+    async fn set_value(&self, key: &str, value: &str) -> RedisResult<()> {
+        self.insert(key, value, None);
+        Ok(())
+    }
+
+    async fn set_value_with_ttl(&self, key: &str, value: &str, ttl_secs: u64) -> RedisResult<()> {
+        self.insert(
+            key,
+            value,
+            Some(Instant::now() + Duration::from_secs(ttl_secs)),
+        );
+        Ok(())
+    }
+
+    async fn get_value(&self, key: &str) -> RedisResult<Option<String>> {
+        Ok(self.get(key))
+    }
+
+    async fn delete_value(&self, key: &str) -> RedisResult<()> {
+        self.values.lock().unwrap().remove(key);
+        Ok(())
+    }
+
+    async fn exists(&self, key: &str) -> RedisResult<Option<bool>> {
+        Ok(Some(self.get(key).is_some()))
+    }
+
+    async fn pull_value(&self, key: &str) -> RedisResult<Option<String>> {
+        let value = self.get(key);
+        self.values.lock().unwrap().remove(key);
+        Ok(value)
+    }
+
+    async fn blacklist_token(&self, token: &str) -> RedisResult<()> {
+        self.set_value(token, "BLACKLISTED").await
+    }
+
+    async fn ensure_not_blacklisted(&self, token: &str) -> Result<(), Status> {
+        match self.get(token) {
+            Some(value) if value == "BLACKLISTED" => Err(Status::unauthenticated(
+                "Token already invalidated or blacklisted",
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Entities kept in a [`Table`].
+pub(crate) trait Row: Clone + Send + Sync + 'static {
+    fn id(&self) -> Uuid;
+}
+
+macro_rules! row {
+    ($($entity:ty),*) => {
+        $(impl Row for $entity {
+            fn id(&self) -> Uuid {
+                self.id
+            }
+        })*
+    };
+}
+
+row!(
+    User,
+    UserIdentity,
+    UserSessions,
+    PasskeyCredential,
+    Organization,
+    Membership,
+    OutboxMessage,
+    ApiKey,
+    AuditEvent,
+    Invitation,
+    ServiceClient,
+    WebhookSubscription,
+    WebhookDelivery,
+    OAuthClient,
+    OAuthConsent,
+    UserInfo
+);
+
+/// A database table in a `Vec`, implementing the generic `DbPort` and the entity's own port.
+pub(crate) struct Table<T> {
+    rows: Mutex<Vec<T>>,
+}
+
+impl<T: Row> Default for Table<T> {
+    fn default() -> Self {
+        Table {
+            rows: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl<T: Row> Table<T> {
+    pub(crate) fn with(rows: Vec<T>) -> Self {
+        Table {
+            rows: Mutex::new(rows),
+        }
+    }
+
+    pub(crate) fn with_row(&self, row: T) {
+        self.rows.lock().unwrap().push(row);
+    }
+
+    pub(crate) fn all(&self) -> Vec<T> {
+        self.rows.lock().unwrap().clone()
+    }
+
+    pub(crate) fn get(&self, id: Uuid) -> Option<T> {
+        self.find(|row| row.id() == id)
+    }
+
+    fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<T> {
+        self.rows
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|row| predicate(row))
+            .cloned()
+    }
+
+    pub(crate) fn modify(&self, id: Uuid, change: impl FnOnce(&mut T)) {
+        if let Some(row) = self
+            .rows
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .find(|row| row.id() == id)
+        {
+            change(row);
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Row> DbPort<T> for Table<T> {
+    async fn save(&self, data: &T) -> Result<(), Error> {
+        self.rows.lock().unwrap().push(data.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<T>, Error> {
+        Ok(self.get(id))
+    }
+
+    /// Tables index no columns, so lookups by one find nothing.
+    async fn find_by_coll(&self, _coll: &str, _value: &str) -> Result<Option<T>, Error> {
+        Ok(None)
+    }
+
+    async fn update(&self, id: Uuid, data: &T) -> Result<(), Error> {
+        self.modify(id, |row| *row = data.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), Error> {
+        self.rows.lock().unwrap().retain(|row| row.id() != id);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UserPort for Table<User> {
+    async fn register(
+        &self,
+        data: &User,
+        _membership: Option<&Membership>,
+        _messages: &[OutboxMessage],
+    ) -> Result<(), Error> {
+        self.save(data).await
+    }
+
+    async fn find_by_email(
+        &self,
+        email: &str,
+        org_id: Option<Uuid>,
+    ) -> Result<Option<User>, Error> {
+        Ok(self.find(|user| user.email == email && user.org_id == org_id))
+    }
+
+    async fn verify_email(&self, id: Uuid, _messages: &[OutboxMessage]) -> Result<(), Error> {
+        self.modify(id, |user| user.status = UserStatus::Active);
+        Ok(())
+    }
+
+    async fn set_phone_number(&self, id: Uuid, phone_number: &str) -> Result<(), Error> {
+        self.modify(id, |user| {
+            user.phone_number = Some(phone_number.to_string());
+            user.phone_verified_at = None;
+        });
+        Ok(())
+    }
+
+    async fn verify_phone_number(&self, id: Uuid) -> Result<(), Error> {
+        self.modify(id, |user| user.phone_verified_at = Some(Utc::now()));
+        Ok(())
+    }
+
+    async fn update_password(&self, id: Uuid, data: &User) -> Result<(), Error> {
+        self.modify(id, |user| user.password = data.password.clone());
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UserIdentityPort for Table<UserIdentity> {
+    async fn find_by_provider_subject(
+        &self,
+        provider: &str,
+        subject: &str,
+    ) -> Result<Option<UserIdentity>, Error> {
+        Ok(self.find(|identity| identity.provider == provider && identity.subject == subject))
+    }
+}
+
+#[async_trait]
+impl PasskeyCredentialPort for Table<PasskeyCredential> {
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<PasskeyCredential>, Error> {
+        Ok(self
+            .all()
+            .into_iter()
+            .filter(|passkey| passkey.user_id == user_id)
+            .collect())
+    }
+
+    async fn find_by_credential_id(
+        &self,
+        credential_id: &str,
+    ) -> Result<Option<PasskeyCredential>, Error> {
+        Ok(self.find(|passkey| passkey.credential_id == credential_id))
+    }
+
+    async fn record_use(&self, id: Uuid, credential: &str, sign_count: i64) -> Result<(), Error> {
+        self.modify(id, |passkey| {
+            passkey.credential = credential.to_string();
+            passkey.sign_count = sign_count;
+            passkey.last_used_at = Some(Utc::now());
+        });
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl OrganizationPort for Table<Organization> {
+    async fn create_with_owner(&self, data: &Organization, _owner_id: Uuid) -> Result<(), Error> {
+        self.save(data).await
+    }
+
+    async fn list_by_user(&self, _user_id: Uuid) -> Result<Vec<UserOrganization>, Error> {
+        Ok(Vec::new())
+    }
+}
+
+#[async_trait]
+impl MembershipPort for Table<Membership> {
+    async fn find_membership(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<Membership>, Error> {
+        Ok(self.find(|membership| membership.org_id == org_id && membership.user_id == user_id))
+    }
+}
+
+#[async_trait]
+impl OutboxPort for Table<OutboxMessage> {
+    async fn enqueue(&self, message: &OutboxMessage) -> Result<(), Error> {
+        self.save(message).await
+    }
+
+    async fn claim_due(&self, limit: i64, lease: Duration) -> Result<Vec<OutboxMessage>, Error> {
+        let now = Utc::now();
+        let mut rows = self.rows.lock().unwrap();
+        Ok(rows
+            .iter_mut()
+            .filter(|message| {
+                message.status == OutboxStatus::Pending.as_str() && message.next_attempt_at <= now
+            })
+            .take(limit as usize)
+            .map(|message| {
+                message.attempts += 1;
+                message.next_attempt_at = now + lease;
+                message.clone()
+            })
+            .collect())
+    }
+
+    async fn mark_delivered(&self, id: Uuid) -> Result<(), Error> {
+        self.modify(id, |message| {
+            message.status = OutboxStatus::Delivered.as_str().to_string();
+            message.delivered_at = Some(Utc::now());
+            message.last_error = None;
+        });
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), Error> {
+        self.modify(id, |message| {
+            message.last_error = Some(error.to_string());
+            match retry_at {
+                Some(retry_at) => message.next_attempt_at = retry_at,
+                None => message.status = OutboxStatus::Dead.as_str().to_string(),
+            }
+        });
+        Ok(())
+    }
+
+    async fn list(
+        &self,
+        _status: Option<OutboxStatus>,
+        _before: Option<(DateTime<Utc>, Uuid)>,
+        _limit: i64,
+    ) -> Result<Vec<OutboxMessage>, Error> {
+        Ok(self.all())
+    }
+
+    async fn replay(&self, _id: Uuid) -> Result<bool, Error> {
+        Ok(false)
+    }
+
+    async fn purge_finished(&self, before: DateTime<Utc>) -> Result<u64, Error> {
+        let mut rows = self.rows.lock().unwrap();
+        let count = rows.len();
+        rows.retain(|message| {
+            message.status == OutboxStatus::Pending.as_str() || message.updated_at >= before
+        });
+        Ok((count - rows.len()) as u64)
+    }
+}
+
+#[async_trait]
+impl ApiKeyPort for Table<ApiKey> {
+    async fn find_by_prefix(&self, prefix: &str) -> Result<Option<ApiKey>, Error> {
+        Ok(self.find(|api_key| api_key.prefix == prefix))
+    }
+
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<ApiKey>, Error> {
+        Ok(self
+            .all()
+            .into_iter()
+            .filter(|api_key| api_key.user_id == user_id)
+            .collect())
+    }
+
+    async fn revoke(&self, id: Uuid, user_id: Uuid) -> Result<bool, Error> {
+        let owned = self.find(|api_key| api_key.id == id && api_key.user_id == user_id);
+        self.modify(id, |api_key| api_key.revoked_at = Some(Utc::now()));
+        Ok(owned.is_some())
+    }
+
+    async fn touch_last_used(&self, id: Uuid) -> Result<(), Error> {
+        self.modify(id, |api_key| api_key.last_used_at = Some(Utc::now()));
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AuditPort for Table<AuditEvent> {
+    async fn record(&self, event: &AuditEvent) -> Result<(), Error> {
+        self.save(event).await
+    }
+
+    async fn list(&self, filter: &AuditFilter, limit: i64) -> Result<Vec<AuditEvent>, Error> {
+        let mut events: Vec<AuditEvent> = self
+            .all()
+            .into_iter()
+            .filter(|event| {
+                filter
+                    .actor_id
+                    .is_none_or(|actor_id| event.actor_id == Some(actor_id))
+                    && filter
+                        .org_id
+                        .is_none_or(|org_id| event.org_id == Some(org_id))
+                    && filter
+                        .action
+                        .as_ref()
+                        .is_none_or(|action| &event.action == action)
+                    && filter
+                        .outcome
+                        .as_ref()
+                        .is_none_or(|outcome| &event.outcome == outcome)
+            })
+            .collect();
+        events.reverse();
+        events.truncate(limit as usize);
+        Ok(events)
+    }
+}
+
+#[async_trait]
+impl InvitationPort for Table<Invitation> {
+    async fn create_with_message(
+        &self,
+        data: &Invitation,
+        _message: &OutboxMessage,
+    ) -> Result<(), Error> {
+        self.save(data).await
+    }
+
+    async fn list_by_org(&self, org_id: Uuid) -> Result<Vec<Invitation>, Error> {
+        Ok(self
+            .all()
+            .into_iter()
+            .filter(|invitation| invitation.org_id == org_id)
+            .collect())
+    }
+
+    async fn find_pending(&self, org_id: Uuid, email: &str) -> Result<Option<Invitation>, Error> {
+        Ok(self.find(|invitation| {
+            invitation.org_id == org_id && invitation.email == email && invitation.is_pending()
+        }))
+    }
+
+    async fn mark_accepted(&self, id: Uuid) -> Result<(), Error> {
+        self.modify(id, |invitation| invitation.accepted_at = Some(Utc::now()));
+        Ok(())
+    }
+
+    async fn revoke(&self, id: Uuid) -> Result<bool, Error> {
+        let pending = self
+            .get(id)
+            .is_some_and(|invitation| invitation.is_pending());
+        self.modify(id, |invitation| invitation.revoked_at = Some(Utc::now()));
+        Ok(pending)
+    }
+}
+
+#[async_trait]
+impl ServiceClientPort for Table<ServiceClient> {
+    async fn find_by_client_id(&self, client_id: &str) -> Result<Option<ServiceClient>, Error> {
+        Ok(self.find(|client| client.client_id == client_id))
+    }
+}
+
+#[async_trait]
+impl OAuthClientPort for Table<OAuthClient> {
+    async fn find_by_client_id(&self, client_id: &str) -> Result<Option<OAuthClient>, Error> {
+        Ok(self.find(|client| client.client_id == client_id))
+    }
+}
+
+#[async_trait]
+impl OAuthConsentPort for Table<OAuthConsent> {
+    async fn find_by_user_client(
+        &self,
+        user_id: Uuid,
+        client_id: &str,
+    ) -> Result<Option<OAuthConsent>, Error> {
+        Ok(self.find(|consent| consent.user_id == user_id && consent.client_id == client_id))
+    }
+
+    async fn grant(&self, data: &OAuthConsent) -> Result<(), Error> {
+        self.rows.lock().unwrap().retain(|consent| {
+            consent.user_id != data.user_id || consent.client_id != data.client_id
+        });
+        self.save(data).await
+    }
+}
+
+impl UserInfoPort for Table<UserInfo> {}
+
+#[async_trait]
+impl WebhookSubscriptionPort for Table<WebhookSubscription> {
+    async fn list_active(&self) -> Result<Vec<WebhookSubscription>, Error> {
+        Ok(self.all())
+    }
+
+    async fn list_for_event(&self, event: &str) -> Result<Vec<WebhookSubscription>, Error> {
+        Ok(self
+            .all()
+            .into_iter()
+            .filter(|subscription| subscription.events.iter().any(|e| e == event))
+            .collect())
+    }
+
+    async fn remove(&self, id: Uuid) -> Result<bool, Error> {
+        let found = self.get(id).is_some();
+        self.delete(id).await?;
+        Ok(found)
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryPort for Table<WebhookDelivery> {
+    async fn record(&self, delivery: &WebhookDelivery) -> Result<(), Error> {
+        self.save(delivery).await
+    }
+
+    async fn list_by_subscription(
+        &self,
+        subscription_id: Uuid,
+        _before: Option<(DateTime<Utc>, Uuid)>,
+        _limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, Error> {
+        Ok(self
+            .all()
+            .into_iter()
+            .filter(|delivery| delivery.subscription_id == subscription_id)
+            .collect())
+    }
+}
+
+/// Account event fan-out that drops everything.
+pub(crate) struct NoAccountEvents;
+
+#[async_trait]
+impl AccountEventPort for NoAccountEvents {
+    async fn publish(
+        &self,
+        _event: &AccountEvent,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        Ok(())
+    }
+
+    async fn subscribe(
+        &self,
+        _user_id: Uuid,
+    ) -> Result<AccountEventStream, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(Box::pin(futures::stream::empty()))
+    }
+}
+
+/// In-process account event fan-out with one channel per user, like the Redis adapter.
+#[derive(Default)]
+pub(crate) struct MemoryAccountEvents {
+    channels: Mutex<HashMap<Uuid, Vec<UnboundedSender<AccountEvent>>>>,
+}
+
+#[async_trait]
+impl AccountEventPort for MemoryAccountEvents {
+    async fn publish(
+        &self,
+        event: &AccountEvent,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(watchers) = self.channels.lock().unwrap().get_mut(&event.user_id) {
+            watchers.retain(|watcher| watcher.unbounded_send(event.clone()).is_ok());
+        }
+        Ok(())
+    }
+
+    async fn subscribe(
+        &self,
+        user_id: Uuid,
+    ) -> Result<AccountEventStream, Box<dyn std::error::Error + Send + Sync>> {
+        let (sender, receiver) = unbounded();
+        self.channels
+            .lock()
+            .unwrap()
+            .entry(user_id)
+            .or_default()
+            .push(sender);
+        Ok(Box::pin(receiver))
+    }
+}
+
+/// Event subscriber that remembers every event it handled and asks for one webhook message
+/// per event, so tests can see whether publishers commit those messages themselves.
+#[derive(Default)]
+pub(crate) struct RecordingSubscriber {
+    handled: Mutex<Vec<DomainEvent>>,
+}
+
+impl RecordingSubscriber {
+    pub(crate) fn handled(&self) -> Vec<&'static str> {
+        self.handled
+            .lock()
+            .unwrap()
+            .iter()
+            .map(DomainEvent::name)
+            .collect()
+    }
+}
+
+#[async_trait]
+impl EventSubscriberPort for RecordingSubscriber {
+    async fn outbox_messages(&self, event: &DomainEvent) -> Vec<OutboxMessage> {
+        vec![OutboxMessage::new(OutboxPayload::Webhook {
+            subscription_id: Uuid::new_v4(),
+            event_id: Uuid::new_v4(),
+            event: event.name().to_string(),
+            body: "{}".to_string(),
+        })]
+    }
+
+    async fn handle(&self, event: &DomainEvent) {
+        self.handled.lock().unwrap().push(event.clone());
+    }
+}
+
+/// Webhook receiver that accepts every delivery.
+pub(crate) struct AcceptingWebhooks;
+
+#[async_trait]
+impl WebhookSenderPort for AcceptingWebhooks {
+    async fn post(
+        &self,
+        _url: &str,
+        _headers: &[(&str, String)],
+        _body: &str,
+    ) -> Result<u16, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(200)
+    }
+}
+
+/// A request the [`SmsGateway`] received: its `Authorization` header and JSON body.
+#[derive(Debug, Clone)]
+pub(crate) struct SmsRequest {
+    pub(crate) authorization: Option<String>,
+    pub(crate) body: Value,
+}
+
+/// A local stand-in for an SMS provider's webhook that records every request and answers with
+/// a fixed status.
+#[derive(Clone)]
+pub(crate) struct SmsGateway {
+    pub(crate) url: String,
+    received: Arc<Mutex<Vec<SmsRequest>>>,
+    status: StatusCode,
+}
+
+impl SmsGateway {
+    pub(crate) async fn start(status: StatusCode) -> Self {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let gateway = SmsGateway {
+            url: format!("http://{}/sms", listener.local_addr().unwrap()),
+            received: Arc::default(),
+            status,
+        };
+
+        let app = Router::new()
+            .route(
+                "/sms",
+                post(
+                    |State(gateway): State<SmsGateway>,
+                     headers: HeaderMap,
+                     Json(body): Json<Value>| async move {
+                        gateway.received.lock().unwrap().push(SmsRequest {
+                            authorization: headers
+                                .get(header::AUTHORIZATION)
+                                .and_then(|value| value.to_str().ok())
+                                .map(String::from),
+                            body,
+                        });
+                        gateway.status
+                    },
+                ),
+            )
+            .with_state(gateway.clone());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        gateway
+    }
+
+    pub(crate) fn received(&self) -> Vec<SmsRequest> {
+        self.received.lock().unwrap().clone()
+    }
+}
+
+/// A delivery the [`WebhookReceiver`] received.
+#[derive(Debug, Clone)]
+pub(crate) struct ReceivedWebhook {
+    pub(crate) headers: HeaderMap,
+    pub(crate) body: String,
+}
+
+/// A local webhook endpoint that records every delivery and answers with a fixed status.
+#[derive(Clone)]
+pub(crate) struct WebhookReceiver {
+    pub(crate) url: String,
+    received: Arc<Mutex<Vec<ReceivedWebhook>>>,
+    status: StatusCode,
+}
+
+impl WebhookReceiver {
+    pub(crate) async fn start(status: StatusCode) -> Self {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let receiver = WebhookReceiver {
+            url: format!("http://{}/hooks", listener.local_addr().unwrap()),
+            received: Arc::default(),
+            status,
+        };
+
+        let app =
+            Router::new()
+                .route(
+                    "/hooks",
+                    post(
+                        |State(receiver): State<WebhookReceiver>,
+                         headers: HeaderMap,
+                         body: String| async move {
+                            receiver
+                                .received
+                                .lock()
+                                .unwrap()
+                                .push(ReceivedWebhook { headers, body });
+                            receiver.status
+                        },
+                    ),
+                )
+                .with_state(receiver.clone());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        receiver
+    }
+
+    pub(crate) fn received(&self) -> Vec<ReceivedWebhook> {
+        self.received.lock().unwrap().clone()
+    }
+}
+
+/// Every store the use cases talk to, so tests can seed rows and inspect what was written.
+#[derive(Default)]
+pub(crate) struct Fakes {
+    pub(crate) redis: Arc<MemoryRedis>,
+    pub(crate) users: Arc<Table<User>>,
+    pub(crate) sessions: Arc<Table<UserSessions>>,
+    pub(crate) identities: Arc<Table<UserIdentity>>,
+    pub(crate) passkeys: Arc<Table<PasskeyCredential>>,
+    pub(crate) organizations: Arc<Table<Organization>>,
+    pub(crate) memberships: Arc<Table<Membership>>,
+    pub(crate) outbox: Arc<Table<OutboxMessage>>,
+    pub(crate) api_keys: Arc<Table<ApiKey>>,
+    pub(crate) audit: Arc<Table<AuditEvent>>,
+    pub(crate) invitations: Arc<Table<Invitation>>,
+    pub(crate) service_clients: Arc<Table<ServiceClient>>,
+    pub(crate) webhook_subscriptions: Arc<Table<WebhookSubscription>>,
+    pub(crate) webhook_deliveries: Arc<Table<WebhookDelivery>>,
+    pub(crate) oauth_clients: Arc<Table<OAuthClient>>,
+    pub(crate) oauth_consents: Arc<Table<OAuthConsent>>,
+    pub(crate) user_info: Arc<Table<UserInfo>>,
+}
+
+impl Fakes {
+    pub(crate) fn auth_use_case(&self, config: &Arc<EnvConfig>) -> AuthUseCase {
+        AuthUseCase::new(
+            self.users.clone(),
+            self.sessions.clone(),
+            self.redis.clone(),
+            self.identities.clone(),
+            Arc::new(OidcAdapter::new(config)),
+            self.passkeys.clone(),
+            self.organizations.clone(),
+            self.memberships.clone(),
+            self.outbox.clone(),
+            Arc::new(EventBus::new(self.outbox.clone())),
+            Arc::new(OtpEmail::init()),
+            Arc::new(WebauthnService::new(config)),
+            config.clone(),
+        )
+    }
+
+    fn webhook_use_case(&self) -> WebhookUseCase {
+        WebhookUseCase::new(
+            self.webhook_subscriptions.clone(),
+            self.webhook_deliveries.clone(),
+            Arc::new(AcceptingWebhooks),
+        )
+    }
+
+    pub(crate) fn invitation_use_case(&self, config: &Arc<EnvConfig>) -> InvitationUseCase {
+        self.invitation_use_case_with_events(config, EventBus::new(self.outbox.clone()))
+    }
+
+    pub(crate) fn invitation_use_case_with_events(
+        &self,
+        config: &Arc<EnvConfig>,
+        events: EventBus,
+    ) -> InvitationUseCase {
+        InvitationUseCase::new(
+            self.invitations.clone(),
+            self.organizations.clone(),
+            self.memberships.clone(),
+            self.users.clone(),
+            Arc::new(events),
+            config.clone(),
+        )
+    }
+
+    pub(crate) fn audit_use_case(&self) -> AuditUseCase {
+        AuditUseCase::new(
+            self.audit.clone(),
+            self.users.clone(),
+            self.memberships.clone(),
+        )
+    }
+
+    pub(crate) fn outbox_use_case(
+        &self,
+        config: &Arc<EnvConfig>,
+        notifications: Arc<dyn NotificationPort>,
+    ) -> OutboxUseCase {
+        self.outbox_use_case_with_sms(config, notifications, Arc::new(LogSmsAdapter))
+    }
+
+    pub(crate) fn outbox_use_case_with_sms(
+        &self,
+        config: &Arc<EnvConfig>,
+        notifications: Arc<dyn NotificationPort>,
+        sms: Arc<dyn SmsPort>,
+    ) -> OutboxUseCase {
+        OutboxUseCase::new(
+            self.outbox.clone(),
+            notifications,
+            sms,
+            self.redis.clone(),
+            self.webhook_use_case(),
+            config.clone(),
+        )
+    }
+
+    pub(crate) fn oidc_provider_use_case(&self, config: &Arc<EnvConfig>) -> OidcProviderUseCase {
+        OidcProviderUseCase::new(
+            self.auth_use_case(config),
+            self.audit_use_case(),
+            self.users.clone(),
+            self.user_info.clone(),
+            self.oauth_clients.clone(),
+            self.oauth_consents.clone(),
+            self.redis.clone(),
+            config.clone(),
+        )
+        .expect("test signing key must load")
+    }
+
+    /// The gRPC handler over these stores, wired like `serve` wires the real one.
+    pub(crate) fn auth_handler(&self, config: &Arc<EnvConfig>) -> AuthHandler {
+        AuthHandler::new(
+            self.auth_use_case(config),
+            ClientCredentialsUseCase::new(self.service_clients.clone(), config.clone()),
+            ApiKeyUseCase::new(self.api_keys.clone()),
+            OrganizationUseCase::new(self.organizations.clone()),
+            self.invitation_use_case(config),
+            self.audit_use_case(),
+            self.outbox_use_case(config, Arc::new(MemoryAdapter::new())),
+            self.webhook_use_case(),
+            AccountEventUseCase::new(Arc::new(NoAccountEvents), CancellationToken::new()),
+            self.redis.clone(),
+            self.api_keys.clone(),
+            config.clone(),
+        )
+    }
+}
@@ -1,5 +1,12 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 use bcrypt::{DEFAULT_COST, hash, verify};
+use chrono::{DateTime, Utc};
+use rand::distr::Alphanumeric;
+use rand::{Rng, rng};
+use sha2::{Digest, Sha256};
 use std::error::Error;
+use uuid::Uuid;
 
 pub async fn hash_password_async(password: String) -> Result<String, Box<dyn Error + Send + Sync>> {
     let handle = tokio::task::spawn_blocking(move || hash(password, DEFAULT_COST));
@@ -20,3 +27,56 @@ pub async fn verify_password_async(
 
     Ok(result)
 }
+
+pub fn generate_random_string(length: usize) -> String {
+    rng()
+        .sample_iter(&Alphanumeric)
+        .take(length)
+        .map(char::from)
+        .collect()
+}
+
+/// S256 PKCE code challenge: `BASE64URL(SHA256(verifier))` without padding.
+pub fn pkce_challenge(code_verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+pub fn sha256_hex(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Opaque keyset cursor over `(created_at, id)`, used by the newest-first list RPCs.
+pub fn encode_cursor(created_at: DateTime<Utc>, id: Uuid) -> String {
+    URL_SAFE_NO_PAD.encode(format!("{}|{}", created_at.to_rfc3339(), id))
+}
+
+pub fn decode_cursor(cursor: &str) -> Option<(DateTime<Utc>, Uuid)> {
+    let decoded = String::from_utf8(URL_SAFE_NO_PAD.decode(cursor).ok()?).ok()?;
+    let (created_at, id) = decoded.split_once('|')?;
+
+    Some((
+        DateTime::parse_from_rfc3339(created_at)
+            .ok()?
+            .with_timezone(&Utc),
+        Uuid::parse_str(id).ok()?,
+    ))
+}
+
+/// Reduces a locale such as `en-US` or an `Accept-Language` value such as `id-ID,id;q=0.9` to
+/// its lowercase primary language tag.
+pub fn primary_language_tag(value: &str) -> Option<String> {
+    let tag = value
+        .split(',')
+        .next()?
+        .split(';')
+        .next()?
+        .split(['-', '_'])
+        .next()?
+        .trim();
+
+    let valid = (2..=3).contains(&tag.len()) && tag.chars().all(|c| c.is_ascii_alphabetic());
+    valid.then(|| tag.to_ascii_lowercase())
+}